@@ -0,0 +1,76 @@
+use genx::{
+    crossover::single_point_crossover,
+    encoding::{decode_reals, Coding},
+    mutation::{flipping_mutation, inversion_mutation},
+    selection::{random_selection, stochastic_universal_selection},
+};
+use rand::{distributions::Uniform, prelude::Distribution};
+
+fn main() {
+    let equation = |x: f32| 3.0 * x * x + 8.0 * (-x) + 5.0;
+    let population_size = 15;
+    let iterations = 1000;
+    let mutation_probability = 0.5;
+    let bits_per_variable = 16;
+    let bounds = vec![(-10.0, 10.0)];
+    let mut prng = rand::thread_rng();
+
+    let decode = |individual: &Vec<bool>| decode_reals(individual, &bounds, bits_per_variable, Coding::Gray).unwrap()[0];
+    let fitness_function = |individual: &Vec<bool>| {
+        let x = decode(individual);
+        1.0 / ((equation(x) - x).abs() + 1.0)
+    };
+    let mut population = (0..population_size)
+        .map(|_| (0..bits_per_variable).map(|_| rand::random::<bool>()).collect())
+        .collect::<Vec<Vec<bool>>>();
+
+    let get_fitness_values = |population: &Vec<Vec<bool>>| {
+        population
+            .iter()
+            .map(&fitness_function)
+            .collect::<Vec<f32>>()
+    };
+
+    let best_fitness_value = |population: &Vec<Vec<bool>>| {
+        population
+            .iter()
+            .max_by(|&a, &b| {
+                fitness_function(a)
+                    .partial_cmp(&fitness_function(b))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap()
+            .clone()
+    };
+
+    let mut best_now = best_fitness_value(&population);
+    let between = Uniform::from(0.0..1.0);
+    for _ in 0..iterations {
+        let idxs = random_selection(population_size, 2, None);
+        let (mut child1, mut child2) =
+            single_point_crossover(&population[idxs[0]], &population[idxs[1]], None);
+
+        if between.sample(&mut prng) < mutation_probability {
+            flipping_mutation(&mut child1, 0.1, None).unwrap();
+        }
+        if between.sample(&mut prng) < mutation_probability {
+            inversion_mutation(&mut child2, None);
+        }
+
+        population.push(child1);
+        population.push(child2);
+
+        let fitness_values = get_fitness_values(&population);
+        let selected_idx = stochastic_universal_selection(&fitness_values, population_size, None);
+        population = selected_idx
+            .iter()
+            .map(|&a| population[a].clone())
+            .collect::<Vec<Vec<bool>>>();
+        let best = best_fitness_value(&population);
+        if fitness_function(&best) > fitness_function(&best_now) {
+            best_now = best;
+        }
+    }
+    let x = decode(&best_now);
+    println!("{} {}", x, equation(x));
+}
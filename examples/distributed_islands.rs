@@ -0,0 +1,137 @@
+// Solves concatenated deceptive trap functions with islands living in separate processes.
+//
+// cargo run --example distributed_islands
+//     spawns 4 local processes, one island each, exchanging migrants over TCP
+// cargo run --example distributed_islands -- <index> <endpoint> <endpoint> ...
+//     runs the island <index> of the model listening on the given endpoints, e.g. on
+//     several machines with `0 10.0.0.1:7000 10.0.0.2:7000`, or `1 unix:/tmp/a.sock unix:/tmp/b.sock`
+// cargo run --example distributed_islands -- <index>
+//     runs the island <index> on a free local port, which it prints before reading the
+//     endpoints of all the islands from its standard input
+
+use std::{
+    env,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    process::{Command, Stdio},
+};
+
+use genx::{
+    crossover::single_point_crossover,
+    island::{DistributedIsland, EmigrantPolicy, Endpoint, ImmigrantPolicy, Island, Migration, Topology},
+    mutation::flipping_mutation,
+    replacement::mu_plus_lambda_replacement,
+    selection::binary_tournament_selection,
+};
+
+const NUM_ISLANDS: usize = 4;
+const NUM_BITS: usize = 64;
+const TRAP_SIZE: usize = 4;
+const POPULATION_SIZE: usize = 40;
+const GENERATIONS: usize = 300;
+
+// every block of 4 bits leads away from its optimum of all ones
+fn fitness(individual: &[bool]) -> f32 {
+    individual
+        .chunks(TRAP_SIZE)
+        .map(|block| {
+            let ones = block.iter().filter(|&&x| x).count();
+            if ones == TRAP_SIZE {
+                TRAP_SIZE as f32
+            } else {
+                (TRAP_SIZE - 1 - ones) as f32
+            }
+        })
+        .sum()
+}
+
+fn evolve(population: &mut Vec<Vec<bool>>, fitness_values: &mut Vec<f32>, _: usize, _: Option<u64>) {
+    let parents = binary_tournament_selection(fitness_values, POPULATION_SIZE, None);
+    let mut offsprings = vec![];
+    for pair in parents.chunks(2) {
+        let (mut child1, mut child2) = single_point_crossover(&population[pair[0]], &population[pair[1]], None);
+        flipping_mutation(&mut child1, 1.0 / NUM_BITS as f32, None).unwrap();
+        flipping_mutation(&mut child2, 1.0 / NUM_BITS as f32, None).unwrap();
+        offsprings.push((child1.clone(), fitness(&child1)));
+        offsprings.push((child2.clone(), fitness(&child2)));
+    }
+    mu_plus_lambda_replacement(population, fitness_values, offsprings).unwrap();
+}
+
+fn run_island(index: usize, endpoints: Vec<Endpoint>, listener: Option<TcpListener>) {
+    let population = (0..POPULATION_SIZE)
+        .map(|_| {
+            let mut individual = vec![false; NUM_BITS];
+            flipping_mutation(&mut individual, 0.5, None).unwrap();
+            individual
+        })
+        .collect::<Vec<Vec<bool>>>();
+    let fitness_values = population.iter().map(|individual| fitness(individual)).collect();
+    let island = Island::new(population, fitness_values, evolve);
+    let migration = Migration::new(2, 10, EmigrantPolicy::Best, ImmigrantPolicy::ReplaceWorst);
+
+    let mut island = match listener {
+        Some(listener) => DistributedIsland::from_listener(island, index, endpoints, listener, Topology::Ring, migration),
+        None => DistributedIsland::new(island, index, endpoints, Topology::Ring, migration),
+    }
+    .unwrap();
+    island.run(GENERATIONS, Some(42)).unwrap();
+    let (best, best_fitness) = island.island().best().unwrap();
+    let bits = best.iter().map(|&x| if x { '1' } else { '0' }).collect::<String>();
+    println!("island {}: best fitness {} of {} {}", index, best_fitness, NUM_BITS, bits);
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    match args.split_first() {
+        Some((index, [])) => {
+            // keep listening on the free port, so that no other process can take it before the island starts
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            println!("{}", listener.local_addr().unwrap());
+            let mut endpoints = String::new();
+            io::stdin().read_line(&mut endpoints).unwrap();
+            let endpoints = endpoints.split_whitespace().map(|endpoint| endpoint.parse().unwrap()).collect();
+            run_island(index.parse().unwrap(), endpoints, Some(listener));
+            return;
+        }
+        Some((index, endpoints)) => {
+            let endpoints = endpoints.iter().map(|endpoint| endpoint.parse().unwrap()).collect();
+            run_island(index.parse().unwrap(), endpoints, None);
+            return;
+        }
+        None => {}
+    }
+
+    let mut children = (0..NUM_ISLANDS)
+        .map(|index| {
+            Command::new(env::current_exe().unwrap())
+                .arg(index.to_string())
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .spawn()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let mut outputs = children
+        .iter_mut()
+        .map(|child| BufReader::new(child.stdout.take().unwrap()))
+        .collect::<Vec<_>>();
+    let endpoints = outputs
+        .iter_mut()
+        .map(|output| {
+            let mut endpoint = String::new();
+            output.read_line(&mut endpoint).unwrap();
+            endpoint.trim().to_string()
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    for child in children.iter_mut() {
+        writeln!(child.stdin.take().unwrap(), "{}", endpoints).unwrap();
+    }
+    for (mut child, mut output) in children.into_iter().zip(outputs) {
+        let mut result = String::new();
+        output.read_to_string(&mut result).unwrap();
+        child.wait().unwrap();
+        print!("{}", result);
+    }
+}
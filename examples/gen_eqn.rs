@@ -1,7 +1,7 @@
 use genx::{
     crossover::simulated_binary_crossover,
     mutation::polynomial_mutation,
-    selection::{roulette_wheel_selection, stochastic_universal_selection},
+    selection::{roulette_wheel_selection, stochastic_universal_selection, transform_fitness, FitnessTransform, Objective},
 };
 use rand::{distributions::Uniform, prelude::Distribution};
 
@@ -21,25 +21,23 @@ fn main() {
     let mutation_probability = 0.7;
     let between = Uniform::from(-10.0..10.0);
     let mut prng = rand::thread_rng();
-    let fitness_function = |x: f32| 1.0 / ((equation(x) - x).abs() + 1.0);
+    let cost_function = |x: f32| (equation(x) - x).abs();
 
     let mut population = (0..population_size)
         .map(|_| between.sample(&mut prng))
         .collect::<Vec<f32>>();
 
     let get_fitness_values = |population: &Vec<f32>| {
-        population
-            .iter()
-            .map(|x| fitness_function(*x))
-            .collect::<Vec<f32>>()
+        let costs = population.iter().map(|x| cost_function(*x)).collect::<Vec<f32>>();
+        transform_fitness(&costs, Objective::Minimize, FitnessTransform::Inverse).unwrap()
     };
 
     let best_fitness_value = |population: &Vec<f32>| {
         population
             .iter()
-            .max_by(|&a, &b| {
-                fitness_function(*a)
-                    .partial_cmp(&fitness_function(*b))
+            .min_by(|&a, &b| {
+                cost_function(*a)
+                    .partial_cmp(&cost_function(*b))
                     .unwrap_or(std::cmp::Ordering::Equal)
             })
             .unwrap()
@@ -68,7 +66,7 @@ fn main() {
             .map(|&a| population[a])
             .collect::<Vec<f32>>();
         let best = best_fitness_value(&population);
-        if fitness_function(best) > fitness_function(best_now) {
+        if cost_function(best) < cost_function(best_now) {
             best_now = best;
         }
     }
@@ -3,7 +3,8 @@ use std::fmt::{Debug, Display};
 use genx::{
     crossover::uniform_crossover,
     mutation::inversion_mutation,
-    selection::{random_selection, stochastic_universal_selection},
+    replacement::deterministic_crowding,
+    selection::random_selection,
 };
 
 use rand::{distributions::Uniform, prelude::Distribution};
@@ -133,6 +134,11 @@ fn main() {
             .clone()
     };
 
+    let hamming_distance = |a: &Vec<bool>, b: &Vec<bool>| {
+        a.iter().zip(b.iter()).filter(|(x, y)| x != y).count() as f32
+    };
+
+    let mut fitness_values = get_fitness_values(&population);
     let mut best_now = best_fitness_value(&population);
     let between = Uniform::from(0.0..1.0);
     let mut prng = rand::thread_rng();
@@ -147,15 +153,17 @@ fn main() {
         if between.sample(&mut prng) < mutation_probability {
             inversion_mutation(&mut child2, None);
         }
-        population.push(child1);
-        population.push(child2);
-
-        let fitness_values = get_fitness_values(&population);
-        let selected_idx = stochastic_universal_selection(&fitness_values, population_size, None);
-        population = selected_idx
-            .iter()
-            .map(|&a| population[a].clone())
-            .collect::<Vec<Vec<bool>>>();
+        // children only replace their most similar parent, keeping diverse packings around
+        let (fitness1, fitness2) = (fitness_function(&child1), fitness_function(&child2));
+        let offsprings = ((child1, fitness1), (child2, fitness2));
+        deterministic_crowding(
+            &mut population,
+            &mut fitness_values,
+            (idxs[0], idxs[1]),
+            offsprings,
+            hamming_distance,
+        )
+        .unwrap();
         let best = best_fitness_value(&population);
         if fitness_function(&best) > fitness_function(&best_now) {
             best_now = best;
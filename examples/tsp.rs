@@ -4,7 +4,7 @@ use genx::{
     crossover::partially_mapped_crossover,
     mutation::scramble_mutation,
     scaling::linear_scaling,
-    selection::{random_selection, stochastic_universal_selection},
+    selection::{random_selection, stochastic_universal_selection, transform_fitness, FitnessTransform, Objective},
 };
 
 use rand::{distributions::Uniform, prelude::Distribution, seq::SliceRandom, thread_rng};
@@ -48,12 +48,6 @@ fn main() {
         sum
     };
 
-    let fitness_function = |a: &Vec<usize>| {
-        let sum = distance(a);
-
-        1.0 / (sum.sqrt().sqrt() + 1.0)
-    };
-
     let mut population = (0..population_size)
         .map(|_| {
             let mut a = (0..n).collect::<Vec<usize>>();
@@ -63,10 +57,11 @@ fn main() {
         .collect::<Vec<Vec<usize>>>();
 
     let get_fitness_values = |population: &Vec<Vec<usize>>| {
-        population
+        let costs = population
             .iter()
-            .map(|a| fitness_function(a))
-            .collect::<Vec<f32>>()
+            .map(|a| distance(a).sqrt().sqrt())
+            .collect::<Vec<f32>>();
+        transform_fitness(&costs, Objective::Minimize, FitnessTransform::Inverse).unwrap()
     };
 
     let mut best_now = population[0].clone();
@@ -95,14 +90,14 @@ fn main() {
 
         let best = population
             .iter()
-            .max_by(|a, b| {
-                fitness_function(a)
-                    .partial_cmp(&fitness_function(b))
+            .min_by(|a, b| {
+                distance(a)
+                    .partial_cmp(&distance(b))
                     .unwrap_or(Ordering::Equal)
             })
             .unwrap();
 
-        if fitness_function(best) > fitness_function(&best_now) {
+        if distance(best) < distance(&best_now) {
             best_now = best.clone();
         }
     }
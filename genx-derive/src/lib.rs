@@ -0,0 +1,245 @@
+//! **genx-derive** provides `#[derive(Genome)]` for struct shaped solutions
+//! of [genx](https://docs.rs/genx). It is re-exported by genx as
+//! `genx::genome::Genome` when the `derive` feature is enabled.
+//!
+//! Every field is annotated with a `gene` attribute choosing its encoding,
+//! and the generated implementation dispatches to the genx operators for it:
+//!
+//! | Attribute | Field type | Crossover | Mutation |
+//! |-----------|------------|-----------|----------|
+//! | `#[gene(range = 0.0..10.0)]` | `f32`, `f64` | `blend_crossover` | `polynomial_mutation` |
+//! | `#[gene(range = 0..10)]` | integers | `integer_simulated_binary_crossover` | `creep_mutation` |
+//! | `#[gene(permutation)]` | `Vec<usize>` | `order_crossover` | `swap_mutation` |
+//! | `#[gene(bits = 8)]` | integers, `Vec<bool>` | `single_point_crossover` | `flipping_mutation` |
+//!
+//! Values are always kept within their range. Integer ranges should fit in an `i32`.
+//! Crossover and mutation keep the length of permutations, which `random` can only
+//! create when given as `#[gene(permutation, len = 8)]`, and panics otherwise.
+//! Fields without a `gene` attribute should implement `Genome` themselves.
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, ExprRange, Fields, LitInt, Member, RangeLimits,
+    Type,
+};
+
+enum Gene {
+    Real { lower: Expr, upper: Expr },
+    Integer { lower: Expr, upper: Expr },
+    Permutation { len: Option<Expr> },
+    Bits { bits: LitInt, mask: bool },
+    Nested,
+}
+
+#[proc_macro_derive(Genome, attributes(gene))]
+pub fn derive_genome(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(Error::new(Span::call_site(), "Genome can only be derived for structs")),
+    };
+
+    let mut members = Vec::new();
+    let mut genes = Vec::new();
+    for (idx, field) in fields.iter().enumerate() {
+        members.push(match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::from(idx),
+        });
+        genes.push(parse_gene(field)?);
+    }
+
+    let (mut random, mut crossover, mut mutate) = (Vec::new(), Vec::new(), Vec::new());
+    let (mut children1, mut children2) = (Vec::new(), Vec::new());
+    for (idx, ((member, gene), field)) in members.iter().zip(genes.iter()).zip(fields.iter()).enumerate() {
+        let ty = &field.ty;
+        let (child1, child2) = (format_ident!("__child1_{}", idx), format_ident!("__child2_{}", idx));
+        let support = quote!(::genx::genome::derive_support);
+        match gene {
+            Gene::Real { lower, upper } => {
+                random.push(quote!(#support::random_real((#lower) as f32, (#upper) as f32, __seeds.next_seed()) as #ty));
+                crossover.push(quote! {
+                    let (#child1, #child2) = #support::crossover_real(
+                        self.#member as f32, other.#member as f32, (#lower) as f32, (#upper) as f32, __seeds.next_seed());
+                    let (#child1, #child2) = (#child1 as #ty, #child2 as #ty);
+                });
+                mutate.push(quote! {
+                    self.#member = #support::mutate_real(
+                        self.#member as f32, (#lower) as f32, (#upper) as f32, &mut __seeds, mutation_probability) as #ty;
+                });
+            }
+            Gene::Integer { lower, upper } => {
+                random.push(quote!(#support::random_integer((#lower) as i32, (#upper) as i32, __seeds.next_seed()) as #ty));
+                crossover.push(quote! {
+                    let (#child1, #child2) = #support::crossover_integer(
+                        self.#member as i32, other.#member as i32, (#lower) as i32, (#upper) as i32, __seeds.next_seed());
+                    let (#child1, #child2) = (#child1 as #ty, #child2 as #ty);
+                });
+                mutate.push(quote! {
+                    self.#member = #support::mutate_integer(
+                        self.#member as i32, (#lower) as i32, (#upper) as i32, &mut __seeds, mutation_probability) as #ty;
+                });
+            }
+            Gene::Permutation { len } => {
+                random.push(match len {
+                    Some(len) => quote!(#support::random_permutation(#len, __seeds.next_seed())),
+                    None => {
+                        let message = format!("`{}` is a `permutation` without `len`, which can not be created randomly", quote!(#member));
+                        quote!(::std::panic!(#message))
+                    }
+                });
+                crossover.push(quote! {
+                    let (#child1, #child2) = #support::crossover_permutation(&self.#member, &other.#member, __seeds.next_seed());
+                });
+                mutate.push(quote!(#support::mutate_permutation(&mut self.#member, &mut __seeds, mutation_probability);));
+            }
+            Gene::Bits { bits, mask: true } => {
+                random.push(quote!(#support::random_mask(#bits, __seeds.next_seed())));
+                crossover.push(quote! {
+                    let (#child1, #child2) = #support::crossover_mask(&self.#member, &other.#member, __seeds.next_seed());
+                });
+                mutate.push(quote!(#support::mutate_mask(&mut self.#member, &mut __seeds, mutation_probability);));
+            }
+            Gene::Bits { bits, mask: false } => {
+                random.push(quote!(#support::random_bits(#bits, __seeds.next_seed()) as #ty));
+                crossover.push(quote! {
+                    let (#child1, #child2) = #support::crossover_bits(
+                        self.#member as u64, other.#member as u64, #bits, __seeds.next_seed());
+                    let (#child1, #child2) = (#child1 as #ty, #child2 as #ty);
+                });
+                mutate.push(quote! {
+                    self.#member = #support::mutate_bits(self.#member as u64, #bits, &mut __seeds, mutation_probability) as #ty;
+                });
+            }
+            Gene::Nested => {
+                random.push(quote!(<#ty as ::genx::genome::Genome>::random(__seeds.next_seed())));
+                crossover.push(quote! {
+                    let (#child1, #child2) = ::genx::genome::Genome::crossover(&self.#member, &other.#member, __seeds.next_seed());
+                });
+                mutate.push(quote! {
+                    ::genx::genome::Genome::mutate(&mut self.#member, mutation_probability, __seeds.next_seed());
+                });
+            }
+        }
+        children1.push(child1);
+        children2.push(child2);
+    }
+
+    let construct = |values: Vec<TokenStream2>| match fields {
+        Fields::Named(_) => quote!(Self { #(#members: #values),* }),
+        Fields::Unnamed(_) => quote!(Self(#(#values),*)),
+        Fields::Unit => quote!(Self),
+    };
+    let random = construct(random);
+    let child1 = construct(children1.iter().map(|child| quote!(#child)).collect());
+    let child2 = construct(children2.iter().map(|child| quote!(#child)).collect());
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::genx::genome::Genome for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables, unreachable_code)]
+            fn random(seed: ::std::option::Option<u64>) -> Self {
+                let mut __seeds = ::genx::genome::derive_support::Seeds::new(seed);
+                #random
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn crossover(&self, other: &Self, seed: ::std::option::Option<u64>) -> (Self, Self) {
+                let mut __seeds = ::genx::genome::derive_support::Seeds::new(seed);
+                #(#crossover)*
+                (#child1, #child2)
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn mutate(&mut self, mutation_probability: f32, seed: ::std::option::Option<u64>) {
+                let mut __seeds = ::genx::genome::derive_support::Seeds::new(seed);
+                #(#mutate)*
+            }
+        }
+    })
+}
+
+fn parse_gene(field: &syn::Field) -> Result<Gene, Error> {
+    let mut gene = None;
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("gene")) {
+        if gene.is_some() {
+            return Err(Error::new(attr.span(), "only one `gene` attribute is allowed per field"));
+        }
+        let (mut range, mut permutation, mut len, mut bits) = (None, false, None, None);
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("range") {
+                range = Some(meta.value()?.parse::<ExprRange>()?);
+            } else if meta.path.is_ident("permutation") {
+                permutation = true;
+            } else if meta.path.is_ident("len") {
+                len = Some(meta.value()?.parse::<Expr>()?);
+            } else if meta.path.is_ident("bits") {
+                bits = Some(meta.value()?.parse::<LitInt>()?);
+            } else {
+                return Err(meta.error("expected `range`, `permutation`, `len` or `bits`"));
+            }
+            Ok(())
+        })?;
+
+        gene = Some(match (range, permutation, bits) {
+            (Some(range), false, None) => parse_range(&field.ty, range)?,
+            (None, true, None) => Gene::Permutation { len },
+            (None, false, Some(bits)) => {
+                let count = bits.base10_parse::<usize>()?;
+                let mask = is_vec(&field.ty);
+                if count == 0 || (!mask && count > 64) {
+                    return Err(Error::new(bits.span(), "`bits` should lie between 1 and 64 for integer fields"));
+                }
+                Gene::Bits { bits, mask }
+            }
+            _ => {
+                return Err(Error::new(
+                    attr.span(),
+                    "expected exactly one of `range = ...`, `permutation` or `bits = ...`",
+                ))
+            }
+        });
+    }
+
+    Ok(gene.unwrap_or(Gene::Nested))
+}
+
+fn parse_range(ty: &Type, range: ExprRange) -> Result<Gene, Error> {
+    let span = range.span();
+    let (lower, upper) = match (range.start, range.end) {
+        (Some(lower), Some(upper)) => (*lower, *upper),
+        _ => return Err(Error::new(span, "`range` requires both a lower and an upper bound")),
+    };
+    if is_float(ty) {
+        return Ok(Gene::Real { lower, upper });
+    }
+    let upper = match range.limits {
+        RangeLimits::HalfOpen(_) => syn::parse_quote!((#upper) - 1),
+        RangeLimits::Closed(_) => upper,
+    };
+    Ok(Gene::Integer { lower, upper })
+}
+
+fn last_segment(ty: &Type) -> Option<&syn::PathSegment> {
+    match ty {
+        Type::Path(path) => path.path.segments.last(),
+        _ => None,
+    }
+}
+
+fn is_float(ty: &Type) -> bool {
+    last_segment(ty).is_some_and(|segment| segment.ident == "f32" || segment.ident == "f64")
+}
+
+fn is_vec(ty: &Type) -> bool {
+    last_segment(ty).is_some_and(|segment| segment.ident == "Vec")
+}
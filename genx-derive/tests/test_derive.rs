@@ -0,0 +1,122 @@
+extern crate genx;
+
+#[cfg(test)]
+mod tests {
+  use genx::genome::Genome;
+
+  #[derive(Genome, Clone, Debug, PartialEq)]
+  struct Machine {
+    #[gene(range = 0.5..2.0)]
+    speed: f32,
+    #[gene(range = 1..=8)]
+    workers: u16,
+  }
+
+  #[derive(Genome, Clone, Debug, PartialEq)]
+  struct Schedule {
+    #[gene(permutation, len = 6)]
+    order: Vec<usize>,
+    #[gene(bits = 4)]
+    machines: u8,
+    #[gene(bits = 10)]
+    enabled: Vec<bool>,
+    machine: Machine,
+  }
+
+  #[derive(Genome, Clone, Debug, PartialEq)]
+  struct Point(#[gene(range = -1.0..1.0)] f64, #[gene(range = -5..5)] i64);
+
+  #[derive(Genome, Clone, Debug, PartialEq)]
+  struct Wide(#[gene(range = -2_000_000_000..=2_000_000_000)] i32, #[gene(bits = 3)] u8);
+
+  #[derive(Genome, Clone, Debug, PartialEq)]
+  struct Route {
+    #[gene(permutation)]
+    stops: Vec<usize>,
+    #[gene(range = 0.0..1.0)]
+    speed: f32,
+  }
+
+  fn check_schedule(schedule: &Schedule) {
+    let mut order = schedule.order.clone();
+    order.sort();
+    assert_eq!(order, (0..6).collect::<Vec<usize>>());
+    assert!(schedule.machines < 16);
+    assert_eq!(schedule.enabled.len(), 10);
+    assert!((0.5..=2.0).contains(&schedule.machine.speed));
+    assert!((1..=8).contains(&schedule.machine.workers));
+  }
+
+  #[test]
+  fn test_derive_random() {
+    let schedule = Schedule::random(Some(42));
+    check_schedule(&schedule);
+    assert_eq!(schedule, Schedule::random(Some(42)));
+    assert_ne!(schedule, Schedule::random(Some(43)));
+  }
+
+  #[test]
+  fn test_derive_crossover() {
+    let (parent1, parent2) = (Schedule::random(Some(1)), Schedule::random(Some(2)));
+    let (child1, child2) = parent1.crossover(&parent2, Some(42));
+    check_schedule(&child1);
+    check_schedule(&child2);
+    assert_eq!((child1, child2), parent1.crossover(&parent2, Some(42)));
+  }
+
+  #[test]
+  fn test_derive_mutate() {
+    let original = Schedule::random(Some(1));
+    let mut schedule = original.clone();
+    schedule.mutate(0.0, Some(42));
+    assert_eq!(schedule, original);
+
+    schedule.mutate(1.0, Some(42));
+    check_schedule(&schedule);
+    assert_ne!(schedule, original);
+    assert!(schedule.enabled.iter().zip(original.enabled.iter()).all(|(x, y)| x != y));
+  }
+
+  #[test]
+  fn test_derive_tuple_struct() {
+    for seed in 0..20 {
+      let mut point = Point::random(Some(seed));
+      point.mutate(1.0, Some(seed));
+      let (child, _) = point.crossover(&Point::random(None), Some(seed));
+      for Point(x, y) in [point, child].iter() {
+        assert!((-1.0..=1.0).contains(x));
+        assert!((-5..5).contains(y));
+      }
+    }
+  }
+
+  #[test]
+  fn test_derive_wide_range() {
+    for seed in 0..20 {
+      let mut wide = Wide(2_000_000_000 - seed as i32, 0);
+      // probabilities above 1 are clamped for integers as for bits
+      wide.mutate(1.5, Some(seed));
+      assert!((-2_000_000_000..=2_000_000_000).contains(&wide.0));
+      assert!(wide.1 < 8);
+    }
+  }
+
+  #[test]
+  fn test_derive_permutation_without_len() {
+    let parent1 = Route { stops: vec![3, 0, 4, 1, 2], speed: 0.5 };
+    let parent2 = Route { stops: vec![1, 2, 0, 4, 3], speed: 0.2 };
+    let (mut child1, child2) = parent1.crossover(&parent2, Some(42));
+    child1.mutate(1.0, Some(42));
+    for child in [child1, child2].iter() {
+      let mut stops = child.stops.clone();
+      stops.sort();
+      assert_eq!(stops, (0..5).collect::<Vec<usize>>());
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "`stops` is a `permutation` without `len`")]
+  fn test_derive_random_permutation_without_len() {
+    Route::random(None);
+  }
+}
@@ -0,0 +1,63 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::check_length;
+
+/**
+## Description:
+BLX-ɑ-β crossover samples every gene of the offsprings uniformly from an interval around the genes
+of the parents. With `d` the distance between the genes, the interval is extended by ɑd on the side
+of the better parent and by βd on the side of the worse parent, favouring the better one when ɑ > β.
+
+### Note:
+- `parent1` should be the better of the two parents.
+- `bounds` should contain one `(lower, upper)` pair per gene, offsprings are clamped to them.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a tuple containing two offsprings of type `Vec<f32>`
+
+## Example:
+```rust
+use genx::crossover::blend_alpha_beta_crossover;
+
+let parent1 = vec![1.0, 2.0, 3.0];
+let parent2 = vec![2.0, 2.0, 1.0];
+let bounds = vec![(0.0, 4.0); 3];
+let (child1, child2) = blend_alpha_beta_crossover(&parent1, &parent2, 0.75, 0.25, &bounds, None);
+assert!(child1[0] >= 0.25 && child1[0] <= 2.25);
+```
+ */
+pub fn blend_alpha_beta_crossover(
+    parent1: &Vec<f32>,
+    parent2: &Vec<f32>,
+    alpha: f32,
+    beta: f32,
+    bounds: &Vec<(f32, f32)>,
+    seed: Option<u64>,
+) -> (Vec<f32>, Vec<f32>) {
+    check_length(parent1, parent2);
+    if bounds.len() != parent1.len() {
+        panic!("Bounds must be provided for every gene");
+    }
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let n = parent1.len();
+    let (mut child1, mut child2) = (Vec::with_capacity(n), Vec::with_capacity(n));
+    for ((&better, &worse), &(lower, upper)) in parent1.iter().zip(parent2.iter()).zip(bounds.iter()) {
+        let distance = (better - worse).abs();
+        let (low, high) = if better <= worse {
+            (better - alpha * distance, worse + beta * distance)
+        } else {
+            (worse - beta * distance, better + alpha * distance)
+        };
+        let mut sample = || (low + prng.gen::<f32>() * (high - low)).clamp(lower, upper);
+        child1.push(sample());
+        child2.push(sample());
+    }
+
+    (child1, child2)
+}
@@ -0,0 +1,105 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::genome::{CompositeGenome, Segment, SegmentCrossover, SegmentOperator};
+
+/**
+## Description:
+Composite crossover recombines two individuals made of several typed segments. Every segment is
+recombined with the crossover of the matching `SegmentOperator` with probability `crossover_rate`,
+otherwise the segment is copied over from the parents unchanged. Real segments are recombined gene by gene.
+
+### Note:
+- `operators` should contain one operator for every segment, with the same kind as the segment, otherwise the function panics.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a tuple containing two offsprings of type `CompositeGenome`
+
+## Example:
+```rust
+use genx::{
+    crossover::{blend_crossover, composite_crossover, order_crossover},
+    genome::{CompositeGenome, Segment, SegmentCrossover, SegmentMutation, SegmentOperator},
+    mutation::polynomial_mutation,
+};
+
+let operators = vec![
+    SegmentOperator::new(
+        SegmentCrossover::permutation(order_crossover),
+        1.0,
+        SegmentMutation::permutation(|_, _| ()),
+        0.0,
+    ),
+    SegmentOperator::new(
+        SegmentCrossover::real(|parent1, parent2, seed| blend_crossover(parent1, parent2, 0.5, seed)),
+        1.0,
+        SegmentMutation::real(|gene, seed| polynomial_mutation(gene, 20.0, 0.5, seed)),
+        0.1,
+    ),
+];
+let parent1 = CompositeGenome::new(vec![Segment::Permutation(vec![1, 3, 4, 0, 2]), Segment::Real(vec![0.5, 2.0])]);
+let parent2 = CompositeGenome::new(vec![Segment::Permutation(vec![2, 3, 0, 4, 1]), Segment::Real(vec![1.5, 1.0])]);
+let (child1, child2) = composite_crossover(&parent1, &parent2, &operators, None);
+```
+ */
+pub fn composite_crossover(
+    parent1: &CompositeGenome,
+    parent2: &CompositeGenome,
+    operators: &Vec<SegmentOperator>,
+    seed: Option<u64>,
+) -> (CompositeGenome, CompositeGenome) {
+    if parent1.segments.len() != parent2.segments.len() || parent1.segments.len() != operators.len() {
+        panic!("Parents and operators must have the same number of segments");
+    }
+    let segments = parent1.segments.iter().zip(parent2.segments.iter()).zip(operators.iter());
+    if segments.clone().any(|((segment1, segment2), operator)| !operator.crossover.matches(segment1) || !operator.crossover.matches(segment2)) {
+        panic!("Segment kinds must match their operators");
+    }
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let n = operators.len();
+    let (mut child1, mut child2) = (Vec::with_capacity(n), Vec::with_capacity(n));
+    for ((segment1, segment2), operator) in segments {
+        if prng.gen_range(0.0..1.0) >= operator.crossover_rate {
+            child1.push(segment1.clone());
+            child2.push(segment2.clone());
+            continue;
+        }
+
+        let mut next_seed = || seed.map(|_| prng.gen::<u64>());
+        let (offspring1, offspring2) = match (&operator.crossover, segment1, segment2) {
+            (SegmentCrossover::Binary(crossover), Segment::Binary(genes1), Segment::Binary(genes2)) => {
+                let (offspring1, offspring2) = crossover(genes1, genes2, next_seed());
+                (Segment::Binary(offspring1), Segment::Binary(offspring2))
+            }
+            (SegmentCrossover::Integer(crossover), Segment::Integer(genes1), Segment::Integer(genes2)) => {
+                let (offspring1, offspring2) = crossover(genes1, genes2, next_seed());
+                (Segment::Integer(offspring1), Segment::Integer(offspring2))
+            }
+            (SegmentCrossover::Real(crossover), Segment::Real(genes1), Segment::Real(genes2)) => {
+                if genes1.len() != genes2.len() {
+                    panic!("Vectors must be the same length");
+                }
+                let (offspring1, offspring2) = genes1
+                    .iter()
+                    .zip(genes2.iter())
+                    .map(|(&val1, &val2)| crossover(val1, val2, next_seed()))
+                    .unzip();
+                (Segment::Real(offspring1), Segment::Real(offspring2))
+            }
+            (SegmentCrossover::Permutation(crossover), Segment::Permutation(genes1), Segment::Permutation(genes2)) => {
+                let (offspring1, offspring2) = crossover(genes1, genes2, next_seed());
+                (Segment::Permutation(offspring1), Segment::Permutation(offspring2))
+            }
+            _ => unreachable!(),
+        };
+        child1.push(offspring1);
+        child2.push(offspring2);
+    }
+
+    (CompositeGenome::new(child1), CompositeGenome::new(child2))
+}
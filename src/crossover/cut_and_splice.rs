@@ -0,0 +1,41 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/**
+## Description:
+Cut and splice crossover picks a separate crossover point in each parent and exchanges the tails
+after those points. As the points differ the offsprings can have lengths different from their
+parents, which makes it suitable for variable length individuals.
+
+### Note:
+- Parents need not be of the same length.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a tuple containing two offsprings of type `Vec<T>`, whose lengths add up to the lengths of the parents.
+
+## Example:
+```rust
+use genx::crossover::cut_and_splice_crossover;
+
+let parent1 = vec![1, 2, 3, 4, 5, 6, 7];
+let parent2 = vec![10, 20, 30];
+let (child1, child2) = cut_and_splice_crossover(&parent1, &parent2, None);
+assert_eq!(child1.len() + child2.len(), 10);
+```
+ */
+pub fn cut_and_splice_crossover<T>(parent1: &Vec<T>, parent2: &Vec<T>, seed: Option<u64>) -> (Vec<T>, Vec<T>)
+where
+    T: Clone,
+{
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let cut1 = prng.gen_range(0..=parent1.len());
+    let cut2 = prng.gen_range(0..=parent2.len());
+
+    let child1 = parent1[..cut1].iter().chain(parent2[cut2..].iter()).cloned().collect();
+    let child2 = parent2[..cut2].iter().chain(parent1[cut1..].iter()).cloned().collect();
+    (child1, child2)
+}
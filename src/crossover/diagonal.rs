@@ -0,0 +1,58 @@
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+
+use super::check_parents;
+
+/**
+## Description:
+Diagonal crossover is a multi parent generalisation of the multi point crossover. For `n` parents it
+picks `n - 1` crossover points, splitting every parent into `n` segments. The `i`th offspring takes
+its `j`th segment from parent `(i + j) mod n`, so the segments are combined along the diagonals.
+
+### Note:
+- All parents should be of the same length and there should be atleast two of them.
+- If the parents are shorter than their count, fewer crossover points are used.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a `Vec<Vec<T>>` containing one offspring per parent.
+
+## Example:
+```rust
+use genx::crossover::diagonal_crossover;
+
+let parents = vec![vec![1, 1, 1, 1, 1, 1], vec![2, 2, 2, 2, 2, 2], vec![3, 3, 3, 3, 3, 3]];
+let children = diagonal_crossover(&parents, None);
+assert_eq!(children.len(), 3);
+```
+ */
+pub fn diagonal_crossover<T>(parents: &Vec<Vec<T>>, seed: Option<u64>) -> Vec<Vec<T>>
+where
+    T: Copy,
+{
+    check_parents(parents, 2);
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let (num_parents, n) = (parents.len(), parents[0].len());
+    let num_points = (num_parents - 1).min(n.saturating_sub(1));
+    let mut points = sample(&mut prng, n.saturating_sub(1), num_points)
+        .iter()
+        .map(|x| x + 1)
+        .collect::<Vec<usize>>();
+    points.sort_unstable();
+    points.insert(0, 0);
+    points.push(n);
+
+    (0..num_parents)
+        .map(|i| {
+            let mut child = Vec::with_capacity(n);
+            for (j, segment) in points.windows(2).enumerate() {
+                child.extend_from_slice(&parents[(i + j) % num_parents][segment[0]..segment[1]]);
+            }
+            child
+        })
+        .collect()
+}
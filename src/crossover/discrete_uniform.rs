@@ -0,0 +1,47 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::check_length;
+
+/**
+## Description:
+Discrete uniform recombination creates every gene of each offspring by copying the gene at the same
+position from one of the two parents, picked uniformly at random. Unlike `uniform_crossover` the two
+offsprings are sampled independently, so they need not be complementary.
+
+### Note:
+- The function works for any gene type, which makes it suitable for integer encoded individuals.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a tuple containing two offsprings of type `Vec<T>`
+
+## Example:
+```rust
+use genx::crossover::discrete_uniform_crossover;
+
+let parent1 = vec![1, 8, 4, 0, 6];
+let parent2 = vec![5, 2, 4, 9, 7];
+let (child1, child2) = discrete_uniform_crossover(&parent1, &parent2, None);
+```
+ */
+pub fn discrete_uniform_crossover<T>(parent1: &Vec<T>, parent2: &Vec<T>, seed: Option<u64>) -> (Vec<T>, Vec<T>)
+where
+    T: Copy,
+{
+    check_length(parent1, parent2);
+
+    let mut prng = match seed {
+        Some(val) => StdRng::seed_from_u64(val),
+        None => StdRng::from_entropy(),
+    };
+
+    let mut pick = |val1: T, val2: T| if prng.gen::<bool>() { val1 } else { val2 };
+    let n = parent1.len();
+    let (mut child1, mut child2) = (Vec::with_capacity(n), Vec::with_capacity(n));
+    for (&val1, &val2) in parent1.iter().zip(parent2.iter()) {
+        child1.push(pick(val1, val2));
+        child2.push(pick(val1, val2));
+    }
+
+    (child1, child2)
+}
@@ -0,0 +1,118 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{check_length, heuristic_crossover};
+
+/**
+## Description:
+`FitnessAwareCrossover` is implemented by crossovers that need the fitness of the parents in addition
+to their genes. Each parent is passed as a `(individual, fitness)` pair, where a higher fitness denotes
+a better parent.
+
+It is implemented for every function or closure with the matching signature, such as
+`fitness_biased_uniform_crossover`, and for [`HeuristicCrossover`].
+
+## Example:
+```rust
+use genx::crossover::{fitness_biased_uniform_crossover, FitnessAwareCrossover, HeuristicCrossover};
+
+fn breed<T, C: FitnessAwareCrossover<T>>(crossover: &C, population: &Vec<T>, fitness_values: &Vec<f32>) -> (T, T) {
+    crossover.crossover((&population[0], fitness_values[0]), (&population[1], fitness_values[1]), None)
+}
+
+let population = vec![vec![1.0, 2.0, 3.0], vec![2.0, 2.0, 1.0]];
+let fitness_values = vec![0.2, 0.8];
+let (child1, child2) = breed(&fitness_biased_uniform_crossover, &population, &fitness_values);
+let (child1, child2) = breed(&HeuristicCrossover::new(None), &population, &fitness_values);
+```
+ */
+pub trait FitnessAwareCrossover<T> {
+    fn crossover(&self, parent1: (&T, f32), parent2: (&T, f32), seed: Option<u64>) -> (T, T);
+}
+
+impl<T, F> FitnessAwareCrossover<T> for F
+where
+    F: Fn((&T, f32), (&T, f32), Option<u64>) -> (T, T),
+{
+    fn crossover(&self, parent1: (&T, f32), parent2: (&T, f32), seed: Option<u64>) -> (T, T) {
+        self(parent1, parent2, seed)
+    }
+}
+
+/// `heuristic_crossover` as a [`FitnessAwareCrossover`], both offsprings are extrapolated independently.
+pub struct HeuristicCrossover {
+    pub bounds: Option<Vec<(f32, f32)>>,
+}
+
+impl HeuristicCrossover {
+    pub fn new(bounds: Option<Vec<(f32, f32)>>) -> Self {
+        HeuristicCrossover { bounds }
+    }
+}
+
+impl FitnessAwareCrossover<Vec<f32>> for HeuristicCrossover {
+    fn crossover(&self, parent1: (&Vec<f32>, f32), parent2: (&Vec<f32>, f32), seed: Option<u64>) -> (Vec<f32>, Vec<f32>) {
+        let mut prng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let mut next_seed = || seed.map(|_| prng.gen::<u64>());
+        (
+            heuristic_crossover(parent1, parent2, self.bounds.as_ref(), next_seed()),
+            heuristic_crossover(parent1, parent2, self.bounds.as_ref(), next_seed()),
+        )
+    }
+}
+
+/**
+## Description:
+Fitness biased uniform crossover is a uniform crossover where the first offspring takes each gene from
+the first parent with probability f<sub>1</sub> / (f<sub>1</sub> + f<sub>2</sub>) and from the second parent otherwise.
+The second offspring receives the genes not taken by the first one, so fitter parents pass on more genes.
+
+### Note:
+- Negative fitness values are treated as zero, if both are zero each parent is equally likely.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a tuple containing two offsprings of type `Vec<T>`
+
+## Example:
+```rust
+use genx::crossover::fitness_biased_uniform_crossover;
+
+let parent1 = vec![true, false, false, true, true, false, false, true];
+let parent2 = vec![true, true, true, false, true, false, true, true];
+let (child1, child2) = fitness_biased_uniform_crossover((&parent1, 3.0), (&parent2, 1.0), None);
+```
+ */
+pub fn fitness_biased_uniform_crossover<T>(
+    parent1: (&Vec<T>, f32),
+    parent2: (&Vec<T>, f32),
+    seed: Option<u64>,
+) -> (Vec<T>, Vec<T>)
+where
+    T: Clone,
+{
+    check_length(parent1.0, parent2.0);
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let (fitness1, fitness2) = (parent1.1.max(0.0), parent2.1.max(0.0));
+    let bias = if fitness1 + fitness2 > 0.0 {
+        fitness1 / (fitness1 + fitness2)
+    } else {
+        0.5
+    };
+
+    let (mut child1, mut child2) = (parent1.0.clone(), parent2.0.clone());
+    for (val1, val2) in child1.iter_mut().zip(child2.iter_mut()) {
+        if prng.gen::<f32>() >= bias {
+            std::mem::swap(val1, val2);
+        }
+    }
+
+    (child1, child2)
+}
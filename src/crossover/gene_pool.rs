@@ -0,0 +1,46 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::check_parents;
+
+/**
+## Description:
+Gene pool recombination treats the genes of all the `parents` at a position as a common pool.
+For every position of the offspring two parents are drawn afresh from the pool and the gene is
+set to a random point on the line between their genes:
+    C<sub>i</sub> = P<sub>a,i</sub> + r(P<sub>b,i</sub> - P<sub>a,i</sub>)
+    Here, r is a random value between 0 and 1.
+
+### Note:
+- All parents should be of the same length and there should be atleast two of them.
+- For discrete genes use `scanning_crossover` with `ScanningStrategy::Uniform`, which draws from the pool directly.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is the offspring of type `Vec<f32>`
+
+## Example:
+```rust
+use genx::crossover::gene_pool_crossover;
+
+let parents = vec![vec![0.0, 1.0, 2.0], vec![1.0, 1.0, 4.0], vec![0.5, 1.0, 3.0]];
+let child = gene_pool_crossover(&parents, None);
+assert!(child[0] >= 0.0 && child[0] <= 1.0);
+assert_eq!(child[1], 1.0);
+```
+ */
+pub fn gene_pool_crossover(parents: &Vec<Vec<f32>>, seed: Option<u64>) -> Vec<f32> {
+    check_parents(parents, 2);
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let num_parents = parents.len();
+    (0..parents[0].len())
+        .map(|i| {
+            let (a, b) = (prng.gen_range(0..num_parents), prng.gen_range(0..num_parents));
+            parents[a][i] + prng.gen::<f32>() * (parents[b][i] - parents[a][i])
+        })
+        .collect()
+}
@@ -0,0 +1,65 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::check_length;
+
+/**
+## Description:
+Wright's heuristic crossover uses the fitness of the parents to extrapolate beyond the better parent,
+in the direction pointing away from the worse one:
+    C = P<sub>best</sub> + r(P<sub>best</sub> - P<sub>worst</sub>)
+    Here, r is a random value between 0 and 1.
+
+### Note:
+- A higher fitness value denotes a better parent.
+- `bounds` can optionally contain one `(lower, upper)` pair per gene that the offspring is clamped to.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is the single offspring of type `Vec<f32>`
+
+## Example:
+```rust
+use genx::crossover::heuristic_crossover;
+
+let parent1 = vec![1.0, 2.0, 3.0];
+let parent2 = vec![2.0, 2.0, 1.0];
+let child = heuristic_crossover((&parent1, 0.4), (&parent2, 0.9), None, None);
+assert!(child[0] >= 2.0 && child[2] <= 1.0);
+```
+ */
+pub fn heuristic_crossover(
+    parent1: (&Vec<f32>, f32),
+    parent2: (&Vec<f32>, f32),
+    bounds: Option<&Vec<(f32, f32)>>,
+    seed: Option<u64>,
+) -> Vec<f32> {
+    check_length(parent1.0, parent2.0);
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let (best, worst) = if parent1.1 >= parent2.1 {
+        (parent1.0, parent2.0)
+    } else {
+        (parent2.0, parent1.0)
+    };
+    let ratio = prng.gen::<f32>();
+    let mut child = best
+        .iter()
+        .zip(worst.iter())
+        .map(|(&b, &w)| b + ratio * (b - w))
+        .collect::<Vec<f32>>();
+
+    if let Some(bounds) = bounds {
+        if bounds.len() != child.len() {
+            panic!("Bounds must be provided for every gene");
+        }
+        for (x, &(lower, upper)) in child.iter_mut().zip(bounds.iter()) {
+            *x = x.clamp(lower, upper);
+        }
+    }
+
+    child
+}
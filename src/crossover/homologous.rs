@@ -0,0 +1,46 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/**
+## Description:
+Homologous crossover exchanges a segment found at the same positions in both parents. The two
+crossover points are picked within the length of the shorter parent, so genes are only ever exchanged
+with the genes at the same loci and both offsprings keep the length of the parent they were copied from.
+
+### Note:
+- Parents need not be of the same length.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a tuple containing two offsprings of type `Vec<T>` with the lengths of `parent1` and `parent2`.
+
+## Example:
+```rust
+use genx::crossover::homologous_crossover;
+
+let parent1 = vec![1, 2, 3, 4, 5, 6, 7];
+let parent2 = vec![10, 20, 30];
+let (child1, child2) = homologous_crossover(&parent1, &parent2, None);
+assert_eq!((child1.len(), child2.len()), (7, 3));
+assert_eq!(child1[3..], parent1[3..]);
+```
+ */
+pub fn homologous_crossover<T>(parent1: &Vec<T>, parent2: &Vec<T>, seed: Option<u64>) -> (Vec<T>, Vec<T>)
+where
+    T: Clone,
+{
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let n = parent1.len().min(parent2.len());
+    let (mut start, mut end) = (prng.gen_range(0..=n), prng.gen_range(0..=n));
+    if end < start {
+        std::mem::swap(&mut start, &mut end);
+    }
+
+    let (mut child1, mut child2) = (parent1.clone(), parent2.clone());
+    child1[start..end].clone_from_slice(&parent2[start..end]);
+    child2[start..end].clone_from_slice(&parent1[start..end]);
+    (child1, child2)
+}
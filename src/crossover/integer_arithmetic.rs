@@ -0,0 +1,38 @@
+use super::check_length;
+
+/**
+## Description:
+Integer arithmetic crossover takes the weighted average of every gene of two integer encoded
+parents and rounds it to the nearest integer:
+    C1 = round((1-ɑ)P1 + ɑP2)
+    C2 = round((1-ɑ)P2 + ɑP1)
+
+### Note:
+- The function takes a `f32` parameter `alpha` in the range [0.0 - 1.0] denoting the weight of the other parent.
+- `alpha` follows the same convention as `whole_arithmetic_crossover`.
+- As both offsprings lie between the parents, they never leave the bounds the parents lie in.
+
+## Return:
+The return value is a tuple containing two offsprings of type `Vec<i32>`
+
+## Example:
+```rust
+use genx::crossover::integer_arithmetic_crossover;
+
+let parent1 = vec![1, 8, 4, 0, 6];
+let parent2 = vec![5, 2, 4, 9, 7];
+let (child1, child2) = integer_arithmetic_crossover(&parent1, &parent2, 0.3);
+assert_eq!(child1, vec![2, 6, 4, 3, 6]);
+assert_eq!(child2, vec![4, 4, 4, 6, 7]);
+```
+ */
+pub fn integer_arithmetic_crossover(parent1: &Vec<i32>, parent2: &Vec<i32>, alpha: f32) -> (Vec<i32>, Vec<i32>) {
+    check_length(parent1, parent2);
+
+    let combine = |a: i32, b: i32| ((1.0 - alpha) * a as f32 + alpha * b as f32).round() as i32;
+    parent1
+        .iter()
+        .zip(parent2.iter())
+        .map(|(&val1, &val2)| (combine(val1, val2), combine(val2, val1)))
+        .unzip()
+}
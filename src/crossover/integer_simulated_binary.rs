@@ -0,0 +1,65 @@
+use rand::{prelude::StdRng, Rng, SeedableRng};
+
+use super::check_length;
+use crate::mutation::check_bounds;
+
+/**
+## Description:
+Integer simulated binary crossover applies the simulated binary crossover independently to every
+gene of two integer encoded parents. The real valued offsprings are rounded to the nearest integer
+and clamped to the inclusive `(lower, upper)` bounds of that gene.
+
+### Note:
+- The function takes a `f32` parameter `operator` denoting SBX crossover distribution factor.
+- `bounds` should contain one `(lower, upper)` pair for every gene of the parents.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a `Result<(Vec<i32>, Vec<i32>), &'static str>` containing the two offsprings, which will return error
+if `bounds` does not contain a pair for every gene or if a lower bound is greater than its upper bound.
+
+## Example:
+```rust
+use genx::crossover::integer_simulated_binary_crossover;
+
+let parent1 = vec![1, 8, 4, 0, 6];
+let parent2 = vec![5, 2, 4, 9, 7];
+let bounds = vec![(0, 9); parent1.len()];
+let (child1, child2) = integer_simulated_binary_crossover(&parent1, &parent2, &bounds, 2.0, None).unwrap();
+```
+ */
+pub fn integer_simulated_binary_crossover(
+    parent1: &Vec<i32>,
+    parent2: &Vec<i32>,
+    bounds: &Vec<(i32, i32)>,
+    operator: f32,
+    seed: Option<u64>,
+) -> Result<(Vec<i32>, Vec<i32>), &'static str> {
+    check_length(parent1, parent2);
+    check_bounds(parent1, bounds)?;
+
+    let mut prng = match seed {
+        Some(val) => StdRng::seed_from_u64(val),
+        None => StdRng::from_entropy(),
+    };
+
+    let beta = |ui: f32| {
+        if ui <= 0.5 {
+            (2.0 * ui).powf(1.0 / (operator + 1.0))
+        } else {
+            (1.0 / (2.0 * (1.0 - ui))).powf(1.0 / (operator + 1.0))
+        }
+    };
+
+    let n = parent1.len();
+    let (mut child1, mut child2) = (Vec::with_capacity(n), Vec::with_capacity(n));
+    for i in 0..n {
+        let (val1, val2) = (parent1[i] as f32, parent2[i] as f32);
+        let (lower, upper) = bounds[i];
+        let beta_ui = beta(prng.gen::<f32>());
+        child1.push(((0.5 * ((val1 + val2) - beta_ui * (val1 - val2))).round() as i32).clamp(lower, upper));
+        child2.push(((0.5 * ((val1 + val2) + beta_ui * (val1 - val2))).round() as i32).clamp(lower, upper));
+    }
+
+    Ok((child1, child2))
+}
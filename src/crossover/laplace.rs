@@ -0,0 +1,59 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::check_length;
+
+/**
+## Description:
+Laplace crossover displaces both parents by a distance proportional to their spread, with the factor
+β drawn from a Laplace distribution for every gene:
+    β = a - b·ln(u) if r ≤ 0.5, else a + b·ln(u)
+    C1<sub>i</sub> = P1<sub>i</sub> + β|P1<sub>i</sub> - P2<sub>i</sub>|
+    C2<sub>i</sub> = P2<sub>i</sub> + β|P1<sub>i</sub> - P2<sub>i</sub>|
+    Here, u and r are random values between 0 and 1.
+
+### Note:
+- The function takes a `location` (a, usually `0.0`) and `scale` (b) of the distribution. A smaller scale keeps offsprings closer to the parents.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a tuple containing two offsprings of type `Vec<f32>`
+
+## Example:
+```rust
+use genx::crossover::laplace_crossover;
+
+let parent1 = vec![1.0, 2.0, 3.0];
+let parent2 = vec![2.0, 2.0, 1.0];
+let (child1, child2) = laplace_crossover(&parent1, &parent2, 0.0, 0.35, None);
+assert_eq!((child1[1], child2[1]), (2.0, 2.0));
+```
+ */
+pub fn laplace_crossover(
+    parent1: &Vec<f32>,
+    parent2: &Vec<f32>,
+    location: f32,
+    scale: f32,
+    seed: Option<u64>,
+) -> (Vec<f32>, Vec<f32>) {
+    check_length(parent1, parent2);
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    parent1
+        .iter()
+        .zip(parent2.iter())
+        .map(|(&val1, &val2)| {
+            let u = 1.0 - prng.gen::<f32>();
+            let beta = if prng.gen::<f32>() <= 0.5 {
+                location - scale * u.ln()
+            } else {
+                location + scale * u.ln()
+            };
+            let spread = (val1 - val2).abs();
+            (val1 + beta * spread, val2 + beta * spread)
+        })
+        .unzip()
+}
@@ -0,0 +1,69 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/**
+## Description:
+Messy splice crossover is the cut and splice operator of messy genetic algorithms. First each parent
+is cut at a random point with probability `cut_probability * (length - 1)`, so longer individuals are
+more likely to be cut. Then the resulting pieces are visited in order and every piece is spliced
+(concatenated) to the next one with probability `splice_probability`.
+
+### Note:
+- Parents need not be of the same length.
+- Both probabilities should lie in the range [0.0 - 1.0].
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a `Vec<Vec<T>>` of one to four offsprings which together contain every gene of both parents.
+
+## Example:
+```rust
+use genx::crossover::messy_splice_crossover;
+
+let parent1 = vec![1, 2, 3, 4, 5, 6, 7];
+let parent2 = vec![10, 20, 30];
+let children = messy_splice_crossover(&parent1, &parent2, 0.1, 0.5, None);
+assert_eq!(children.iter().map(|child| child.len()).sum::<usize>(), 10);
+```
+ */
+pub fn messy_splice_crossover<T>(
+    parent1: &Vec<T>,
+    parent2: &Vec<T>,
+    cut_probability: f32,
+    splice_probability: f32,
+    seed: Option<u64>,
+) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let mut pieces: Vec<Vec<T>> = Vec::with_capacity(4);
+    for parent in [parent1, parent2] {
+        let probability = cut_probability * parent.len().saturating_sub(1) as f32;
+        if parent.len() > 1 && prng.gen_range(0.0..1.0) < probability {
+            let cut = prng.gen_range(1..parent.len());
+            pieces.push(parent[..cut].to_vec());
+            pieces.push(parent[cut..].to_vec());
+        } else {
+            pieces.push(parent.clone());
+        }
+    }
+
+    let mut children: Vec<Vec<T>> = Vec::with_capacity(pieces.len());
+    let mut pieces = pieces.into_iter();
+    let mut current = pieces.next().unwrap();
+    for piece in pieces {
+        if prng.gen_range(0.0..1.0) < splice_probability {
+            current.extend(piece);
+        } else {
+            children.push(current);
+            current = piece;
+        }
+    }
+    children.push(current);
+
+    children
+}
@@ -20,6 +20,11 @@
 //! * `uniform`
 //! * `shuffle`
 //!
+//! Binary encoded individuals packed into a [`crate::genome::BitGenome`]
+//! can use the word level variants:
+//! * `packed_uniform`
+//! * `packed_multi_point`
+//!
 //! Available crossover functions for real encoded
 //! individuals are:
 //! * `linear`
@@ -29,6 +34,32 @@
 //! Above all real encoded crossover techniques take in one additional parameter
 //! that determines the shift in values between the parents and offsprings.
 //!
+//! Available crossover functions for real encoded
+//! individuals represented as `Vec<f32>` are:
+//! * `whole_arithmetic`
+//! * `simple_arithmetic`
+//! * `single_arithmetic`
+//! * `heuristic`
+//! * `laplace`
+//! * `blend_alpha_beta`
+//!
+//! `heuristic` and `blend_alpha_beta` make use of which parent is fitter.
+//!
+//! Crossovers that need the fitness of the parents implement the
+//! [`FitnessAwareCrossover`] trait, receiving every parent as an
+//! `(individual, fitness)` pair. Available ones are:
+//! * `fitness_biased_uniform`
+//! * `HeuristicCrossover`
+//!
+//! Available crossover functions for integer encoded
+//! individuals are:
+//! * `integer_arithmetic`
+//! * `integer_simulated_binary`
+//! * `discrete_uniform`
+//!
+//! Integer offsprings of `integer_simulated_binary` are rounded and clamped
+//! to the provided per gene bounds, `discrete_uniform` works for any gene type.
+//!
 //! Available crossover functions for order encoded
 //! individuals are:
 //! * `cycle`
@@ -40,9 +71,35 @@
 //! of invalid order or missing elements. So values should be in range of
 //! 0..n-1 where n is length of the order encoded individual.
 //!
+//! Available crossover functions for variable length
+//! individuals are:
+//! * `cut_and_splice`
+//! * `messy_splice`
+//! * `homologous`
+//!
+//! Unlike the other crossovers these accept parents of different lengths.
+//!
+//! Available crossover functions taking more than two
+//! parents, passed as a `Vec` of individuals, are:
+//! * `diagonal`
+//! * `scanning`
+//! * `gene_pool`
+//! * `unimodal_normal_distribution`
+//! * `simplex`
+//! * `parent_centric`
+//!
+//! The last four are meant for real encoded individuals. Groups of parents
+//! can be selected with the group selections in [`crate::selection::group`].
+//!
+//! Individuals made of several typed segments, represented by a
+//! [`crate::genome::CompositeGenome`], are recombined segment by segment
+//! with `composite`, using any of the above crossovers per segment.
+//!
 //! You can read more about selection schemas and their working from the [research paper](http://ictactjournals.in/paper/IJSC_V6_I1_paper_4_pp_1083_1092.pdf)
 use std::collections::HashSet;
 
+use rand::Rng;
+
 pub mod single_point;
 
 pub mod multi_point;
@@ -65,6 +122,50 @@ pub mod simulated_binary;
 
 pub mod uniform_partially_mapped;
 
+pub mod whole_arithmetic;
+
+pub mod simple_arithmetic;
+
+pub mod single_arithmetic;
+
+pub mod heuristic;
+
+pub mod laplace;
+
+pub mod blend_alpha_beta;
+
+pub mod fitness_aware;
+
+pub mod integer_arithmetic;
+
+pub mod integer_simulated_binary;
+
+pub mod discrete_uniform;
+
+pub mod packed_uniform;
+
+pub mod packed_multi_point;
+
+pub mod composite;
+
+pub mod cut_and_splice;
+
+pub mod messy_splice;
+
+pub mod homologous;
+
+pub mod diagonal;
+
+pub mod scanning;
+
+pub mod gene_pool;
+
+pub mod unimodal_normal_distribution;
+
+pub mod simplex;
+
+pub mod parent_centric;
+
 pub use self::single_point::single_point_crossover;
 
 pub use self::multi_point::multi_point_crossover;
@@ -87,6 +188,50 @@ pub use self::simulated_binary::simulated_binary_crossover;
 
 pub use self::uniform_partially_mapped::uniform_partially_mapped_crossover;
 
+pub use self::whole_arithmetic::whole_arithmetic_crossover;
+
+pub use self::simple_arithmetic::simple_arithmetic_crossover;
+
+pub use self::single_arithmetic::single_arithmetic_crossover;
+
+pub use self::heuristic::heuristic_crossover;
+
+pub use self::laplace::laplace_crossover;
+
+pub use self::blend_alpha_beta::blend_alpha_beta_crossover;
+
+pub use self::fitness_aware::{fitness_biased_uniform_crossover, FitnessAwareCrossover, HeuristicCrossover};
+
+pub use self::integer_arithmetic::integer_arithmetic_crossover;
+
+pub use self::integer_simulated_binary::integer_simulated_binary_crossover;
+
+pub use self::discrete_uniform::discrete_uniform_crossover;
+
+pub use self::packed_uniform::packed_uniform_crossover;
+
+pub use self::packed_multi_point::packed_multi_point_crossover;
+
+pub use self::composite::composite_crossover;
+
+pub use self::cut_and_splice::cut_and_splice_crossover;
+
+pub use self::messy_splice::messy_splice_crossover;
+
+pub use self::homologous::homologous_crossover;
+
+pub use self::diagonal::diagonal_crossover;
+
+pub use self::scanning::{scanning_crossover, ScanningStrategy};
+
+pub use self::gene_pool::gene_pool_crossover;
+
+pub use self::unimodal_normal_distribution::unimodal_normal_distribution_crossover;
+
+pub use self::simplex::simplex_crossover;
+
+pub use self::parent_centric::parent_centric_crossover;
+
 fn check_continuous(vec: &Vec<usize>) -> bool {
   let n = vec.len();
   let mut set:HashSet<usize> = HashSet::new();
@@ -105,3 +250,32 @@ fn check_length<T>(parent1 : &Vec<T>, parent2 : &Vec<T>) {
     panic!("Vectors must be the same length");
   }
 }
+
+fn check_parents<T>(parents: &Vec<Vec<T>>, min_parents: usize) {
+  if parents.len() < min_parents {
+    panic!("Not enough parents for this crossover");
+  }
+  if parents.iter().any(|parent| parent.len() != parents[0].len()) {
+    panic!("Vectors must be the same length");
+  }
+}
+
+fn standard_normal<R: Rng>(prng: &mut R) -> f32 {
+  let u1 = 1.0 - prng.gen::<f32>();
+  let u2 = prng.gen::<f32>();
+  (-2.0 * u1.ln()).sqrt() * (2.0 * std::f32::consts::PI * u2).cos()
+}
+
+fn mean_vector(parents: &[Vec<f32>]) -> Vec<f32> {
+  let mut mean = vec![0.0; parents[0].len()];
+  for parent in parents.iter() {
+    for (x, &y) in mean.iter_mut().zip(parent.iter()) {
+      *x += y / parents.len() as f32;
+    }
+  }
+  mean
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+  a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
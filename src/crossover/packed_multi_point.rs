@@ -0,0 +1,61 @@
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+
+use super::packed_uniform::exchange_masked;
+use crate::genome::{bit::words_for, BitGenome};
+
+/**
+## Description:
+Packed multi point crossover is the `multi_point_crossover` for individuals encoded as a `BitGenome`.
+The `k` crossover points are turned into a mask marking the alternating segments taken from the
+other parent, which is then applied to whole words at once.
+
+### Note:
+- The function takes an integer `k` denoting the number of crossover points.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a tuple containing two offsprings of type `BitGenome`
+
+## Example:
+```rust
+use genx::{crossover::packed_multi_point_crossover, genome::BitGenome};
+
+let parent1 = BitGenome::from(&vec![true, false, false, true, true, false, false, true]);
+let parent2 = BitGenome::from(&vec![true, true, true, false, true, false, true, true]);
+let (child1, child2) = packed_multi_point_crossover(&parent1, &parent2, 3, None);
+```
+ */
+pub fn packed_multi_point_crossover(
+    parent1: &BitGenome,
+    parent2: &BitGenome,
+    k: usize,
+    seed: Option<u64>,
+) -> (BitGenome, BitGenome) {
+    if parent1.len() != parent2.len() {
+        panic!("Vectors must be the same length");
+    }
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let n = parent1.len();
+    let mut mask = vec![0u64; words_for(n)];
+    for idx in sample(&mut prng, n, k.min(n)).iter() {
+        mask[idx / 64] |= 1u64 << (idx % 64);
+    }
+
+    // Every bit at or after an odd number of crossover points comes from the other parent.
+    let mut carry = 0u64;
+    for word in mask.iter_mut() {
+        let mut prefix = *word;
+        for shift in [1, 2, 4, 8, 16, 32] {
+            prefix ^= prefix << shift;
+        }
+        *word = prefix ^ carry;
+        carry = if *word >> 63 == 1 { u64::MAX } else { 0 };
+    }
+
+    exchange_masked(parent1, parent2, &mask)
+}
@@ -0,0 +1,58 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::genome::{bit::random_mask, BitGenome};
+
+/**
+## Description:
+Packed uniform crossover is the `uniform_crossover` for individuals encoded as a `BitGenome`.
+Instead of rolling a random number for every gene it builds a random mask where each bit is set with
+the given `probability`, and exchanges all masked bits between the parents a word at a time.
+
+### Note:
+- The function takes a float value `probability` in the range [0.0 - 1.0] representing the bias.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a tuple containing two offsprings of type `BitGenome`
+
+## Example:
+```rust
+use genx::{crossover::packed_uniform_crossover, genome::BitGenome};
+
+let parent1 = BitGenome::from(&vec![true, false, false, true, true, false, false, true]);
+let parent2 = BitGenome::from(&vec![true, true, true, false, true, false, true, true]);
+let (child1, child2) = packed_uniform_crossover(&parent1, &parent2, 0.6, None);
+```
+ */
+pub fn packed_uniform_crossover(
+    parent1: &BitGenome,
+    parent2: &BitGenome,
+    probability: f64,
+    seed: Option<u64>,
+) -> (BitGenome, BitGenome) {
+    if parent1.len() != parent2.len() {
+        panic!("Vectors must be the same length");
+    }
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let mask = random_mask(&mut prng, parent1.len(), probability);
+    exchange_masked(parent1, parent2, &mask)
+}
+
+pub(crate) fn exchange_masked(parent1: &BitGenome, parent2: &BitGenome, mask: &[u64]) -> (BitGenome, BitGenome) {
+    let (mut words1, mut words2) = (Vec::with_capacity(mask.len()), Vec::with_capacity(mask.len()));
+    for ((&val1, &val2), &bits) in parent1.words().iter().zip(parent2.words().iter()).zip(mask.iter()) {
+        let swapped = (val1 ^ val2) & bits;
+        words1.push(val1 ^ swapped);
+        words2.push(val2 ^ swapped);
+    }
+
+    (
+        BitGenome::from_words(words1, parent1.len()),
+        BitGenome::from_words(words2, parent2.len()),
+    )
+}
@@ -0,0 +1,83 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use super::{check_parents, dot, mean_vector, standard_normal};
+
+/**
+## Description:
+Parent centric crossover (PCX) creates offsprings around the first parent. With `g` the mean of all
+parents and `d = P`<sub>1</sub>` - g`, the offspring is displaced along `d` with a spread of `sigma_zeta`,
+and in the directions perpendicular to `d` with a spread of `sigma_eta` times the average perpendicular
+distance `D` of the other parents from the line through `g` along `d`:
+    C = P<sub>1</sub> + w<sub>ζ</sub>d + D Σ w<sub>η</sub>e<sub>k</sub>
+    Here, w<sub>ζ</sub> ~ N(0, sigma_zeta²) and w<sub>η</sub> ~ N(0, sigma_eta²)
+
+### Note:
+- All parents should be of the same length and there should be atleast three of them.
+- The offsprings are centered on the first parent, usually the best one is passed first.
+- Commonly `sigma_zeta = sigma_eta = 0.1`.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a `Vec<Vec<f32>>` containing `num_offsprings` offsprings.
+
+## Example:
+```rust
+use genx::crossover::parent_centric_crossover;
+
+let parents = vec![vec![1.0, 1.0, 0.0], vec![0.0, 0.0, 0.0], vec![1.0, 0.0, 1.0]];
+let children = parent_centric_crossover(&parents, 0.1, 0.1, 4, None);
+assert_eq!(children.len(), 4);
+```
+ */
+pub fn parent_centric_crossover(
+    parents: &Vec<Vec<f32>>,
+    sigma_zeta: f32,
+    sigma_eta: f32,
+    num_offsprings: usize,
+    seed: Option<u64>,
+) -> Vec<Vec<f32>> {
+    check_parents(parents, 3);
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let mean = mean_vector(parents);
+    let direction = parents[0].iter().zip(mean.iter()).map(|(x, g)| x - g).collect::<Vec<f32>>();
+    let norm = dot(&direction, &direction).sqrt();
+    let unit = if norm > 1e-12 {
+        direction.iter().map(|x| x / norm).collect::<Vec<f32>>()
+    } else {
+        vec![0.0; direction.len()]
+    };
+
+    let perpendicular = |vector: &[f32]| {
+        let projection = dot(vector, &unit);
+        vector.iter().zip(unit.iter()).map(|(x, e)| x - projection * e).collect::<Vec<f32>>()
+    };
+
+    let distance = parents[1..]
+        .iter()
+        .map(|parent| {
+            let offset = parent.iter().zip(parents[0].iter()).map(|(x, p)| x - p).collect::<Vec<f32>>();
+            let residual = perpendicular(&offset);
+            dot(&residual, &residual).sqrt()
+        })
+        .sum::<f32>()
+        / (parents.len() - 1) as f32;
+
+    (0..num_offsprings)
+        .map(|_| {
+            let noise = (0..mean.len()).map(|_| standard_normal(&mut prng)).collect::<Vec<f32>>();
+            let noise = perpendicular(&noise);
+            let weight = sigma_zeta * standard_normal(&mut prng);
+            parents[0]
+                .iter()
+                .zip(direction.iter())
+                .zip(noise.iter())
+                .map(|((x, d), v)| x + weight * d + distance * sigma_eta * v)
+                .collect()
+        })
+        .collect()
+}
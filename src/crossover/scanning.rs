@@ -0,0 +1,78 @@
+use rand::{distributions::WeightedIndex, prelude::Distribution, rngs::StdRng, Rng, SeedableRng};
+
+use super::check_parents;
+
+/// Rule used by `scanning_crossover` to pick the parent a gene is copied from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScanningStrategy<'a> {
+    /// Every parent is equally likely.
+    Uniform,
+    /// The most common value among the parents is used, ties are broken at random.
+    Occurrence,
+    /// Parents are picked proportionally to their non negative fitness values.
+    FitnessBased(&'a Vec<f32>),
+}
+
+/**
+## Description:
+Scanning crossover creates a single offspring from any number of parents by scanning their genes
+position by position. At every position the gene is copied from one of the parents, which is picked
+according to the `strategy`.
+
+### Note:
+- All parents should be of the same length and there should be atleast two of them.
+- For `ScanningStrategy::FitnessBased` there should be one fitness value per parent.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is the offspring of type `Vec<T>`
+
+## Example:
+```rust
+use genx::crossover::{scanning_crossover, ScanningStrategy};
+
+let parents = vec![vec![1, 2, 3, 4], vec![1, 5, 3, 6], vec![7, 5, 3, 4]];
+let child = scanning_crossover(&parents, ScanningStrategy::Occurrence, None);
+assert_eq!(child, vec![1, 5, 3, 4]);
+
+let fitness_values = vec![0.0, 0.0, 1.0];
+let child = scanning_crossover(&parents, ScanningStrategy::FitnessBased(&fitness_values), None);
+assert_eq!(child, parents[2]);
+```
+ */
+pub fn scanning_crossover<T>(parents: &Vec<Vec<T>>, strategy: ScanningStrategy, seed: Option<u64>) -> Vec<T>
+where
+    T: Copy + PartialEq,
+{
+    check_parents(parents, 2);
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let n = parents[0].len();
+    match strategy {
+        ScanningStrategy::Uniform => (0..n)
+            .map(|i| parents[prng.gen_range(0..parents.len())][i])
+            .collect(),
+        ScanningStrategy::FitnessBased(fitness_values) => {
+            if fitness_values.len() != parents.len() {
+                panic!("Fitness values must be provided for every parent");
+            }
+            let weights = WeightedIndex::new(fitness_values).expect("Fitness values must be non negative with a positive sum");
+            (0..n).map(|i| parents[weights.sample(&mut prng)][i]).collect()
+        }
+        ScanningStrategy::Occurrence => (0..n)
+            .map(|i| {
+                let counts = parents
+                    .iter()
+                    .map(|parent| parents.iter().filter(|other| other[i] == parent[i]).count())
+                    .collect::<Vec<usize>>();
+                let most = *counts.iter().max().unwrap();
+                let candidates = (0..parents.len()).filter(|&j| counts[j] == most).collect::<Vec<usize>>();
+                parents[candidates[prng.gen_range(0..candidates.len())]][i]
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,50 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::check_length;
+
+/**
+## Description:
+Simple arithmetic crossover picks a random crossover point `k`. Genes before `k` are copied from
+the parent, while every gene from `k` onwards is the weighted average of both parents:
+    C1<sub>i</sub> = (1-ɑ)P1<sub>i</sub> + ɑP2<sub>i</sub>, for i >= k
+    C2<sub>i</sub> = (1-ɑ)P2<sub>i</sub> + ɑP1<sub>i</sub>, for i >= k
+
+### Note:
+- The function takes a `f32` parameter `alpha` in the range [0.0 - 1.0].
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a tuple containing two offsprings of type `Vec<f32>`
+
+## Example:
+```rust
+use genx::crossover::simple_arithmetic_crossover;
+
+let parent1 = vec![1.0, 2.0, 3.0, 4.0];
+let parent2 = vec![3.0, 2.0, 1.0, 0.0];
+let (child1, child2) = simple_arithmetic_crossover(&parent1, &parent2, 0.5, None);
+assert_eq!(child1[3], 2.0);
+```
+ */
+pub fn simple_arithmetic_crossover(
+    parent1: &Vec<f32>,
+    parent2: &Vec<f32>,
+    alpha: f32,
+    seed: Option<u64>,
+) -> (Vec<f32>, Vec<f32>) {
+    check_length(parent1, parent2);
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let k = prng.gen_range(0..parent1.len().max(1));
+    let (mut child1, mut child2) = (parent1.clone(), parent2.clone());
+    for i in k..parent1.len() {
+        child1[i] = (1.0 - alpha) * parent1[i] + alpha * parent2[i];
+        child2[i] = (1.0 - alpha) * parent2[i] + alpha * parent1[i];
+    }
+
+    (child1, child2)
+}
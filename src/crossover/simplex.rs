@@ -0,0 +1,63 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{check_parents, mean_vector};
+
+/**
+## Description:
+Simplex crossover (SPX) expands the simplex formed by the `parents` around its center `O` by the
+factor `epsilon` and samples offsprings uniformly from the expanded simplex:
+    y<sub>k</sub> = O + ε(P<sub>k</sub> - O)
+    C<sub>1</sub> = 0, C<sub>k</sub> = r<sub>k-1</sub>(y<sub>k-1</sub> - y<sub>k</sub> + C<sub>k-1</sub>)
+    Offspring = y<sub>m</sub> + C<sub>m</sub>
+    Here, r<sub>k</sub> = u<sup>1/k</sup> with u a random value between 0 and 1.
+
+### Note:
+- All parents should be of the same length and there should be atleast two of them, usually one more than the number of genes.
+- The recommended value for `epsilon` is `sqrt(m + 1)` for `m` parents.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a `Vec<Vec<f32>>` containing `num_offsprings` offsprings.
+
+## Example:
+```rust
+use genx::crossover::simplex_crossover;
+
+let parents = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]];
+let children = simplex_crossover(&parents, 2.0, 4, None);
+assert_eq!(children.len(), 4);
+```
+ */
+pub fn simplex_crossover(parents: &Vec<Vec<f32>>, epsilon: f32, num_offsprings: usize, seed: Option<u64>) -> Vec<Vec<f32>> {
+    check_parents(parents, 2);
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let center = mean_vector(parents);
+    let expanded = parents
+        .iter()
+        .map(|parent| {
+            parent
+                .iter()
+                .zip(center.iter())
+                .map(|(x, o)| o + epsilon * (x - o))
+                .collect::<Vec<f32>>()
+        })
+        .collect::<Vec<Vec<f32>>>();
+
+    (0..num_offsprings)
+        .map(|_| {
+            let mut offset = vec![0.0; center.len()];
+            for k in 1..expanded.len() {
+                let r = prng.gen::<f32>().powf(1.0 / k as f32);
+                for (i, c) in offset.iter_mut().enumerate() {
+                    *c = r * (expanded[k - 1][i] - expanded[k][i] + *c);
+                }
+            }
+            expanded[expanded.len() - 1].iter().zip(offset.iter()).map(|(y, c)| y + c).collect()
+        })
+        .collect()
+}
@@ -0,0 +1,50 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::check_length;
+
+/**
+## Description:
+Single arithmetic crossover picks a single random gene `k` and replaces it with the weighted average
+of both parents, all other genes are copied from the parent:
+    C1<sub>k</sub> = (1-ɑ)P1<sub>k</sub> + ɑP2<sub>k</sub>
+    C2<sub>k</sub> = (1-ɑ)P2<sub>k</sub> + ɑP1<sub>k</sub>
+
+### Note:
+- The function takes a `f32` parameter `alpha` in the range [0.0 - 1.0].
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a tuple containing two offsprings of type `Vec<f32>`
+
+## Example:
+```rust
+use genx::crossover::single_arithmetic_crossover;
+
+let parent1 = vec![1.0, 2.0, 3.0, 4.0];
+let parent2 = vec![3.0, 2.0, 1.0, 0.0];
+let (child1, child2) = single_arithmetic_crossover(&parent1, &parent2, 0.5, None);
+```
+ */
+pub fn single_arithmetic_crossover(
+    parent1: &Vec<f32>,
+    parent2: &Vec<f32>,
+    alpha: f32,
+    seed: Option<u64>,
+) -> (Vec<f32>, Vec<f32>) {
+    check_length(parent1, parent2);
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let (mut child1, mut child2) = (parent1.clone(), parent2.clone());
+    if parent1.is_empty() {
+        return (child1, child2);
+    }
+    let k = prng.gen_range(0..parent1.len());
+    child1[k] = (1.0 - alpha) * parent1[k] + alpha * parent2[k];
+    child2[k] = (1.0 - alpha) * parent2[k] + alpha * parent1[k];
+
+    (child1, child2)
+}
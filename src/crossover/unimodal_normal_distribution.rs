@@ -0,0 +1,91 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use super::{check_parents, dot, mean_vector, standard_normal};
+
+/**
+## Description:
+Unimodal normal distribution crossover (UNDX-m) samples offsprings from a normal distribution
+centered at the mean `g` of the first `m - 1` parents. Along the primary directions `d`<sub>j</sub> from
+`g` to each of those parents the spread is controlled by `sigma_xi`. In the remaining directions the spread
+is `sigma_eta` times the distance `D` of the last parent from the subspace spanned by the primary directions:
+    C = g + Σ w<sub>j</sub>d<sub>j</sub> + D Σ v<sub>k</sub>e<sub>k</sub>
+    Here, w<sub>j</sub> ~ N(0, sigma_xi²) and v<sub>k</sub> ~ N(0, sigma_eta²)
+
+### Note:
+- All parents should be of the same length and there should be atleast three of them.
+- Commonly used values are `sigma_xi = 1/sqrt(m - 1)` and `sigma_eta = 0.35/sqrt(n - m + 1)`, where `n` is the number of genes.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return:
+The return value is a `Vec<Vec<f32>>` containing `num_offsprings` offsprings.
+
+## Example:
+```rust
+use genx::crossover::unimodal_normal_distribution_crossover;
+
+let parents = vec![vec![0.0, 0.0, 0.0], vec![1.0, 0.0, 0.0], vec![0.5, 1.0, 0.0]];
+let children = unimodal_normal_distribution_crossover(&parents, 0.5, 0.35, 4, None);
+assert_eq!(children.len(), 4);
+```
+ */
+pub fn unimodal_normal_distribution_crossover(
+    parents: &Vec<Vec<f32>>,
+    sigma_xi: f32,
+    sigma_eta: f32,
+    num_offsprings: usize,
+    seed: Option<u64>,
+) -> Vec<Vec<f32>> {
+    check_parents(parents, 3);
+
+    let mut prng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let m = parents.len();
+    let mean = mean_vector(&parents[..m - 1]);
+    let directions = parents[..m - 1]
+        .iter()
+        .map(|parent| parent.iter().zip(mean.iter()).map(|(x, g)| x - g).collect::<Vec<f32>>())
+        .collect::<Vec<Vec<f32>>>();
+
+    // Orthonormal basis of the subspace spanned by the primary directions.
+    let mut basis: Vec<Vec<f32>> = Vec::new();
+    for direction in directions.iter() {
+        let residual = remove_components(direction, &basis);
+        let norm = dot(&residual, &residual).sqrt();
+        if norm > 1e-12 {
+            basis.push(residual.iter().map(|x| x / norm).collect());
+        }
+    }
+
+    let last = parents[m - 1].iter().zip(mean.iter()).map(|(x, g)| x - g).collect::<Vec<f32>>();
+    let residual = remove_components(&last, &basis);
+    let distance = dot(&residual, &residual).sqrt();
+
+    (0..num_offsprings)
+        .map(|_| {
+            let noise = (0..mean.len()).map(|_| standard_normal(&mut prng)).collect::<Vec<f32>>();
+            let noise = remove_components(&noise, &basis);
+            let mut child = mean.iter().zip(noise.iter()).map(|(g, v)| g + distance * sigma_eta * v).collect::<Vec<f32>>();
+            for direction in directions.iter() {
+                let weight = sigma_xi * standard_normal(&mut prng);
+                for (x, d) in child.iter_mut().zip(direction.iter()) {
+                    *x += weight * d;
+                }
+            }
+            child
+        })
+        .collect()
+}
+
+fn remove_components(vector: &[f32], basis: &[Vec<f32>]) -> Vec<f32> {
+    let mut residual = vector.to_vec();
+    for axis in basis.iter() {
+        let projection = dot(&residual, axis);
+        for (x, e) in residual.iter_mut().zip(axis.iter()) {
+            *x -= projection * e;
+        }
+    }
+    residual
+}
@@ -0,0 +1,34 @@
+use super::check_length;
+
+/**
+## Description:
+Whole arithmetic crossover takes the weighted average of the two parents for every gene:
+    C1 = (1-ɑ)P1 + ɑP2
+    C2 = (1-ɑ)P2 + ɑP1
+
+### Note:
+- The function takes a `f32` parameter `alpha` in the range [0.0 - 1.0], with `0.5` both offsprings are the same.
+
+## Return:
+The return value is a tuple containing two offsprings of type `Vec<f32>`
+
+## Example:
+```rust
+use genx::crossover::whole_arithmetic_crossover;
+
+let parent1 = vec![1.0, 2.0, 3.0];
+let parent2 = vec![3.0, 2.0, 1.0];
+let (child1, child2) = whole_arithmetic_crossover(&parent1, &parent2, 0.25);
+assert_eq!(child1, vec![1.5, 2.0, 2.5]);
+assert_eq!(child2, vec![2.5, 2.0, 1.5]);
+```
+ */
+pub fn whole_arithmetic_crossover(parent1: &Vec<f32>, parent2: &Vec<f32>, alpha: f32) -> (Vec<f32>, Vec<f32>) {
+    check_length(parent1, parent2);
+
+    parent1
+        .iter()
+        .zip(parent2.iter())
+        .map(|(&val1, &val2)| ((1.0 - alpha) * val1 + alpha * val2, (1.0 - alpha) * val2 + alpha * val1))
+        .unzip()
+}
@@ -0,0 +1,34 @@
+/**
+## Description
+Converts a standard binary number to its reflected binary Gray code, in which
+consecutive numbers differ in exactly one bit.
+
+## Example
+```rust
+  use genx::encoding::binary_to_gray;
+  assert_eq!(binary_to_gray(0b0111), 0b0100);
+  assert_eq!(binary_to_gray(0b1000), 0b1100);
+```
+*/
+pub fn binary_to_gray(value: u64) -> u64 {
+    value ^ (value >> 1)
+}
+
+/**
+## Description
+Converts a reflected binary Gray code back to the standard binary number it represents.
+
+## Example
+```rust
+  use genx::encoding::{binary_to_gray, gray_to_binary};
+  assert_eq!(gray_to_binary(0b1100), 0b1000);
+  assert_eq!(gray_to_binary(binary_to_gray(1234)), 1234);
+```
+*/
+pub fn gray_to_binary(value: u64) -> u64 {
+    let mut value = value;
+    for shift in [1, 2, 4, 8, 16, 32] {
+        value ^= value >> shift;
+    }
+    value
+}
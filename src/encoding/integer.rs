@@ -0,0 +1,115 @@
+use super::{bits_to_raw, check_segments, max_raw, raw_to_bits, Coding};
+
+/**
+## Description
+Encodes an integer `value` lying in `lower..=upper` into `num_bits` bits. When the range holds
+exactly `2^num_bits` values every value gets its own code, otherwise values are spread evenly
+over the available codes.
+
+### Note
+- Values outside of the bounds are clamped to the bounds.
+- Panics if `num_bits` does not lie between `1` and `64`.
+
+## Example
+```rust
+  use genx::encoding::{encode_integer, Coding};
+  assert_eq!(encode_integer(5, 0, 7, 3, Coding::Binary), vec![true, false, true]);
+  assert_eq!(encode_integer(5, 0, 7, 3, Coding::Gray), vec![true, true, true]);
+```
+*/
+pub fn encode_integer(value: i32, lower: i32, upper: i32, num_bits: usize, coding: Coding) -> Vec<bool> {
+    let max = max_raw(num_bits);
+    let raw = if upper > lower {
+        let fraction = (value.clamp(lower, upper) as f64 - lower as f64) / (upper as f64 - lower as f64);
+        (fraction * max as f64).round() as u64
+    } else {
+        0
+    };
+    raw_to_bits(raw, num_bits, coding)
+}
+
+/**
+## Description
+Decodes the `bits` of a single variable into an integer lying in `lower..=upper`.
+All zero bits map to `lower` and all one bits (in the chosen `coding`) map to `upper`.
+
+_Note: Panics if the number of bits does not lie between `1` and `64`._
+
+## Example
+```rust
+  use genx::encoding::{decode_integer, Coding};
+  assert_eq!(decode_integer(&vec![true, false, true], 10, 17, Coding::Binary), 15);
+  assert_eq!(decode_integer(&vec![true, true, true], 10, 17, Coding::Gray), 15);
+```
+*/
+pub fn decode_integer(bits: &[bool], lower: i32, upper: i32, coding: Coding) -> i32 {
+    let raw = bits_to_raw(bits, coding);
+    let fraction = raw as f64 / max_raw(bits.len()) as f64;
+    (lower as f64 + fraction * (upper as f64 - lower as f64)).round() as i32
+}
+
+/**
+## Description
+Encodes every value of `values` into `bits_per_variable` bits using the matching `(lower, upper)`
+pair of `bounds`, and concatenates them into a single binary encoded individual.
+
+## Return
+The return value is a `Result<Vec<bool>, &'static str>` which will return error if `bounds` does not
+match the number of values or `bits_per_variable` does not lie between `1` and `64`.
+
+## Example
+```rust
+  use genx::encoding::{encode_integers, Coding};
+  let individual = encode_integers(&vec![3, 0], &vec![(0, 3), (0, 3)], 2, Coding::Binary).unwrap();
+  assert_eq!(individual, vec![true, true, false, false]);
+```
+*/
+pub fn encode_integers(
+    values: &Vec<i32>,
+    bounds: &Vec<(i32, i32)>,
+    bits_per_variable: usize,
+    coding: Coding,
+) -> Result<Vec<bool>, &'static str> {
+    if values.len() != bounds.len() {
+        return Err("bounds should contain a (lower, upper) pair for every value");
+    }
+    check_segments(values.len() * bits_per_variable, bounds.len(), bits_per_variable)?;
+
+    Ok(values
+        .iter()
+        .zip(bounds.iter())
+        .flat_map(|(&value, &(lower, upper))| encode_integer(value, lower, upper, bits_per_variable, coding))
+        .collect())
+}
+
+/**
+## Description
+Splits a binary encoded `individual` into segments of `bits_per_variable` bits and decodes each
+segment into an integer using the matching `(lower, upper)` pair of `bounds`.
+
+## Return
+The return value is a `Result<Vec<i32>, &'static str>` which will return error if the length of `individual`
+is not `bits_per_variable` times the number of `bounds`.
+
+## Example
+```rust
+  use genx::encoding::{decode_integers, Coding};
+  let individual = vec![true, true, false, true];
+  let values = decode_integers(&individual, &vec![(0, 3), (-2, 1)], 2, Coding::Binary).unwrap();
+  assert_eq!(values, vec![3, -1]);
+```
+*/
+pub fn decode_integers(
+    individual: &Vec<bool>,
+    bounds: &Vec<(i32, i32)>,
+    bits_per_variable: usize,
+    coding: Coding,
+) -> Result<Vec<i32>, &'static str> {
+    check_segments(individual.len(), bounds.len(), bits_per_variable)?;
+
+    Ok(individual
+        .chunks(bits_per_variable)
+        .zip(bounds.iter())
+        .map(|(bits, &(lower, upper))| decode_integer(bits, lower, upper, coding))
+        .collect())
+}
@@ -0,0 +1,80 @@
+//! The `encoding` module provides functions to map real and integer
+//! valued variables to and from binary encoded individuals.
+//!
+//! Encoding real parameters as bitstrings allows the binary crossover
+//! and mutation schemas such as `single_point_crossover`,
+//! `flipping_mutation` and `inversion_mutation` to be used for real valued
+//! problems. Every variable occupies a fixed number of bits in the
+//! individual, which together with its bounds determines the precision.
+//!
+//! The provided functions are organized in sub-modules
+//! named after the encoding step:
+//! * `gray`
+//! * `real`
+//! * `integer`
+//!
+//! Bits are read most significant first. With [`Coding::Gray`] the bits of a
+//! variable hold its reflected Gray code, so neighbouring values always differ
+//! by a single bit flip.
+
+pub mod gray;
+
+pub mod integer;
+
+pub mod real;
+
+pub use self::gray::{binary_to_gray, gray_to_binary};
+pub use self::integer::{decode_integer, decode_integers, encode_integer, encode_integers};
+pub use self::real::{bits_for_precision, decode_real, decode_reals, encode_real, encode_reals};
+
+/// Binary code used to store a single variable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Coding {
+    /// Standard positional binary code.
+    Binary,
+    /// Reflected binary Gray code.
+    Gray,
+}
+
+const MAX_BITS: usize = 64;
+
+fn bits_to_raw(bits: &[bool], coding: Coding) -> u64 {
+    if bits.is_empty() || bits.len() > MAX_BITS {
+        panic!("A variable must be encoded in 1 to 64 bits");
+    }
+    let raw = bits.iter().fold(0u64, |acc, &bit| (acc << 1) | bit as u64);
+    match coding {
+        Coding::Binary => raw,
+        Coding::Gray => gray_to_binary(raw),
+    }
+}
+
+fn raw_to_bits(raw: u64, num_bits: usize, coding: Coding) -> Vec<bool> {
+    if num_bits == 0 || num_bits > MAX_BITS {
+        panic!("A variable must be encoded in 1 to 64 bits");
+    }
+    let code = match coding {
+        Coding::Binary => raw,
+        Coding::Gray => binary_to_gray(raw),
+    };
+    (0..num_bits).rev().map(|shift| code >> shift & 1 == 1).collect()
+}
+
+fn max_raw(num_bits: usize) -> u64 {
+    if num_bits >= MAX_BITS {
+        u64::MAX
+    } else {
+        (1u64 << num_bits) - 1
+    }
+}
+
+fn check_segments(individual_len: usize, num_variables: usize, bits_per_variable: usize) -> Result<(), &'static str> {
+    if bits_per_variable == 0 || bits_per_variable > MAX_BITS {
+        return Err("bits_per_variable should lie between 1 and 64 inclusive");
+    }
+    if individual_len != num_variables * bits_per_variable {
+        return Err("individual length should be bits_per_variable times the number of bounds");
+    }
+
+    Ok(())
+}
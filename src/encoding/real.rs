@@ -0,0 +1,139 @@
+use super::{bits_to_raw, check_segments, max_raw, raw_to_bits, Coding};
+
+/**
+## Description
+Returns the smallest number of bits needed so that a real variable in `lower..=upper`
+is represented with a step no larger than `precision`.
+
+## Example
+```rust
+  use genx::encoding::bits_for_precision;
+  assert_eq!(bits_for_precision(-10.0, 10.0, 0.01), 11);
+```
+*/
+pub fn bits_for_precision(lower: f32, upper: f32, precision: f32) -> usize {
+    if precision <= 0.0 || upper <= lower {
+        return 1;
+    }
+    let steps = ((upper - lower) as f64 / precision as f64).ceil();
+    ((steps + 1.0).log2().ceil() as usize).clamp(1, 64)
+}
+
+/**
+## Description
+Encodes a real `value` lying in `lower..=upper` into `num_bits` bits. The range is split into
+`2^num_bits - 1` equal steps and the value is rounded to the nearest one.
+
+### Note
+- Values outside of the bounds are clamped to the bounds.
+- Panics if `num_bits` does not lie between `1` and `64`.
+
+## Return
+The return value is a `Vec<bool>` of length `num_bits`, most significant bit first.
+
+## Example
+```rust
+  use genx::encoding::{encode_real, decode_real, Coding};
+  let bits = encode_real(2.5, 0.0, 10.0, 8, Coding::Gray);
+  assert!((decode_real(&bits, 0.0, 10.0, Coding::Gray) - 2.5).abs() < 0.05);
+```
+*/
+pub fn encode_real(value: f32, lower: f32, upper: f32, num_bits: usize, coding: Coding) -> Vec<bool> {
+    let max = max_raw(num_bits);
+    let fraction = if upper > lower {
+        ((value as f64 - lower as f64) / (upper as f64 - lower as f64)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    raw_to_bits((fraction * max as f64).round() as u64, num_bits, coding)
+}
+
+/**
+## Description
+Decodes the `bits` of a single variable into a real value lying in `lower..=upper`.
+All zero bits map to `lower` and all one bits (in the chosen `coding`) map to `upper`.
+
+_Note: Panics if the number of bits does not lie between `1` and `64`._
+
+## Example
+```rust
+  use genx::encoding::{decode_real, Coding};
+  assert_eq!(decode_real(&vec![false, false, false], -1.0, 6.0, Coding::Binary), -1.0);
+  assert_eq!(decode_real(&vec![true, false, false], -1.0, 6.0, Coding::Binary), 3.0);
+  assert_eq!(decode_real(&vec![true, false, false], -1.0, 6.0, Coding::Gray), 6.0);
+```
+*/
+pub fn decode_real(bits: &[bool], lower: f32, upper: f32, coding: Coding) -> f32 {
+    let raw = bits_to_raw(bits, coding);
+    let fraction = raw as f64 / max_raw(bits.len()) as f64;
+    (lower as f64 + fraction * (upper as f64 - lower as f64)) as f32
+}
+
+/**
+## Description
+Encodes every value of `values` into `bits_per_variable` bits using the matching `(lower, upper)`
+pair of `bounds`, and concatenates them into a single binary encoded individual.
+
+## Return
+The return value is a `Result<Vec<bool>, &'static str>` which will return error if `bounds` does not
+match the number of values or `bits_per_variable` does not lie between `1` and `64`.
+
+## Example
+```rust
+  use genx::encoding::{encode_reals, decode_reals, Coding};
+  let bounds = vec![(-10.0, 10.0), (0.0, 1.0)];
+  let individual = encode_reals(&vec![3.0, 0.25], &bounds, 16, Coding::Binary).unwrap();
+  assert_eq!(individual.len(), 32);
+  let values = decode_reals(&individual, &bounds, 16, Coding::Binary).unwrap();
+  assert!((values[0] - 3.0).abs() < 1e-3 && (values[1] - 0.25).abs() < 1e-3);
+```
+*/
+pub fn encode_reals(
+    values: &Vec<f32>,
+    bounds: &Vec<(f32, f32)>,
+    bits_per_variable: usize,
+    coding: Coding,
+) -> Result<Vec<bool>, &'static str> {
+    if values.len() != bounds.len() {
+        return Err("bounds should contain a (lower, upper) pair for every value");
+    }
+    check_segments(values.len() * bits_per_variable, bounds.len(), bits_per_variable)?;
+
+    Ok(values
+        .iter()
+        .zip(bounds.iter())
+        .flat_map(|(&value, &(lower, upper))| encode_real(value, lower, upper, bits_per_variable, coding))
+        .collect())
+}
+
+/**
+## Description
+Splits a binary encoded `individual` into segments of `bits_per_variable` bits and decodes each
+segment into a real value using the matching `(lower, upper)` pair of `bounds`.
+
+## Return
+The return value is a `Result<Vec<f32>, &'static str>` which will return error if the length of `individual`
+is not `bits_per_variable` times the number of `bounds`.
+
+## Example
+```rust
+  use genx::encoding::{decode_reals, Coding};
+  let individual = vec![false, false, true, true, true, true];
+  let values = decode_reals(&individual, &vec![(0.0, 7.0), (-1.0, 1.0)], 3, Coding::Binary).unwrap();
+  assert_eq!(values, vec![1.0, 1.0]);
+```
+*/
+pub fn decode_reals(
+    individual: &Vec<bool>,
+    bounds: &Vec<(f32, f32)>,
+    bits_per_variable: usize,
+    coding: Coding,
+) -> Result<Vec<f32>, &'static str> {
+    check_segments(individual.len(), bounds.len(), bits_per_variable)?;
+
+    Ok(individual
+        .chunks(bits_per_variable)
+        .zip(bounds.iter())
+        .map(|(bits, &(lower, upper))| decode_real(bits, lower, upper, coding))
+        .collect())
+}
@@ -0,0 +1,196 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const WORD_BITS: usize = 64;
+
+/**
+## Description
+`BitGenome` is a packed bitstring representation of a binary encoded individual.
+Bits are stored in `u64` words so operators can work on 64 genes at a time, which
+makes it a better fit than `Vec<bool>` for individuals with a large number of bits.
+
+Unused bits of the last word are always kept at zero.
+
+## Example
+```rust
+  use genx::genome::BitGenome;
+
+  let individual = vec![true, false, false, true, true, false, false, true];
+  let mut genome = BitGenome::from(&individual);
+  genome.flip(1);
+  assert_eq!(genome.count_ones(), 5);
+  assert_eq!(genome.hamming_distance(&BitGenome::from(&individual)), 1);
+  assert_eq!(genome.to_bools()[1], true);
+```
+*/
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGenome {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitGenome {
+    /// Creates a genome of `len` bits all set to `false`.
+    pub fn new(len: usize) -> Self {
+        BitGenome {
+            words: vec![0; words_for(len)],
+            len,
+        }
+    }
+
+    /// Creates a genome of `len` uniformly random bits.
+    ///
+    /// _Note: The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results._
+    pub fn random(len: usize, seed: Option<u64>) -> Self {
+        let mut prng = match seed {
+            Some(val) => StdRng::seed_from_u64(val),
+            None => StdRng::from_entropy(),
+        };
+        let mut genome = BitGenome {
+            words: (0..words_for(len)).map(|_| prng.gen::<u64>()).collect(),
+            len,
+        };
+        genome.clear_tail();
+        genome
+    }
+
+    /// Number of bits in the genome.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the genome holds no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit at `index`, panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> bool {
+        self.check_index(index);
+        self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 == 1
+    }
+
+    /// Sets the bit at `index` to `value`, panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.check_index(index);
+        let mask = 1u64 << (index % WORD_BITS);
+        if value {
+            self.words[index / WORD_BITS] |= mask;
+        } else {
+            self.words[index / WORD_BITS] &= !mask;
+        }
+    }
+
+    /// Flips the bit at `index`, panics if `index` is out of bounds.
+    pub fn flip(&mut self, index: usize) {
+        self.check_index(index);
+        self.words[index / WORD_BITS] ^= 1u64 << (index % WORD_BITS);
+    }
+
+    /// Number of bits set to `true`.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Number of positions at which the two genomes differ, panics if lengths differ.
+    pub fn hamming_distance(&self, other: &BitGenome) -> usize {
+        if self.len != other.len {
+            panic!("Genomes must be the same length");
+        }
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum()
+    }
+
+    /// The packed `u64` words backing the genome, least significant bit first.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Unpacks the genome into a `Vec<bool>`.
+    pub fn to_bools(&self) -> Vec<bool> {
+        (0..self.len).map(|idx| self.get(idx)).collect()
+    }
+
+    pub(crate) fn from_words(words: Vec<u64>, len: usize) -> Self {
+        let mut genome = BitGenome { words, len };
+        genome.clear_tail();
+        genome
+    }
+
+    pub(crate) fn words_mut(&mut self) -> &mut [u64] {
+        &mut self.words
+    }
+
+    fn clear_tail(&mut self) {
+        let used = self.len % WORD_BITS;
+        if used != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1u64 << used) - 1;
+            }
+        }
+    }
+
+    fn check_index(&self, index: usize) {
+        if index >= self.len {
+            panic!("Index out of bounds for genome");
+        }
+    }
+}
+
+impl From<&Vec<bool>> for BitGenome {
+    fn from(individual: &Vec<bool>) -> Self {
+        let mut genome = BitGenome::new(individual.len());
+        for (idx, &val) in individual.iter().enumerate() {
+            if val {
+                genome.words[idx / WORD_BITS] |= 1u64 << (idx % WORD_BITS);
+            }
+        }
+        genome
+    }
+}
+
+pub(crate) fn words_for(len: usize) -> usize {
+    len.div_ceil(WORD_BITS)
+}
+
+/// Samples the next position to act on, skipping over positions left untouched
+/// when each one is independently picked with `probability`.
+pub(crate) fn geometric_skip<R: Rng>(prng: &mut R, probability: f64) -> usize {
+    if probability >= 1.0 {
+        return 0;
+    }
+    let random_value: f64 = 1.0 - prng.gen::<f64>();
+    let skip = random_value.ln() / (1.0 - probability).ln();
+    if skip >= usize::MAX as f64 {
+        usize::MAX
+    } else {
+        skip as usize
+    }
+}
+
+/// Builds a mask of `len` bits where every bit is set independently with `probability`.
+pub(crate) fn random_mask<R: Rng>(prng: &mut R, len: usize, probability: f64) -> Vec<u64> {
+    let num_words = words_for(len);
+    if probability == 0.5 {
+        return (0..num_words).map(|_| prng.gen::<u64>()).collect();
+    }
+    if probability > 0.5 {
+        return random_mask(prng, len, 1.0 - probability)
+            .iter()
+            .map(|word| !word)
+            .collect();
+    }
+
+    let mut mask = vec![0u64; num_words];
+    if probability <= 0.0 {
+        return mask;
+    }
+    let mut idx = geometric_skip(prng, probability);
+    while idx < len {
+        mask[idx / WORD_BITS] |= 1u64 << (idx % WORD_BITS);
+        idx = idx.saturating_add(1).saturating_add(geometric_skip(prng, probability));
+    }
+    mask
+}
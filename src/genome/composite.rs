@@ -0,0 +1,212 @@
+/// A typed run of genes inside a [`CompositeGenome`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Segment {
+    Binary(Vec<bool>),
+    Integer(Vec<i32>),
+    Real(Vec<f32>),
+    Permutation(Vec<usize>),
+}
+
+impl Segment {
+    /// Number of genes in the segment.
+    pub fn len(&self) -> usize {
+        match self {
+            Segment::Binary(genes) => genes.len(),
+            Segment::Integer(genes) => genes.len(),
+            Segment::Real(genes) => genes.len(),
+            Segment::Permutation(genes) => genes.len(),
+        }
+    }
+
+    /// Returns `true` if the segment holds no genes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/**
+## Description
+`CompositeGenome` represents an individual made of several typed segments, for example a
+permutation of jobs followed by a mask of enabled machines and their real valued speeds.
+
+Each segment is recombined and mutated by the operator registered for it in a matching
+[`SegmentOperator`], see `composite_crossover` and `composite_mutation`.
+
+## Example
+```rust
+  use genx::genome::{CompositeGenome, Segment};
+
+  let individual = CompositeGenome::new(vec![
+    Segment::Permutation(vec![2, 0, 1, 3]),
+    Segment::Binary(vec![true, false, true]),
+    Segment::Real(vec![0.5, 1.5, 2.5]),
+  ]);
+  assert_eq!(individual.segments.len(), 3);
+  assert_eq!(individual.len(), 10);
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompositeGenome {
+    pub segments: Vec<Segment>,
+}
+
+impl CompositeGenome {
+    pub fn new(segments: Vec<Segment>) -> Self {
+        CompositeGenome { segments }
+    }
+
+    /// Total number of genes across all segments.
+    pub fn len(&self) -> usize {
+        self.segments.iter().map(|segment| segment.len()).sum()
+    }
+
+    /// Returns `true` if no segment holds any gene.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+type VectorCrossover<T> = Box<dyn Fn(&Vec<T>, &Vec<T>, Option<u64>) -> (Vec<T>, Vec<T>)>;
+
+type VectorMutation<T> = Box<dyn Fn(&mut Vec<T>, Option<u64>)>;
+
+type GeneCrossover = Box<dyn Fn(f32, f32, Option<u64>) -> (f32, f32)>;
+
+type GeneMutation = Box<dyn Fn(f32, Option<u64>) -> f32>;
+
+/// Crossover applied to a single segment, its variant must match the kind of the segment.
+///
+/// Real segments are recombined gene by gene, which fits real encoded crossovers
+/// such as `blend_crossover` or `simulated_binary_crossover`.
+pub enum SegmentCrossover {
+    Binary(VectorCrossover<bool>),
+    Integer(VectorCrossover<i32>),
+    Real(GeneCrossover),
+    Permutation(VectorCrossover<usize>),
+}
+
+impl SegmentCrossover {
+    pub fn binary(crossover: impl Fn(&Vec<bool>, &Vec<bool>, Option<u64>) -> (Vec<bool>, Vec<bool>) + 'static) -> Self {
+        SegmentCrossover::Binary(Box::new(crossover))
+    }
+
+    pub fn integer(crossover: impl Fn(&Vec<i32>, &Vec<i32>, Option<u64>) -> (Vec<i32>, Vec<i32>) + 'static) -> Self {
+        SegmentCrossover::Integer(Box::new(crossover))
+    }
+
+    pub fn real(crossover: impl Fn(f32, f32, Option<u64>) -> (f32, f32) + 'static) -> Self {
+        SegmentCrossover::Real(Box::new(crossover))
+    }
+
+    pub fn permutation(crossover: impl Fn(&Vec<usize>, &Vec<usize>, Option<u64>) -> (Vec<usize>, Vec<usize>) + 'static) -> Self {
+        SegmentCrossover::Permutation(Box::new(crossover))
+    }
+
+    /// Returns `true` if the crossover is of the same kind as `segment`.
+    pub fn matches(&self, segment: &Segment) -> bool {
+        matches!(
+            (self, segment),
+            (SegmentCrossover::Binary(_), Segment::Binary(_))
+                | (SegmentCrossover::Integer(_), Segment::Integer(_))
+                | (SegmentCrossover::Real(_), Segment::Real(_))
+                | (SegmentCrossover::Permutation(_), Segment::Permutation(_))
+        )
+    }
+}
+
+/// Mutation applied to a single segment, its variant must match the kind of the segment.
+///
+/// Real segments are mutated gene by gene, which fits real encoded mutations
+/// such as `polynomial_mutation` or `random_mutation`.
+pub enum SegmentMutation {
+    Binary(VectorMutation<bool>),
+    Integer(VectorMutation<i32>),
+    Real(GeneMutation),
+    Permutation(VectorMutation<usize>),
+}
+
+impl SegmentMutation {
+    pub fn binary(mutation: impl Fn(&mut Vec<bool>, Option<u64>) + 'static) -> Self {
+        SegmentMutation::Binary(Box::new(mutation))
+    }
+
+    pub fn integer(mutation: impl Fn(&mut Vec<i32>, Option<u64>) + 'static) -> Self {
+        SegmentMutation::Integer(Box::new(mutation))
+    }
+
+    pub fn real(mutation: impl Fn(f32, Option<u64>) -> f32 + 'static) -> Self {
+        SegmentMutation::Real(Box::new(mutation))
+    }
+
+    pub fn permutation(mutation: impl Fn(&mut Vec<usize>, Option<u64>) + 'static) -> Self {
+        SegmentMutation::Permutation(Box::new(mutation))
+    }
+
+    /// Returns `true` if the mutation is of the same kind as `segment`.
+    pub fn matches(&self, segment: &Segment) -> bool {
+        matches!(
+            (self, segment),
+            (SegmentMutation::Binary(_), Segment::Binary(_))
+                | (SegmentMutation::Integer(_), Segment::Integer(_))
+                | (SegmentMutation::Real(_), Segment::Real(_))
+                | (SegmentMutation::Permutation(_), Segment::Permutation(_))
+        )
+    }
+}
+
+/**
+## Description
+`SegmentOperator` holds the crossover and mutation used for one segment of a [`CompositeGenome`]
+together with the probability of applying each of them.
+
+### Note
+- `crossover_rate` is the probability that the segment is recombined, otherwise it is copied from the parents.
+- `mutation_rate` is the probability that the segment is mutated. For real segments it applies to each gene separately.
+
+## Example
+```rust
+  use genx::{
+    crossover::{blend_crossover, order_crossover, uniform_crossover},
+    genome::{SegmentCrossover, SegmentMutation, SegmentOperator},
+    mutation::{flipping_mutation, polynomial_mutation, swap_mutation},
+  };
+
+  let operators = vec![
+    SegmentOperator::new(
+      SegmentCrossover::permutation(order_crossover),
+      0.9,
+      SegmentMutation::permutation(|individual, seed| swap_mutation(individual, seed).unwrap()),
+      0.2,
+    ),
+    SegmentOperator::new(
+      SegmentCrossover::binary(|parent1, parent2, seed| uniform_crossover(parent1, parent2, 0.5, seed)),
+      0.7,
+      SegmentMutation::binary(|individual, seed| flipping_mutation(individual, 0.1, seed).unwrap()),
+      0.5,
+    ),
+    SegmentOperator::new(
+      SegmentCrossover::real(|parent1, parent2, seed| blend_crossover(parent1, parent2, 0.5, seed)),
+      1.0,
+      SegmentMutation::real(|gene, seed| polynomial_mutation(gene, 20.0, 0.5, seed)),
+      0.1,
+    ),
+  ];
+```
+*/
+pub struct SegmentOperator {
+    pub crossover: SegmentCrossover,
+    pub crossover_rate: f32,
+    pub mutation: SegmentMutation,
+    pub mutation_rate: f32,
+}
+
+impl SegmentOperator {
+    pub fn new(crossover: SegmentCrossover, crossover_rate: f32, mutation: SegmentMutation, mutation_rate: f32) -> Self {
+        SegmentOperator {
+            crossover,
+            crossover_rate,
+            mutation,
+            mutation_rate,
+        }
+    }
+}
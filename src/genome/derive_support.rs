@@ -0,0 +1,136 @@
+//! Helpers called by the code generated by `#[derive(Genome)]`, not part of the public API.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{
+    crossover::{blend_crossover, integer_simulated_binary_crossover, order_crossover, single_point_crossover},
+    mutation::{creep_mutation, flipping_mutation, polynomial_mutation, swap_mutation},
+};
+
+/// Hands out a seed for every field so a seeded call stays deterministic.
+pub struct Seeds {
+    prng: StdRng,
+    seeded: bool,
+}
+
+impl Seeds {
+    pub fn new(seed: Option<u64>) -> Self {
+        let prng = match seed {
+            Some(val) => StdRng::seed_from_u64(val),
+            None => StdRng::from_entropy(),
+        };
+        Seeds {
+            prng,
+            seeded: seed.is_some(),
+        }
+    }
+
+    pub fn next_seed(&mut self) -> Option<u64> {
+        let val = self.prng.gen::<u64>();
+        if self.seeded {
+            Some(val)
+        } else {
+            None
+        }
+    }
+
+    pub fn roll(&mut self) -> f32 {
+        self.prng.gen_range(0.0..1.0)
+    }
+}
+
+fn prng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(val) => StdRng::seed_from_u64(val),
+        None => StdRng::from_entropy(),
+    }
+}
+
+pub fn random_real(lower: f32, upper: f32, seed: Option<u64>) -> f32 {
+    if upper <= lower {
+        return lower;
+    }
+    prng(seed).gen_range(lower..=upper)
+}
+
+pub fn crossover_real(parent1: f32, parent2: f32, lower: f32, upper: f32, seed: Option<u64>) -> (f32, f32) {
+    let (child1, child2) = blend_crossover(parent1, parent2, 0.5, seed);
+    (child1.clamp(lower, upper), child2.clamp(lower, upper))
+}
+
+pub fn mutate_real(individual: f32, lower: f32, upper: f32, seeds: &mut Seeds, mutation_probability: f32) -> f32 {
+    if seeds.roll() >= mutation_probability {
+        return individual;
+    }
+    polynomial_mutation(individual, 20.0, (upper - lower) / 10.0, seeds.next_seed()).clamp(lower, upper)
+}
+
+pub fn random_integer(lower: i32, upper: i32, seed: Option<u64>) -> i32 {
+    prng(seed).gen_range(lower..=upper)
+}
+
+pub fn crossover_integer(parent1: i32, parent2: i32, lower: i32, upper: i32, seed: Option<u64>) -> (i32, i32) {
+    let (child1, child2) =
+        integer_simulated_binary_crossover(&vec![parent1], &vec![parent2], &vec![(lower, upper)], 2.0, seed).unwrap();
+    (child1[0], child2[0])
+}
+
+pub fn mutate_integer(individual: i32, lower: i32, upper: i32, seeds: &mut Seeds, mutation_probability: f32) -> i32 {
+    let mut genes = vec![individual.clamp(lower, upper)];
+    // the range of valid bounds can exceed i32, but a tenth of it can not
+    let max_step = ((upper as i64 - lower as i64) / 10).max(1) as i32;
+    creep_mutation(&mut genes, &vec![(lower, upper)], max_step, mutation_probability.clamp(0.0, 1.0), seeds.next_seed()).unwrap();
+    genes[0]
+}
+
+fn to_bits(value: u64, bits: usize) -> Vec<bool> {
+    (0..bits).rev().map(|shift| value >> shift & 1 == 1).collect()
+}
+
+fn from_bits(bits: &[bool]) -> u64 {
+    bits.iter().fold(0u64, |acc, &bit| (acc << 1) | bit as u64)
+}
+
+pub fn random_bits(bits: usize, seed: Option<u64>) -> u64 {
+    from_bits(&random_mask(bits, seed))
+}
+
+pub fn crossover_bits(parent1: u64, parent2: u64, bits: usize, seed: Option<u64>) -> (u64, u64) {
+    let (child1, child2) = single_point_crossover(&to_bits(parent1, bits), &to_bits(parent2, bits), seed);
+    (from_bits(&child1), from_bits(&child2))
+}
+
+pub fn mutate_bits(individual: u64, bits: usize, seeds: &mut Seeds, mutation_probability: f32) -> u64 {
+    let mut genes = to_bits(individual, bits);
+    mutate_mask(&mut genes, seeds, mutation_probability);
+    from_bits(&genes)
+}
+
+pub fn random_mask(bits: usize, seed: Option<u64>) -> Vec<bool> {
+    let mut prng = prng(seed);
+    (0..bits).map(|_| prng.gen::<bool>()).collect()
+}
+
+pub fn crossover_mask(parent1: &Vec<bool>, parent2: &Vec<bool>, seed: Option<u64>) -> (Vec<bool>, Vec<bool>) {
+    single_point_crossover(parent1, parent2, seed)
+}
+
+pub fn mutate_mask(individual: &mut Vec<bool>, seeds: &mut Seeds, mutation_probability: f32) {
+    flipping_mutation(individual, mutation_probability.clamp(0.0, 1.0), seeds.next_seed()).unwrap();
+}
+
+pub fn random_permutation(len: usize, seed: Option<u64>) -> Vec<usize> {
+    let mut individual = (0..len).collect::<Vec<usize>>();
+    individual.shuffle(&mut prng(seed));
+    individual
+}
+
+pub fn crossover_permutation(parent1: &Vec<usize>, parent2: &Vec<usize>, seed: Option<u64>) -> (Vec<usize>, Vec<usize>) {
+    order_crossover(parent1, parent2, seed)
+}
+
+pub fn mutate_permutation(individual: &mut Vec<usize>, seeds: &mut Seeds, mutation_probability: f32) {
+    if seeds.roll() < mutation_probability && individual.len() > 1 {
+        swap_mutation(individual, seeds.next_seed()).unwrap();
+    }
+}
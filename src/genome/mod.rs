@@ -0,0 +1,71 @@
+//! The `genome` module provides dedicated representations for
+//! individuals where a plain `Vec` of genes is not the best fit.
+//!
+//! The provided types are organized in sub-modules
+//! named after the encoding they represent:
+//! * `bit`
+//! * `composite`
+//!
+//! The operators working on these types live alongside the other
+//! operators in the [`crate::crossover`] and [`crate::mutation`] modules.
+//!
+//! Struct shaped solutions can implement the [`Genome`] trait, which with the
+//! `derive` feature enabled is generated by `#[derive(Genome)]`. Every field
+//! is annotated with a `gene` attribute choosing its encoding:
+//! * `#[gene(range = 0.0..10.0)]` for real or integer fields within bounds
+//! * `#[gene(permutation)]` for `Vec<usize>` orders, created as orders of `0..8`
+//!   by `random` when given as `#[gene(permutation, len = 8)]`
+//! * `#[gene(bits = 8)]` for integer fields, or `Vec<bool>` masks, of that many bits
+//!
+//! Fields without a `gene` attribute should implement [`Genome`] themselves.
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use genx::genome::Genome;
+//!
+//! #[derive(Genome, Clone, Debug)]
+//! struct Schedule {
+//!     #[gene(permutation, len = 6)]
+//!     order: Vec<usize>,
+//!     #[gene(bits = 4)]
+//!     machines: u8,
+//!     #[gene(range = 0.5..2.0)]
+//!     speed: f32,
+//! }
+//!
+//! let parent1 = Schedule::random(Some(1));
+//! let parent2 = Schedule::random(Some(2));
+//! let (mut child1, _) = parent1.crossover(&parent2, None);
+//! child1.mutate(0.1, None);
+//! # }
+//! ```
+
+pub mod bit;
+
+pub mod composite;
+
+#[doc(hidden)]
+pub mod derive_support;
+
+pub use self::bit::BitGenome;
+
+pub use self::composite::{CompositeGenome, Segment, SegmentCrossover, SegmentMutation, SegmentOperator};
+
+#[cfg(feature = "derive")]
+pub use genx_derive::Genome;
+
+/// Genome of a struct shaped solution that knows how to create, recombine
+/// and mutate itself by dispatching to the operators of genx.
+pub trait Genome: Sized {
+    /// Creates a random individual.
+    ///
+    /// _Note: The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results._
+    fn random(seed: Option<u64>) -> Self;
+
+    /// Recombines `self` with `other` into two offsprings.
+    fn crossover(&self, other: &Self, seed: Option<u64>) -> (Self, Self);
+
+    /// Mutates the individual in place, `mutation_probability` is applied to every gene separately.
+    fn mutate(&mut self, mutation_probability: f32, seed: Option<u64>);
+}
@@ -0,0 +1,434 @@
+use std::{
+  fmt,
+  io::{self, ErrorKind, Read, Write},
+  net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+  str::FromStr,
+  thread,
+  time::{Duration, Instant},
+};
+#[cfg(unix)]
+use std::{
+  fs,
+  os::unix::net::{UnixListener, UnixStream},
+  path::PathBuf,
+};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{insert_immigrants, select_emigrants, Island, Migrant, Migration, Topology};
+
+/**
+## Description
+`Endpoint` is the address at which a [`DistributedIsland`] receives its immigrants, either a TCP socket address or, on
+unix platforms, the path of a Unix domain socket.
+
+Endpoints are parsed from `host:port` strings, optionally prefixed by `tcp:`, or from `unix:path` strings, and are
+displayed back in the same format so that they can be passed to other processes.
+
+## Example
+```rust
+  use genx::island::Endpoint;
+
+  let endpoint = "127.0.0.1:4000".parse::<Endpoint>().unwrap();
+  assert_eq!(endpoint.to_string(), "127.0.0.1:4000");
+  assert!("localhost".parse::<Endpoint>().is_err());
+```
+*/
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+  Tcp(SocketAddr),
+  #[cfg(unix)]
+  Unix(PathBuf),
+}
+
+impl FromStr for Endpoint {
+  type Err = &'static str;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    #[cfg(unix)]
+    {
+      if let Some(path) = s.strip_prefix("unix:") {
+        return Ok(Endpoint::Unix(PathBuf::from(path)));
+      }
+    }
+    s.strip_prefix("tcp:")
+      .unwrap_or(s)
+      .to_socket_addrs()
+      .ok()
+      .and_then(|mut addresses| addresses.next())
+      .map(Endpoint::Tcp)
+      .ok_or("endpoint should be of the form host:port or unix:path")
+  }
+}
+
+impl fmt::Display for Endpoint {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Endpoint::Tcp(address) => write!(f, "{}", address),
+      #[cfg(unix)]
+      Endpoint::Unix(path) => write!(f, "unix:{}", path.display()),
+    }
+  }
+}
+
+enum Listener {
+  Tcp(TcpListener),
+  #[cfg(unix)]
+  Unix(UnixListener, PathBuf),
+}
+
+impl Listener {
+  fn bind(endpoint: &Endpoint) -> Result<Self, &'static str> {
+    let listener = match endpoint {
+      Endpoint::Tcp(address) => TcpListener::bind(address).and_then(|listener| listener.set_nonblocking(true).map(|_| Listener::Tcp(listener))),
+      #[cfg(unix)]
+      Endpoint::Unix(path) => UnixListener::bind(path).and_then(|listener| listener.set_nonblocking(true).map(|_| Listener::Unix(listener, path.clone()))),
+    };
+    listener.map_err(|_| "could not listen on the endpoint of the island")
+  }
+
+  /// Endpoint the listener is bound to, with the port chosen by the system when binding port `0`.
+  fn endpoint(&self) -> io::Result<Endpoint> {
+    match self {
+      Listener::Tcp(listener) => listener.local_addr().map(Endpoint::Tcp),
+      #[cfg(unix)]
+      Listener::Unix(_, path) => Ok(Endpoint::Unix(path.clone())),
+    }
+  }
+
+  /// Accepts a pending connection, failing with `ErrorKind::WouldBlock` if there is none.
+  fn accept(&self, timeout: Duration) -> io::Result<Box<dyn Read>> {
+    match self {
+      Listener::Tcp(listener) => listener.accept().and_then(|(stream, _)| {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(timeout))?;
+        Ok(Box::new(stream) as Box<dyn Read>)
+      }),
+      #[cfg(unix)]
+      Listener::Unix(listener, _) => listener.accept().and_then(|(stream, _)| {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(timeout))?;
+        Ok(Box::new(stream) as Box<dyn Read>)
+      }),
+    }
+  }
+}
+
+#[cfg(unix)]
+impl Drop for Listener {
+  fn drop(&mut self) {
+    if let Listener::Unix(_, path) = self {
+      let _ = fs::remove_file(path);
+    }
+  }
+}
+
+/// Sends `bytes` to `endpoint`, retrying until `deadline` while the receiving island is not listening yet.
+fn send(endpoint: &Endpoint, bytes: &[u8], deadline: Instant) -> Result<(), &'static str> {
+  loop {
+    let stream = match endpoint {
+      Endpoint::Tcp(address) => TcpStream::connect(address).map(|stream| Box::new(stream) as Box<dyn Write>),
+      #[cfg(unix)]
+      Endpoint::Unix(path) => UnixStream::connect(path).map(|stream| Box::new(stream) as Box<dyn Write>),
+    };
+    match stream {
+      Ok(mut stream) => return stream.write_all(bytes).and_then(|_| stream.flush()).map_err(|_| "could not send migrants"),
+      Err(_) if Instant::now() < deadline => thread::sleep(Duration::from_millis(10)),
+      Err(_) => return Err("could not connect to the endpoint of an island"),
+    }
+  }
+}
+
+type Batch<T> = (u64, usize, Vec<(T, f32)>);
+
+/// Bytes opening every batch of migrants, followed by the version of the format, so that stray connections are told apart.
+const MAGIC: [u8; 4] = *b"GENX";
+const VERSION: u8 = 1;
+
+/// Largest batch of migrants read from a connection, 64 MiB.
+const MAX_BATCH_SIZE: u64 = 64 << 20;
+
+/// Longest wait for the next bytes of a connection, so that a silent connection does not hold up the migration.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+fn encode_batch<T: Migrant>(batch: &Batch<T>) -> Vec<u8> {
+  let mut bytes = MAGIC.to_vec();
+  bytes.push(VERSION);
+  batch.encode(&mut bytes);
+  bytes
+}
+
+/// Reads a batch from a connection, `None` if it could not be read, is too large, or is not a batch of migrants.
+fn read_batch<T: Migrant>(stream: Box<dyn Read>) -> Option<Batch<T>> {
+  let mut bytes = Vec::new();
+  stream.take(MAX_BATCH_SIZE + 1).read_to_end(&mut bytes).ok()?;
+  if bytes.len() as u64 > MAX_BATCH_SIZE {
+    return None;
+  }
+  match bytes.strip_prefix(&MAGIC[..]) {
+    Some([version, batch @ ..]) if *version == VERSION => Batch::<T>::from_bytes(batch).ok(),
+    _ => None,
+  }
+}
+
+/// Batches received ahead of their migration, and the number of connections skipped so far.
+struct Inbox<T> {
+  batches: Vec<Batch<T>>,
+  rejected: usize,
+}
+
+/**
+Waits until the batches of `epoch` from all `sources` have been received, and returns their migrants in source order.
+Connections which are not a batch of migrants, and batches of past migrations, of unknown islands, or received twice
+are skipped and counted as rejected.
+*/
+fn receive<T: Migrant>(listener: &Listener, inbox: &mut Inbox<T>, epoch: u64, sources: &[usize], num_islands: usize, deadline: Instant) -> Result<Vec<(T, f32)>, &'static str> {
+  while !sources.iter().all(|&source| inbox.batches.iter().any(|batch| batch.0 == epoch && batch.1 == source)) {
+    let now = Instant::now();
+    if now >= deadline {
+      return Err("timed out waiting for immigrants");
+    }
+    match listener.accept(READ_TIMEOUT.min(deadline - now).max(Duration::from_millis(1))) {
+      Ok(stream) => match read_batch::<T>(stream) {
+        Some(batch) if batch.0 >= epoch && batch.1 < num_islands && !inbox.batches.iter().any(|other| (other.0, other.1) == (batch.0, batch.1)) => {
+          inbox.batches.push(batch)
+        }
+        _ => inbox.rejected += 1,
+      },
+      Err(error) if error.kind() == ErrorKind::WouldBlock => thread::sleep(Duration::from_millis(1)),
+      Err(_) => {
+        inbox.rejected += 1;
+        thread::sleep(Duration::from_millis(1));
+      }
+    }
+  }
+  let (current, later) = std::mem::take(&mut inbox.batches).into_iter().partition::<Vec<Batch<T>>, _>(|batch| batch.0 == epoch);
+  inbox.batches = later;
+  let (mut current, unexpected) = current.into_iter().partition::<Vec<Batch<T>>, _>(|batch| sources.contains(&batch.1));
+  inbox.rejected += unexpected.len();
+  current.sort_by_key(|batch| batch.1);
+  Ok(current.into_iter().flat_map(|batch| batch.2).collect())
+}
+
+/// Seed of the `index`th island drawn from a `seed` shared by all the islands, so that their random streams are unrelated.
+fn island_seed(seed: u64, index: usize) -> u64 {
+  let mut prng = StdRng::seed_from_u64(seed);
+  (0..=index).map(|_| prng.gen::<u64>()).last().unwrap()
+}
+
+/**
+## Description
+`DistributedIsland` is an island of an island model whose islands live in separate processes, possibly on separate
+machines, and exchange migrants over TCP or Unix domain sockets using the [`Migrant`] serialisation of individuals.
+
+Every process runs one island, given its `index` and the `endpoints` of all the islands of the model, and listens on
+its own endpoint. At every migration it sends its emigrants to its destinations in the topology, then waits for the
+emigrants of all its sources before going on, so all the islands migrate in lockstep as in an [`IslandModel`](super::IslandModel).
+
+### Note
+
+- All the processes should run with the same `seed`, from which the seeds of every island are derived along with its
+  index. A `Topology::Random` requires a seed, so that all the islands agree on the connections of every migration.
+- Sending and waiting give up after a timeout of 30 seconds by default, which can be changed with `set_timeout`.
+- Batches of migrants start with a header, and connections which do not carry a valid batch from an island of the
+  model are skipped rather than failing the migration, see `rejected`.
+- An endpoint with port `0` listens on a port chosen by the system, reported by `endpoint`. To hand such an endpoint
+  to the other islands before they start, bind the listener beforehand and pass it to `from_listener`.
+
+## Example
+```rust
+  use genx::island::{DistributedIsland, EmigrantPolicy, Endpoint, ImmigrantPolicy, Island, Migration, Topology};
+  use std::thread;
+
+  let endpoints = vec!["127.0.0.1:0".parse::<Endpoint>().unwrap()];
+  let island = Island::new(vec![vec![1.0_f32, 2.0]], vec![3.0], |_: &mut Vec<Vec<f32>>, _: &mut Vec<f32>, _, _| {});
+  let migration = Migration::new(1, 10, EmigrantPolicy::Best, ImmigrantPolicy::ReplaceWorst);
+  // every island would usually run in its own process with its index passed on the command line
+  let mut island = DistributedIsland::new(island, 0, endpoints, Topology::Ring, migration).unwrap();
+  island.run(20, Some(42)).unwrap();
+  assert_eq!(island.generation(), 20);
+```
+*/
+pub struct DistributedIsland<'a, T> {
+  island: Island<'a, T>,
+  index: usize,
+  endpoints: Vec<Endpoint>,
+  topology: Topology,
+  migration: Migration,
+  timeout: Duration,
+  listener: Listener,
+  inbox: Inbox<T>,
+  generation: usize,
+  epoch: u64,
+}
+
+impl<'a, T: Clone + Migrant> DistributedIsland<'a, T> {
+  /**
+  Creates the `index`th island of a distributed island model and starts listening on its endpoint, which returns error
+  if `index` is not the index of an endpoint, if the migration interval is `0`, if the population and fitness values
+  of the island have different lengths, if the migration size is larger than the population, or if the endpoint of the
+  island can not be listened on.
+  */
+  pub fn new(island: Island<'a, T>, index: usize, endpoints: Vec<Endpoint>, topology: Topology, migration: Migration) -> Result<Self, &'static str> {
+    if index >= endpoints.len() {
+      return Err("index should be less than the number of endpoints");
+    }
+    let listener = Listener::bind(&endpoints[index])?;
+    Self::listening(island, index, endpoints, listener, topology, migration)
+  }
+
+  /**
+  Creates the `index`th island of a distributed island model receiving its immigrants on an already bound TCP
+  `listener`, which avoids racing other processes for a free port between choosing the endpoints and listening on
+  them. The endpoint of the island is the address of the listener, and errors are returned as by `new`.
+  */
+  pub fn from_listener(island: Island<'a, T>, index: usize, endpoints: Vec<Endpoint>, listener: TcpListener, topology: Topology, migration: Migration) -> Result<Self, &'static str> {
+    listener.set_nonblocking(true).map_err(|_| "could not listen on the endpoint of the island")?;
+    Self::listening(island, index, endpoints, Listener::Tcp(listener), topology, migration)
+  }
+
+  fn listening(island: Island<'a, T>, index: usize, mut endpoints: Vec<Endpoint>, listener: Listener, topology: Topology, migration: Migration) -> Result<Self, &'static str> {
+    if index >= endpoints.len() {
+      return Err("index should be less than the number of endpoints");
+    }
+    if migration.interval == 0 {
+      return Err("migration interval should be greater than 0");
+    }
+    if island.population.len() != island.fitness_values.len() {
+      return Err("population and fitness_values should have the same length");
+    }
+    if migration.size > island.population.len() {
+      return Err("migration size should be at most the size of the population");
+    }
+    endpoints[index] = listener.endpoint().map_err(|_| "could not listen on the endpoint of the island")?;
+    Ok(DistributedIsland {
+      island,
+      index,
+      endpoints,
+      topology,
+      migration,
+      timeout: Duration::from_secs(30),
+      listener,
+      inbox: Inbox { batches: Vec::new(), rejected: 0 },
+      generation: 0,
+      epoch: 0,
+    })
+  }
+
+  pub fn island(&self) -> &Island<'a, T> {
+    &self.island
+  }
+
+  pub fn island_mut(&mut self) -> &mut Island<'a, T> {
+    &mut self.island
+  }
+
+  pub fn index(&self) -> usize {
+    self.index
+  }
+
+  pub fn endpoints(&self) -> &Vec<Endpoint> {
+    &self.endpoints
+  }
+
+  /// Endpoint the island listens on, with the port chosen by the system if it was given port `0`.
+  pub fn endpoint(&self) -> &Endpoint {
+    &self.endpoints[self.index]
+  }
+
+  /// Number of connections skipped so far because they did not carry a valid batch of migrants from a source of the island.
+  pub fn rejected(&self) -> usize {
+    self.inbox.rejected
+  }
+
+  /// Number of generations run so far.
+  pub fn generation(&self) -> usize {
+    self.generation
+  }
+
+  pub fn timeout(&self) -> Duration {
+    self.timeout
+  }
+
+  pub fn set_timeout(&mut self, timeout: Duration) {
+    self.timeout = timeout;
+  }
+
+  /**
+  Sends emigrants to the destinations of the island and inserts the immigrants of its sources, which returns error if
+  the migration size is larger than the population, if the topology is random without a seed, or if the migrants could
+  not be exchanged before the timeout.
+  */
+  pub fn migrate(&mut self, seed: Option<u64>) -> Result<(), &'static str> {
+    if let (Topology::Random(_), None) = (self.topology, seed) {
+      return Err("a random topology needs a seed shared by all the islands");
+    }
+    let mut prng = match seed {
+      Some(val) => StdRng::seed_from_u64(island_seed(val, self.index)),
+      None => StdRng::from_entropy()
+    };
+    let num_islands = self.endpoints.len();
+    let destinations = self.topology.destinations(num_islands, seed).swap_remove(self.index);
+    let sources = self.topology.sources(num_islands, seed).swap_remove(self.index);
+
+    let emigrants = select_emigrants(
+      &self.island.population,
+      &self.island.fitness_values,
+      self.migration.size,
+      self.migration.emigrant_policy,
+      seed.map(|_| prng.gen::<u64>()),
+    )?;
+    let batch = encode_batch(&(self.epoch, self.index, emigrants));
+
+    let deadline = Instant::now() + self.timeout;
+    let (endpoints, listener, inbox, epoch) = (&self.endpoints, &self.listener, &mut self.inbox, self.epoch);
+    let batch = &batch;
+    let (sent, immigrants) = thread::scope(|scope| {
+      let senders = destinations
+        .iter()
+        .map(|&destination| scope.spawn(move || send(&endpoints[destination], batch, deadline)))
+        .collect::<Vec<_>>();
+      let immigrants = receive(listener, inbox, epoch, &sources, num_islands, deadline);
+      let sent = senders
+        .into_iter()
+        .try_for_each(|sender| sender.join().unwrap_or(Err("could not send migrants")));
+      (sent, immigrants)
+    });
+    sent?;
+    self.epoch += 1;
+    insert_immigrants(
+      &mut self.island.population,
+      &mut self.island.fitness_values,
+      immigrants?,
+      self.migration.immigrant_policy,
+      seed.map(|_| prng.gen::<u64>()),
+    )
+  }
+
+  /// Evolves the island for one generation, then migrates if the migration interval has elapsed.
+  pub fn step(&mut self, seed: Option<u64>) -> Result<(), &'static str> {
+    let mut prng = match seed {
+      Some(val) => StdRng::seed_from_u64(val),
+      None => StdRng::from_entropy()
+    };
+    let island_seed = seed.map(|_| island_seed(prng.gen::<u64>(), self.index));
+    self.island.evolve(self.generation, island_seed);
+    self.generation += 1;
+    if self.generation % self.migration.interval == 0 {
+      self.migrate(seed.map(|_| prng.gen::<u64>()))?;
+    }
+    Ok(())
+  }
+
+  /// Runs `generations` steps of the island.
+  pub fn run(&mut self, generations: usize, seed: Option<u64>) -> Result<(), &'static str> {
+    let mut prng = match seed {
+      Some(val) => StdRng::seed_from_u64(val),
+      None => StdRng::from_entropy()
+    };
+    for _ in 0..generations {
+      self.step(seed.map(|_| prng.gen::<u64>()))?;
+    }
+    Ok(())
+  }
+}
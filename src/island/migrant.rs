@@ -0,0 +1,204 @@
+use crate::genome::{BitGenome, CompositeGenome, Segment};
+
+/**
+## Description
+`Migrant` serialises individuals into bytes so that they can be sent to islands living in other processes, see
+[`DistributedIsland`](super::DistributedIsland).
+
+The format is a compact little endian binary format: numbers are written as their little endian bytes, `usize` as
+a `u64`, and vectors as their length followed by their elements. It is implemented for the primitive types, vectors,
+pairs and triples of migrants, and the genomes of `genx::genome`, so that most individuals can migrate without any additional
+dependency. Other individuals can implement it on top of the implementations of their fields.
+
+## Example
+```rust
+  use genx::island::Migrant;
+
+  let individual = (vec![true, false, true], vec![0.5_f32, 1.5]);
+  let bytes = individual.to_bytes();
+  assert_eq!(<(Vec<bool>, Vec<f32>)>::from_bytes(&bytes).unwrap(), individual);
+```
+*/
+pub trait Migrant: Sized {
+  /// Appends the serialised migrant to `buffer`.
+  fn encode(&self, buffer: &mut Vec<u8>);
+
+  /// Reads a migrant from the front of `bytes`, advancing `bytes` past it.
+  fn decode(bytes: &mut &[u8]) -> Result<Self, &'static str>;
+
+  fn to_bytes(&self) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    self.encode(&mut buffer);
+    buffer
+  }
+
+  /// Reads a migrant taking up all of `bytes`.
+  fn from_bytes(mut bytes: &[u8]) -> Result<Self, &'static str> {
+    let migrant = Self::decode(&mut bytes)?;
+    if !bytes.is_empty() {
+      return Err("unexpected trailing bytes after migrant");
+    }
+    Ok(migrant)
+  }
+}
+
+fn take<'b>(bytes: &mut &'b [u8], count: usize) -> Result<&'b [u8], &'static str> {
+  if bytes.len() < count {
+    return Err("unexpected end of migrant bytes");
+  }
+  let (taken, rest) = bytes.split_at(count);
+  *bytes = rest;
+  Ok(taken)
+}
+
+macro_rules! impl_migrant_for_number {
+  ($($number:ty),*) => {
+    $(
+      impl Migrant for $number {
+        fn encode(&self, buffer: &mut Vec<u8>) {
+          buffer.extend_from_slice(&self.to_le_bytes());
+        }
+
+        fn decode(bytes: &mut &[u8]) -> Result<Self, &'static str> {
+          let mut array = [0; std::mem::size_of::<$number>()];
+          array.copy_from_slice(take(bytes, std::mem::size_of::<$number>())?);
+          Ok(<$number>::from_le_bytes(array))
+        }
+      }
+    )*
+  };
+}
+
+impl_migrant_for_number!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl Migrant for usize {
+  fn encode(&self, buffer: &mut Vec<u8>) {
+    (*self as u64).encode(buffer);
+  }
+
+  fn decode(bytes: &mut &[u8]) -> Result<Self, &'static str> {
+    let value = u64::decode(bytes)?;
+    if value > usize::MAX as u64 {
+      return Err("migrant value does not fit in usize");
+    }
+    Ok(value as usize)
+  }
+}
+
+impl Migrant for bool {
+  fn encode(&self, buffer: &mut Vec<u8>) {
+    buffer.push(*self as u8);
+  }
+
+  fn decode(bytes: &mut &[u8]) -> Result<Self, &'static str> {
+    match u8::decode(bytes)? {
+      0 => Ok(false),
+      1 => Ok(true),
+      _ => Err("invalid bool in migrant bytes"),
+    }
+  }
+}
+
+impl<T: Migrant> Migrant for Vec<T> {
+  fn encode(&self, buffer: &mut Vec<u8>) {
+    self.len().encode(buffer);
+    for item in self.iter() {
+      item.encode(buffer);
+    }
+  }
+
+  fn decode(bytes: &mut &[u8]) -> Result<Self, &'static str> {
+    let len = usize::decode(bytes)?;
+    // every item takes at least a byte, which bounds the allocation for corrupted lengths
+    let mut items = Vec::with_capacity(len.min(bytes.len()));
+    for _ in 0..len {
+      items.push(T::decode(bytes)?);
+    }
+    Ok(items)
+  }
+}
+
+impl<A: Migrant, B: Migrant> Migrant for (A, B) {
+  fn encode(&self, buffer: &mut Vec<u8>) {
+    self.0.encode(buffer);
+    self.1.encode(buffer);
+  }
+
+  fn decode(bytes: &mut &[u8]) -> Result<Self, &'static str> {
+    Ok((A::decode(bytes)?, B::decode(bytes)?))
+  }
+}
+
+impl<A: Migrant, B: Migrant, C: Migrant> Migrant for (A, B, C) {
+  fn encode(&self, buffer: &mut Vec<u8>) {
+    self.0.encode(buffer);
+    self.1.encode(buffer);
+    self.2.encode(buffer);
+  }
+
+  fn decode(bytes: &mut &[u8]) -> Result<Self, &'static str> {
+    Ok((A::decode(bytes)?, B::decode(bytes)?, C::decode(bytes)?))
+  }
+}
+
+impl Migrant for BitGenome {
+  fn encode(&self, buffer: &mut Vec<u8>) {
+    self.len().encode(buffer);
+    for word in self.words() {
+      word.encode(buffer);
+    }
+  }
+
+  fn decode(bytes: &mut &[u8]) -> Result<Self, &'static str> {
+    let len = usize::decode(bytes)?;
+    let num_words = len / 64 + usize::from(len % 64 != 0);
+    if bytes.len() / 8 < num_words {
+      return Err("unexpected end of migrant bytes");
+    }
+    let words = (0..num_words).map(|_| u64::decode(bytes)).collect::<Result<Vec<u64>, &'static str>>()?;
+    Ok(BitGenome::from_words(words, len))
+  }
+}
+
+impl Migrant for Segment {
+  fn encode(&self, buffer: &mut Vec<u8>) {
+    match self {
+      Segment::Binary(genes) => {
+        buffer.push(0);
+        genes.encode(buffer);
+      }
+      Segment::Integer(genes) => {
+        buffer.push(1);
+        genes.encode(buffer);
+      }
+      Segment::Real(genes) => {
+        buffer.push(2);
+        genes.encode(buffer);
+      }
+      Segment::Permutation(genes) => {
+        buffer.push(3);
+        genes.encode(buffer);
+      }
+    }
+  }
+
+  fn decode(bytes: &mut &[u8]) -> Result<Self, &'static str> {
+    match u8::decode(bytes)? {
+      0 => Ok(Segment::Binary(Vec::decode(bytes)?)),
+      1 => Ok(Segment::Integer(Vec::decode(bytes)?)),
+      2 => Ok(Segment::Real(Vec::decode(bytes)?)),
+      3 => Ok(Segment::Permutation(Vec::decode(bytes)?)),
+      _ => Err("invalid segment kind in migrant bytes"),
+    }
+  }
+}
+
+impl Migrant for CompositeGenome {
+  fn encode(&self, buffer: &mut Vec<u8>) {
+    self.segments.encode(buffer);
+  }
+
+  fn decode(bytes: &mut &[u8]) -> Result<Self, &'static str> {
+    Ok(CompositeGenome::new(Vec::decode(bytes)?))
+  }
+}
@@ -0,0 +1,134 @@
+use std::cmp::Ordering;
+
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+
+use crate::replacement::{replace_random, replace_worst};
+
+/// Decides which individuals of an island are copied to the other islands.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EmigrantPolicy {
+  /// Sends the fittest individuals.
+  Best,
+  /// Sends distinct individuals chosen uniformly at random.
+  Random,
+}
+
+/// Decides which individuals of an island are replaced by the received immigrants.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImmigrantPolicy {
+  /// Replaces the worst individuals, see `genx::replacement::replace_worst`.
+  ReplaceWorst,
+  /// Replaces distinct individuals chosen uniformly at random, see `genx::replacement::replace_random`.
+  ReplaceRandom,
+}
+
+/**
+## Description
+`Migration` configures the exchange of individuals between islands: every `interval` generations each island sends copies
+of `size` of its individuals, chosen by the `emigrant_policy`, to its destinations in the topology, and those immigrants
+replace individuals of the receiving island chosen by the `immigrant_policy`.
+
+## Example
+```rust
+  use genx::island::{EmigrantPolicy, ImmigrantPolicy, Migration};
+
+  let migration = Migration::new(2, 10, EmigrantPolicy::Best, ImmigrantPolicy::ReplaceWorst);
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Migration {
+  pub size: usize,
+  pub interval: usize,
+  pub emigrant_policy: EmigrantPolicy,
+  pub immigrant_policy: ImmigrantPolicy,
+}
+
+impl Migration {
+  pub fn new(size: usize, interval: usize, emigrant_policy: EmigrantPolicy, immigrant_policy: ImmigrantPolicy) -> Self {
+    Migration { size, interval, emigrant_policy, immigrant_policy }
+  }
+}
+
+/**
+## Description
+Copies `size` individuals of the population, paired with their fitness values, to be sent to other islands according to
+the emigrant `policy`.
+
+### Note
+
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results of `EmigrantPolicy::Random`.
+
+## Return
+
+The return value is a `Result<Vec<(T, f32)>, &'static str>` which will return error if `population` and `fitness_values`
+have different lengths, or if `size` is larger than the population.
+
+## Example
+```rust
+  use genx::island::{select_emigrants, EmigrantPolicy};
+  let population = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+  let fitness_values = vec![1.0, 4.0, 0.5];
+
+  let emigrants = select_emigrants(&population, &fitness_values, 1, EmigrantPolicy::Best, None).unwrap();
+  assert_eq!(emigrants, vec![(vec![3, 4], 4.0)]);
+```
+*/
+pub fn select_emigrants<T: Clone>(population: &Vec<T>, fitness_values: &Vec<f32>, size: usize, policy: EmigrantPolicy, seed: Option<u64>) -> Result<Vec<(T, f32)>, &'static str> {
+  if population.len() != fitness_values.len() {
+    return Err("population and fitness_values should have the same length");
+  }
+  if size > population.len() {
+    return Err("migration size should be at most the size of the population");
+  }
+  let chosen = match policy {
+    EmigrantPolicy::Best => {
+      let mut order = (0..population.len()).collect::<Vec<usize>>();
+      order.sort_by(|&a, &b| fitness_values[b].partial_cmp(&fitness_values[a]).unwrap_or(Ordering::Equal));
+      order.truncate(size);
+      order
+    }
+    EmigrantPolicy::Random => {
+      let mut prng = match seed {
+        Some(val) => StdRng::seed_from_u64(val),
+        None => StdRng::from_entropy()
+      };
+      sample(&mut prng, population.len(), size).into_vec()
+    }
+  };
+  Ok(chosen.into_iter().map(|idx| (population[idx].clone(), fitness_values[idx])).collect())
+}
+
+/**
+## Description
+Inserts the `immigrants` received from other islands into the population according to the immigrant `policy`.
+
+### Note
+
+- When an island receives more immigrants than it has individuals, only the fittest immigrants are kept.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results of `ImmigrantPolicy::ReplaceRandom`.
+
+## Return
+
+The return value is a `Result<(), &'static str>` which will return error if `population` and `fitness_values` have
+different lengths.
+
+## Example
+```rust
+  use genx::island::{insert_immigrants, ImmigrantPolicy};
+  let mut population = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+  let mut fitness_values = vec![1.0, 4.0, 0.5];
+
+  insert_immigrants(&mut population, &mut fitness_values, vec![(vec![7, 8], 2.0)], ImmigrantPolicy::ReplaceWorst, None).unwrap();
+  assert_eq!(population, vec![vec![1, 2], vec![3, 4], vec![7, 8]]);
+```
+*/
+pub fn insert_immigrants<T>(population: &mut Vec<T>, fitness_values: &mut Vec<f32>, mut immigrants: Vec<(T, f32)>, policy: ImmigrantPolicy, seed: Option<u64>) -> Result<(), &'static str> {
+  if immigrants.len() > population.len() {
+    immigrants.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+    immigrants.truncate(population.len());
+  }
+  match policy {
+    ImmigrantPolicy::ReplaceWorst => replace_worst(population, fitness_values, immigrants),
+    ImmigrantPolicy::ReplaceRandom => replace_random(population, fitness_values, immigrants, seed),
+  }
+}
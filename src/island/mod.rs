@@ -0,0 +1,41 @@
+//! The `island` module provides implementation of the island model,
+//! in which several subpopulations, the islands, evolve independently
+//! and in parallel, each with its own operators, while periodically
+//! exchanging individuals.
+//!
+//! An [`IslandModel`] runs one thread per [`Island`] and migrates
+//! individuals between them:
+//! * `topology`: the connections along which islands send emigrants,
+//!   among ring, fully connected, star and random topologies.
+//! * `migration`: the migration size and interval, and the policies
+//!   choosing the emigrants and the individuals replaced by immigrants.
+//!
+//! Islands can also live in separate processes, possibly on separate
+//! machines, as [`DistributedIsland`]s exchanging migrants over TCP or
+//! Unix domain sockets:
+//! * `distributed`: the islands and the [`Endpoint`]s they listen on.
+//! * `migrant`: the [`Migrant`] serialisation of individuals sent over
+//!   the network, implemented for common individuals and genomes.
+//!
+//! Higher fitness values are better, and emigrants carry their fitness
+//! values along so that they are not evaluated again.
+
+pub mod distributed;
+
+pub mod migrant;
+
+pub mod migration;
+
+pub mod model;
+
+pub mod topology;
+
+pub use self::distributed::{DistributedIsland, Endpoint};
+
+pub use self::migrant::Migrant;
+
+pub use self::migration::{insert_immigrants, select_emigrants, EmigrantPolicy, ImmigrantPolicy, Migration};
+
+pub use self::model::{Island, IslandModel};
+
+pub use self::topology::Topology;
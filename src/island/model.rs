@@ -0,0 +1,232 @@
+use std::{cmp::Ordering, thread};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{insert_immigrants, select_emigrants, Migration, Topology};
+
+type Evolve<'a, T> = Box<dyn FnMut(&mut Vec<T>, &mut Vec<f32>, usize, Option<u64>) + Send + 'a>;
+
+/**
+## Description
+`Island` is a subpopulation of an island model, evolving independently from the other islands with its own operators.
+
+The `evolve` closure takes the population, its fitness values, the current generation and an optional seed, and runs one
+generation of the genetic algorithm of the island, keeping the fitness values in sync with the population.
+
+## Example
+```rust
+  use genx::{island::Island, mutation::flipping_mutation};
+  let one_max = |individual: &Vec<bool>| individual.iter().filter(|&&x| x).count() as f32;
+
+  let population = vec![vec![false; 8]; 4];
+  let fitness_values = population.iter().map(one_max).collect();
+  let mut island = Island::new(population, fitness_values, move |population: &mut Vec<Vec<bool>>, fitness_values: &mut Vec<f32>, _, seed: Option<u64>| {
+    for (idx, (individual, fitness)) in population.iter_mut().zip(fitness_values.iter_mut()).enumerate() {
+      let mut offspring = individual.clone();
+      flipping_mutation(&mut offspring, 0.2, seed.map(|val| val.wrapping_add(idx as u64))).unwrap();
+      if one_max(&offspring) >= *fitness {
+        *fitness = one_max(&offspring);
+        *individual = offspring;
+      }
+    }
+  });
+  island.evolve(0, None);
+```
+*/
+pub struct Island<'a, T> {
+  pub population: Vec<T>,
+  pub fitness_values: Vec<f32>,
+  evolve: Evolve<'a, T>,
+}
+
+impl<'a, T> Island<'a, T> {
+  pub fn new<E>(population: Vec<T>, fitness_values: Vec<f32>, evolve: E) -> Self
+  where
+    E: FnMut(&mut Vec<T>, &mut Vec<f32>, usize, Option<u64>) + Send + 'a,
+  {
+    Island { population, fitness_values, evolve: Box::new(evolve) }
+  }
+
+  /// Runs one generation of the island.
+  pub fn evolve(&mut self, generation: usize, seed: Option<u64>) {
+    (self.evolve)(&mut self.population, &mut self.fitness_values, generation, seed);
+  }
+
+  /// Fittest individual of the island along with its fitness value, `None` if the island is empty.
+  pub fn best(&self) -> Option<(&T, f32)> {
+    self
+      .population
+      .iter()
+      .zip(self.fitness_values.iter().cloned())
+      .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+  }
+}
+
+/**
+## Description
+`IslandModel` evolves several islands in parallel, one thread per island, and periodically exchanges individuals between
+them along the connections of a [`Topology`] as configured by a [`Migration`].
+
+Isolated islands drift towards different regions of the search space, while migration spreads good building blocks
+between them, which keeps more diversity than a single panmictic population of the same size.
+
+### Note
+
+- `step`, `migrate` and `run` can also take in an optional `seed` value of type `Option<u64>` from which the seeds of
+  every island and migration are drawn, for deterministic results as long as the operators of the islands are seeded.
+
+## Example
+```rust
+  use genx::{island::{EmigrantPolicy, ImmigrantPolicy, Island, IslandModel, Migration, Topology}, mutation::flipping_mutation};
+  let one_max = |individual: &Vec<bool>| individual.iter().filter(|&&x| x).count() as f32;
+  let hill_climber = |mutation_probability: f32| {
+    move |population: &mut Vec<Vec<bool>>, fitness_values: &mut Vec<f32>, _, seed: Option<u64>| {
+      for (idx, (individual, fitness)) in population.iter_mut().zip(fitness_values.iter_mut()).enumerate() {
+        let mut offspring = individual.clone();
+        flipping_mutation(&mut offspring, mutation_probability, seed.map(|val| val.wrapping_add(idx as u64))).unwrap();
+        if one_max(&offspring) >= *fitness {
+          *fitness = one_max(&offspring);
+          *individual = offspring;
+        }
+      }
+    }
+  };
+
+  let islands = vec![0.05, 0.1, 0.2, 0.3]
+    .into_iter()
+    .map(|mutation_probability| Island::new(vec![vec![false; 16]; 10], vec![0.0; 10], hill_climber(mutation_probability)))
+    .collect();
+  let migration = Migration::new(2, 5, EmigrantPolicy::Best, ImmigrantPolicy::ReplaceWorst);
+  let mut model = IslandModel::new(islands, Topology::Ring, migration).unwrap();
+
+  model.run(50, None).unwrap();
+  assert_eq!(model.generation(), 50);
+  let (best, fitness) = model.best().unwrap();
+```
+*/
+pub struct IslandModel<'a, T> {
+  islands: Vec<Island<'a, T>>,
+  topology: Topology,
+  migration: Migration,
+  generation: usize,
+}
+
+impl<'a, T: Clone + Send> IslandModel<'a, T> {
+  /**
+  Creates an island model, which returns error if there are no islands, if the migration interval is `0`, if the
+  population and fitness values of an island have different lengths, or if the migration size is larger than the
+  population of an island.
+  */
+  pub fn new(islands: Vec<Island<'a, T>>, topology: Topology, migration: Migration) -> Result<Self, &'static str> {
+    if islands.is_empty() {
+      return Err("there should be at least one island");
+    }
+    if migration.interval == 0 {
+      return Err("migration interval should be greater than 0");
+    }
+    if islands.iter().any(|island| island.population.len() != island.fitness_values.len()) {
+      return Err("population and fitness_values of every island should have the same length");
+    }
+    if islands.iter().any(|island| migration.size > island.population.len()) {
+      return Err("migration size should be at most the size of the population of every island");
+    }
+    Ok(IslandModel { islands, topology, migration, generation: 0 })
+  }
+
+  pub fn islands(&self) -> &Vec<Island<'a, T>> {
+    &self.islands
+  }
+
+  pub fn islands_mut(&mut self) -> &mut Vec<Island<'a, T>> {
+    &mut self.islands
+  }
+
+  pub fn topology(&self) -> Topology {
+    self.topology
+  }
+
+  pub fn migration(&self) -> Migration {
+    self.migration
+  }
+
+  /// Number of generations run so far.
+  pub fn generation(&self) -> usize {
+    self.generation
+  }
+
+  /// Fittest individual over all the islands along with its fitness value.
+  pub fn best(&self) -> Option<(&T, f32)> {
+    self
+      .islands
+      .iter()
+      .filter_map(|island| island.best())
+      .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+  }
+
+  /**
+  Sends emigrants from every island to its destinations in the topology, which returns error if the migration size is
+  larger than the population of an island.
+  */
+  pub fn migrate(&mut self, seed: Option<u64>) -> Result<(), &'static str> {
+    let mut prng = match seed {
+      Some(val) => StdRng::seed_from_u64(val),
+      None => StdRng::from_entropy()
+    };
+    let destinations = self.topology.destinations(self.islands.len(), seed.map(|_| prng.gen::<u64>()));
+    let mut immigrants = vec![Vec::new(); self.islands.len()];
+    for (island, destinations) in self.islands.iter().zip(destinations) {
+      let emigrants = select_emigrants(
+        &island.population,
+        &island.fitness_values,
+        self.migration.size,
+        self.migration.emigrant_policy,
+        seed.map(|_| prng.gen::<u64>()),
+      )?;
+      for destination in destinations {
+        immigrants[destination].extend(emigrants.iter().cloned());
+      }
+    }
+    for (island, immigrants) in self.islands.iter_mut().zip(immigrants) {
+      insert_immigrants(
+        &mut island.population,
+        &mut island.fitness_values,
+        immigrants,
+        self.migration.immigrant_policy,
+        seed.map(|_| prng.gen::<u64>()),
+      )?;
+    }
+    Ok(())
+  }
+
+  /// Evolves every island for one generation in parallel, then migrates if the migration interval has elapsed.
+  pub fn step(&mut self, seed: Option<u64>) -> Result<(), &'static str> {
+    let mut prng = match seed {
+      Some(val) => StdRng::seed_from_u64(val),
+      None => StdRng::from_entropy()
+    };
+    let generation = self.generation;
+    let seeds = self.islands.iter().map(|_| seed.map(|_| prng.gen::<u64>())).collect::<Vec<Option<u64>>>();
+    thread::scope(|scope| {
+      for (island, seed) in self.islands.iter_mut().zip(seeds) {
+        scope.spawn(move || island.evolve(generation, seed));
+      }
+    });
+    self.generation += 1;
+    if self.generation % self.migration.interval == 0 {
+      self.migrate(seed.map(|_| prng.gen::<u64>()))?;
+    }
+    Ok(())
+  }
+
+  /// Runs `generations` steps of the island model.
+  pub fn run(&mut self, generations: usize, seed: Option<u64>) -> Result<(), &'static str> {
+    let mut prng = match seed {
+      Some(val) => StdRng::seed_from_u64(val),
+      None => StdRng::from_entropy()
+    };
+    for _ in 0..generations {
+      self.step(seed.map(|_| prng.gen::<u64>()))?;
+    }
+    Ok(())
+  }
+}
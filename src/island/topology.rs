@@ -0,0 +1,75 @@
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+
+/**
+## Description
+`Topology` defines along which connections the islands of an island model send their emigrants to each other.
+
+- `Ring`: every island sends emigrants to the next one, the last island sending to the first.
+- `FullyConnected`: every island sends emigrants to all the other islands.
+- `Star`: the first island is the hub, which sends emigrants to all the other islands and receives emigrants from them.
+- `Random(count)`: every island sends emigrants to `count` distinct other islands, chosen anew at every migration.
+
+## Example
+```rust
+  use genx::island::Topology;
+
+  assert_eq!(Topology::Ring.destinations(3, None), vec![vec![1], vec![2], vec![0]]);
+  assert_eq!(Topology::Star.destinations(3, None), vec![vec![1, 2], vec![0], vec![0]]);
+  assert_eq!(Topology::Random(2).destinations(4, Some(42)).len(), 4);
+```
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Topology {
+  Ring,
+  FullyConnected,
+  Star,
+  Random(usize),
+}
+
+impl Topology {
+  /**
+  Islands every one of `num_islands` islands sends its emigrants to, an island never sending emigrants to itself.
+
+  The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results of `Random`.
+  */
+  pub fn destinations(&self, num_islands: usize, seed: Option<u64>) -> Vec<Vec<usize>> {
+    let others = |source: usize| (0..num_islands).filter(move |&idx| idx != source);
+    match *self {
+      Topology::Ring => (0..num_islands)
+        .map(|source| others(source).take(1).map(|_| (source + 1) % num_islands).collect())
+        .collect(),
+      Topology::FullyConnected => (0..num_islands).map(|source| others(source).collect()).collect(),
+      Topology::Star => (0..num_islands)
+        .map(|source| if source == 0 { others(source).collect() } else { vec![0] })
+        .collect(),
+      Topology::Random(count) => {
+        let mut prng = match seed {
+          Some(val) => StdRng::seed_from_u64(val),
+          None => StdRng::from_entropy()
+        };
+        (0..num_islands)
+          .map(|source| {
+            let candidates = others(source).collect::<Vec<usize>>();
+            let mut chosen = sample(&mut prng, candidates.len(), count.min(candidates.len()))
+              .iter()
+              .map(|idx| candidates[idx])
+              .collect::<Vec<usize>>();
+            chosen.sort_unstable();
+            chosen
+          })
+          .collect()
+      }
+    }
+  }
+
+  /// Islands every one of `num_islands` islands receives immigrants from, the inverse of `destinations`.
+  pub fn sources(&self, num_islands: usize, seed: Option<u64>) -> Vec<Vec<usize>> {
+    let mut sources = vec![Vec::new(); num_islands];
+    for (source, destinations) in self.destinations(num_islands, seed).into_iter().enumerate() {
+      for destination in destinations {
+        sources[destination].push(source);
+      }
+    }
+    sources
+  }
+}
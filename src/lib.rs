@@ -65,6 +65,14 @@
 //! - **[`mutation`]**
 //! - **[`crossover`]**
 //! - **[`scaling`]**
+//! - **[`replacement`]**
+//!
+//! Individuals are mostly plain vectors of genes, the [`genome`] module
+//! provides packed representations for when those are too costly. The [`encoding`]
+//! module maps real and integer variables onto binary encoded individuals.
+//! The [`speciation`] module divides the population into species of similar individuals,
+//! and the [`quality_diversity`] module searches for diverse sets of high performing solutions.
+//! The [`island`] module evolves several subpopulations in parallel which exchange individuals.
 //!
 //! This crate provides multiple implementations for each one of those operators.
 //! So one can experiment with combining the different implementations to compose
@@ -76,6 +84,11 @@
 //! [dependencies]
 //! genx = "0.4.0"
 //! ```
+//! `#[derive(Genome)]` for struct shaped solutions is available behind the `derive` feature:
+//! ```toml
+//! [dependencies]
+//! genx = { version = "0.4.0", features = ["derive"] }
+//! ```
 //! If you are not using Rust 2018 edition add this to your crate root:
 //! ```rust
 //! extern crate genx;
@@ -98,3 +111,15 @@ pub mod selection;
 pub mod crossover;
 
 pub mod scaling;
+
+pub mod replacement;
+
+pub mod speciation;
+
+pub mod quality_diversity;
+
+pub mod island;
+
+pub mod genome;
+
+pub mod encoding;
@@ -0,0 +1,69 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::genome::{CompositeGenome, Segment, SegmentMutation, SegmentOperator};
+
+/**
+## Description
+Composite mutation is a mutation for individuals made of several typed segments.
+Every segment is mutated with the mutation of the matching `SegmentOperator` with probability
+`mutation_rate`. Real segments are mutated gene by gene, each gene with probability `mutation_rate`.
+
+### Note
+- `operators` should contain one operator for every segment, with the same kind as the segment, otherwise the function panics.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Example
+```rust
+  use genx::{
+    crossover::uniform_crossover,
+    genome::{CompositeGenome, Segment, SegmentCrossover, SegmentMutation, SegmentOperator},
+    mutation::{composite_mutation, flipping_mutation},
+  };
+
+  let operators = vec![SegmentOperator::new(
+    SegmentCrossover::binary(|parent1, parent2, seed| uniform_crossover(parent1, parent2, 0.5, seed)),
+    0.7,
+    SegmentMutation::binary(|individual, seed| flipping_mutation(individual, 0.5, seed).unwrap()),
+    1.0,
+  )];
+  let mut individual = CompositeGenome::new(vec![Segment::Binary(vec![false; 16])]);
+  let original_individual = individual.clone();
+  composite_mutation(&mut individual, &operators, Some(42));
+  assert_ne!(original_individual, individual);
+```
+*/
+pub fn composite_mutation(individual: &mut CompositeGenome, operators: &Vec<SegmentOperator>, seed: Option<u64>) {
+  if individual.segments.len() != operators.len() {
+    panic!("Individual and operators must have the same number of segments");
+  }
+  if individual.segments.iter().zip(operators.iter()).any(|(segment, operator)| !operator.mutation.matches(segment)) {
+    panic!("Segment kinds must match their operators");
+  }
+
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+
+  for (segment, operator) in individual.segments.iter_mut().zip(operators.iter()) {
+    if let (SegmentMutation::Real(mutation), Segment::Real(genes)) = (&operator.mutation, &mut *segment) {
+      for gene in genes.iter_mut() {
+        if prng.gen_range(0.0..1.0) < operator.mutation_rate {
+          *gene = mutation(*gene, seed.map(|_| prng.gen::<u64>()));
+        }
+      }
+      continue;
+    }
+
+    if prng.gen_range(0.0..1.0) >= operator.mutation_rate {
+      continue;
+    }
+    let next_seed = seed.map(|_| prng.gen::<u64>());
+    match (&operator.mutation, segment) {
+      (SegmentMutation::Binary(mutation), Segment::Binary(genes)) => mutation(genes, next_seed),
+      (SegmentMutation::Integer(mutation), Segment::Integer(genes)) => mutation(genes, next_seed),
+      (SegmentMutation::Permutation(mutation), Segment::Permutation(genes)) => mutation(genes, next_seed),
+      _ => unreachable!(),
+    }
+  }
+}
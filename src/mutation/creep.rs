@@ -0,0 +1,64 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::check_bounds;
+
+/**
+## Description
+Creep mutation is a mutation only for integer encoded individuals.
+Given the `mutation_probability` and `individual` it iterates through the integer vector of `individual`
+and generates a random number between `0.0` and `1.0`. If the number is less than `mutation_probability`
+then the gene _creeps_ by a small non zero step picked uniformly from `-max_step..=max_step`.
+The resulting value is clamped to the inclusive `(lower, upper)` bounds of that gene.
+
+### Note
+- `bounds` should contain one `(lower, upper)` pair for every gene of the `individual`.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+The return value is a `Result<(), &'static str>` which will return error if the `mutation_probability` does not
+lie between `0` and `1`, `max_step` is not positive or `bounds` are invalid.
+
+## Example
+```rust
+  use genx::mutation::creep_mutation;
+  let mut individual = vec![3, 7, 1, 9, 4, 0, 5, 2];
+  let bounds = vec![(0, 9); individual.len()];
+  let original_individual = individual.clone();
+  match creep_mutation(&mut individual, &bounds, 2, 0.5, Some(42)) {
+    Ok(_) => (),
+    Err(error) => panic!("{:?}", error)
+  };
+  assert_ne!(original_individual, individual);
+```
+*/
+pub fn creep_mutation(
+    individual: &mut Vec<i32>,
+    bounds: &Vec<(i32, i32)>,
+    max_step: i32,
+    mutation_probability: f32,
+    seed: Option<u64>,
+) -> Result<(), &'static str> {
+    if !(0.0..=1.0).contains(&mutation_probability) {
+        return Err("mutation_probability should lie between 0.0 and 1.0 inclusive");
+    }
+    if max_step <= 0 {
+        return Err("max_step should be greater than 0");
+    }
+    check_bounds(individual, bounds)?;
+
+    let mut prng = match seed {
+        Some(val) => StdRng::seed_from_u64(val),
+        None => StdRng::from_entropy(),
+    };
+    for (val, &(lower, upper)) in individual.iter_mut().zip(bounds.iter()) {
+        if prng.gen_range(0.0..1.0) < mutation_probability {
+            let mut step = prng.gen_range(1..=max_step);
+            if prng.gen::<bool>() {
+                step = -step;
+            }
+            *val = val.saturating_add(step).clamp(lower, upper);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/**
+## Description
+Deletion mutation is a mutation for variable length individuals.
+Given the `individual` it removes the gene at a randomly generated index, shrinking the individual by one.
+Individuals that hold `min_length` genes or fewer are left unchanged.
+
+_Note: The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results._
+
+## Return
+The return value is the removed gene wrapped in an `Option<T>`, `None` if the individual was left unchanged.
+
+## Example
+```rust
+  use genx::mutation::deletion_mutation;
+  let mut individual = vec![3, 1, 4, 1, 5];
+  let removed = deletion_mutation(&mut individual, 2, Some(42));
+  assert_eq!(individual.len(), 4);
+  assert!(removed.is_some());
+```
+*/
+pub fn deletion_mutation<T>(individual: &mut Vec<T>, min_length: usize, seed: Option<u64>) -> Option<T> {
+  if individual.len() <= min_length || individual.is_empty() {
+    return None;
+  }
+
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+  let idx = prng.gen_range(0..individual.len());
+  Some(individual.remove(idx))
+}
@@ -0,0 +1,45 @@
+use std::mem::swap;
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/**
+## Description
+Duplication mutation is a mutation for variable length individuals.
+Given the `individual` it randomly generates two indices and inserts a copy of the genes
+between those indices right after the original ones. The copied segment is shortened if needed
+so the individual never grows beyond `max_length` genes.
+
+_Note: The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results._
+
+## Example
+```rust
+  use genx::mutation::duplication_mutation;
+  let mut individual = vec![3, 1, 4, 1, 5];
+  duplication_mutation(&mut individual, 8, Some(42));
+  assert!(individual.len() > 5 && individual.len() <= 8);
+```
+*/
+pub fn duplication_mutation<T>(individual: &mut Vec<T>, max_length: usize, seed: Option<u64>)
+where
+    T: Clone,
+{
+  if individual.is_empty() || individual.len() >= max_length {
+    return;
+  }
+
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+
+  let length_of_individual = individual.len();
+  let mut idx1 = prng.gen_range(0..length_of_individual);
+  let mut idx2 = prng.gen_range(0..length_of_individual);
+  if idx2 < idx1 {
+    swap(&mut idx1, &mut idx2);
+  }
+
+  let idx2 = idx2.min(idx1 + max_length - length_of_individual - 1);
+  let segment = individual[idx1..=idx2].to_vec();
+  individual.splice(idx2 + 1..idx2 + 1, segment);
+}
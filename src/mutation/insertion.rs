@@ -0,0 +1,31 @@
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/**
+## Description
+Insertion mutation is a mutation for variable length individuals.
+Given the `individual` and a new `gene` it inserts the gene at a randomly generated index,
+growing the individual by one. Individuals that already hold `max_length` genes are left unchanged.
+
+_Note: The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results._
+
+## Example
+```rust
+  use genx::mutation::insertion_mutation;
+  let mut individual = vec![3, 1, 4, 1, 5];
+  insertion_mutation(&mut individual, 9, 10, Some(42));
+  assert_eq!(individual.len(), 6);
+  assert!(individual.contains(&9));
+```
+*/
+pub fn insertion_mutation<T>(individual: &mut Vec<T>, gene: T, max_length: usize, seed: Option<u64>) {
+  if individual.len() >= max_length {
+    return;
+  }
+
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+  let idx = prng.gen_range(0..=individual.len());
+  individual.insert(idx, gene);
+}
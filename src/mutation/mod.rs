@@ -17,11 +17,22 @@
 //! the boolean vector of individual to mutate. Hence they
 //! change the actual boolean Vector.
 //!
+//! Binary encoded individuals packed into a [`crate::genome::BitGenome`]
+//! can use `packed_flipping`, which only pays for the bits it flips.
+//!
 //! Only those functions where, there is a need to constrain
 //! range of values that can be provided as argument will return
 //! a `Result<(), &'static str>`. The returned value should be checked
 //! in case `Err` was returned because of invalid argument values.
 //!
+//! Available mutation schema for integer encoded
+//! individuals are:
+//! * `creep`
+//! * `random_resetting`
+//!
+//! Both take in a vector of inclusive `(lower, upper)` bounds,
+//! one for every gene, and mutated values never leave those bounds.
+//!
 //! Available mutation schema for real value encoded
 //! individuals are:
 //! * `random`
@@ -30,23 +41,68 @@
 //! All the mutation functions for real value encoded schema
 //! take in the floating point value of individual and return
 //! the mutated value.
+//!
+//! Available mutation schema for variable length
+//! individuals are:
+//! * `insertion`
+//! * `deletion`
+//! * `duplication`
+//!
+//! They change the length of the individual but never take it
+//! beyond the provided minimum or maximum length.
+//!
+//! Individuals made of several typed segments, represented by a
+//! [`crate::genome::CompositeGenome`], are mutated segment by segment
+//! with `composite`, using any of the above mutations per segment.
+
+pub mod composite;
+
+pub mod creep;
+
+pub mod deletion;
+
+pub mod duplication;
 
 pub mod flipping;
 
+pub mod insertion;
+
 pub mod inversion;
 
+pub mod packed_flipping;
+
 pub mod polynomial;
 
 pub mod random;
 
+pub mod random_resetting;
+
 pub mod scramble;
 
 pub mod swap;
 
 // Re-exports
+pub use self::composite::composite_mutation;
+pub use self::creep::creep_mutation;
+pub use self::deletion::deletion_mutation;
+pub use self::duplication::duplication_mutation;
 pub use self::flipping::flipping_mutation;
+pub use self::insertion::insertion_mutation;
 pub use self::inversion::inversion_mutation;
+pub use self::packed_flipping::packed_flipping_mutation;
 pub use self::polynomial::polynomial_mutation;
 pub use self::random::random_mutation;
+pub use self::random_resetting::random_resetting_mutation;
 pub use self::scramble::scramble_mutation;
 pub use self::swap::swap_mutation;
+
+pub(crate) fn check_bounds(individual: &[i32], bounds: &[(i32, i32)]) -> Result<(), &'static str> {
+  if individual.len() != bounds.len() {
+    return Err("bounds should contain a (lower, upper) pair for every gene");
+  }
+  if bounds.iter().any(|&(lower, upper)| lower > upper) {
+    return Err("lower bound should not be greater than upper bound");
+  }
+
+  Ok(())
+}
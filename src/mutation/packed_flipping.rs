@@ -0,0 +1,49 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::genome::{bit::geometric_skip, BitGenome};
+
+/**
+## Description
+Packed flipping mutation is the `flipping_mutation` for individuals encoded as a `BitGenome`.
+Instead of generating a random number for every bit it samples the distance to the next flipped
+bit from a geometric distribution, so the cost grows with the number of flipped bits rather than
+the length of the `individual`.
+
+_Note: The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results._
+
+## Return
+The return value is a `Result<(), &'static str>` which will return error only if the `mutation_probability` is greater than `1`
+## Example
+```rust
+  use genx::{genome::BitGenome, mutation::packed_flipping_mutation};
+  let mut individual = BitGenome::random(1000, Some(42));
+  let original_individual = individual.clone();
+  match packed_flipping_mutation(&mut individual, 0.05, Some(42)) {
+    Ok(_) => (),
+    Err(error) => panic!("{:?}", error)
+  };
+  assert_ne!(original_individual, individual);
+```
+*/
+pub fn packed_flipping_mutation(individual: &mut BitGenome, mutation_probability: f32, seed: Option<u64>) -> Result<(), &'static str> {
+  if !(0.0..=1.0).contains(&mutation_probability) {
+    return Err("mutation_probability should lie between 0.0 and 1.0 inclusive");
+  }
+  if mutation_probability == 0.0 {
+    return Ok(());
+  }
+
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+  let n = individual.len();
+  let words = individual.words_mut();
+  let mut idx = geometric_skip(&mut prng, mutation_probability as f64);
+  while idx < n {
+    words[idx / 64] ^= 1u64 << (idx % 64);
+    idx = idx.saturating_add(1).saturating_add(geometric_skip(&mut prng, mutation_probability as f64));
+  }
+
+  Ok(())
+}
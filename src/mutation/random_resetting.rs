@@ -0,0 +1,55 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::check_bounds;
+
+/**
+## Description
+Random resetting mutation is a mutation only for integer encoded individuals.
+Given the `mutation_probability` and `individual` it iterates through the integer vector of `individual`
+and generates a random number between `0.0` and `1.0`. If the number is less than `mutation_probability`
+then the gene is _reset_ to a value picked uniformly from the inclusive `(lower, upper)` bounds of that gene.
+
+### Note
+- `bounds` should contain one `(lower, upper)` pair for every gene of the `individual`.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+The return value is a `Result<(), &'static str>` which will return error if the `mutation_probability` does not
+lie between `0` and `1` or `bounds` are invalid.
+
+## Example
+```rust
+  use genx::mutation::random_resetting_mutation;
+  let mut individual = vec![3, 7, 1, 9, 4, 0, 5, 2];
+  let bounds = vec![(0, 9); individual.len()];
+  let original_individual = individual.clone();
+  match random_resetting_mutation(&mut individual, &bounds, 0.5, Some(42)) {
+    Ok(_) => (),
+    Err(error) => panic!("{:?}", error)
+  };
+  assert_ne!(original_individual, individual);
+```
+*/
+pub fn random_resetting_mutation(
+    individual: &mut Vec<i32>,
+    bounds: &Vec<(i32, i32)>,
+    mutation_probability: f32,
+    seed: Option<u64>,
+) -> Result<(), &'static str> {
+    if !(0.0..=1.0).contains(&mutation_probability) {
+        return Err("mutation_probability should lie between 0.0 and 1.0 inclusive");
+    }
+    check_bounds(individual, bounds)?;
+
+    let mut prng = match seed {
+        Some(val) => StdRng::seed_from_u64(val),
+        None => StdRng::from_entropy(),
+    };
+    for (val, &(lower, upper)) in individual.iter_mut().zip(bounds.iter()) {
+        if prng.gen_range(0.0..1.0) < mutation_probability {
+            *val = prng.gen_range(lower..=upper);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// An individual stored in an archive, along with its fitness value and behaviour descriptor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Elite<T> {
+  pub individual: T,
+  pub fitness: f32,
+  pub descriptor: Vec<f32>,
+}
+
+/**
+## Description
+`Archive` is implemented by the quality diversity archives, which divide the behaviour space into cells and keep the
+fittest individual found so far, the elite, of every cell.
+
+Individuals are described by a behaviour `descriptor`, a vector of floating point values such as the weight and the
+number of items of a knapsack, which decides the cell they compete for. Higher fitness values are better.
+*/
+pub trait Archive<T> {
+  /// Total number of cells of the archive.
+  fn num_cells(&self) -> usize;
+
+  /// Cell the `descriptor` belongs to, or `None` if it has the wrong number of dimensions.
+  fn cell_of(&self, descriptor: &Vec<f32>) -> Option<usize>;
+
+  /// Elite of the `cell`, if the cell has been filled.
+  fn get(&self, cell: usize) -> Option<&Elite<T>>;
+
+  /// Elites of the filled cells in order of their cells.
+  fn elites(&self) -> Vec<&Elite<T>>;
+
+  /// Stores the individual if its cell is empty or it is fitter than the elite of its cell, returning whether it was stored.
+  /// Individuals with a non finite `fitness` are never stored.
+  fn insert(&mut self, individual: T, fitness: f32, descriptor: Vec<f32>) -> bool;
+
+  /// Number of filled cells.
+  fn len(&self) -> usize {
+    self.elites().len()
+  }
+
+  fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Fraction of the cells which have been filled.
+  fn coverage(&self) -> f32 {
+    if self.num_cells() == 0 {
+      return 0.0;
+    }
+    self.len() as f32 / self.num_cells() as f32
+  }
+
+  /// Sum of the fitness values of the elites minus `offset` each, `offset` being the lowest possible fitness value.
+  fn qd_score(&self, offset: f32) -> f32 {
+    self.elites().iter().map(|elite| elite.fitness - offset).sum()
+  }
+
+  /// Fittest elite of the archive.
+  fn best(&self) -> Option<&Elite<T>> {
+    self.elites().into_iter().fold(None, |best: Option<&Elite<T>>, elite| match best {
+      Some(best) if best.fitness >= elite.fitness => Some(best),
+      _ => Some(elite),
+    })
+  }
+}
+
+/**
+## Description
+Random Elite Selection picks `num_parents` elites of the archive uniformly at random, with replacement, which is how
+MAP-Elites selects the parents of the next batch of offsprings.
+
+### Note
+
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Vec<&Elite<T>>` of the selected elites, which is empty if the archive is empty.
+
+## Example
+```rust
+  use genx::quality_diversity::{random_elite_selection, Archive, GridArchive};
+  let mut archive = GridArchive::new(vec![(0.0, 1.0)], vec![4]).unwrap();
+  archive.insert(vec![true, false], 1.0, vec![0.1]);
+  archive.insert(vec![false, true], 2.0, vec![0.9]);
+
+  let parents = random_elite_selection(&archive, 3, None);
+  assert_eq!(parents.len(), 3);
+```
+*/
+pub fn random_elite_selection<T, A: Archive<T>>(archive: &A, num_parents: usize, seed: Option<u64>) -> Vec<&Elite<T>> {
+  let elites = archive.elites();
+  if elites.is_empty() {
+    return Vec::new();
+  }
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+  (0..num_parents).map(|_| elites[prng.gen_range(0..elites.len())]).collect()
+}
@@ -0,0 +1,137 @@
+use std::cmp::Ordering;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{Archive, Elite};
+
+/**
+## Description
+`CvtArchive` is the archive of CVT-MAP-Elites, dividing the behaviour space into the Voronoi cells of `num_centroids`
+centroids, each descriptor belonging to the cell of its nearest centroid.
+
+The centroids are computed by a centroidal Voronoi tessellation (CVT) of the space between the `bounds`, running
+`iterations` of Lloyd's k-means algorithm over `num_samples` uniformly sampled points. Unlike a grid, the number of
+cells doesn't grow exponentially with the number of dimensions of the behaviour space.
+
+### Note
+
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic centroids.
+
+## Example
+```rust
+  use genx::quality_diversity::{Archive, CvtArchive};
+  let mut archive = CvtArchive::new(vec![(0.0, 1.0); 6], 32, 1000, 10, Some(42)).unwrap();
+
+  assert!(archive.insert(vec![1, 2, 3], 1.0, vec![0.5; 6]));
+  assert_eq!(archive.num_cells(), 32);
+```
+*/
+#[derive(Clone, Debug)]
+pub struct CvtArchive<T> {
+  centroids: Vec<Vec<f32>>,
+  cells: Vec<Option<Elite<T>>>,
+}
+
+impl<T> CvtArchive<T> {
+  /// Returns error if a lower bound isn't below its upper bound, or if there are less samples than centroids.
+  pub fn new(bounds: Vec<(f32, f32)>, num_centroids: usize, num_samples: usize, iterations: usize, seed: Option<u64>) -> Result<Self, &'static str> {
+    if bounds.iter().any(|&(lower, upper)| lower.partial_cmp(&upper) != Some(Ordering::Less)) {
+      return Err("lower bounds should be below upper bounds");
+    }
+    if num_samples < num_centroids {
+      return Err("there should be at least as many samples as centroids");
+    }
+    let mut prng = match seed {
+      Some(val) => StdRng::seed_from_u64(val),
+      None => StdRng::from_entropy()
+    };
+    let samples = (0..num_samples)
+      .map(|_| bounds.iter().map(|&(lower, upper)| prng.gen_range(lower..upper)).collect())
+      .collect::<Vec<Vec<f32>>>();
+
+    let mut centroids = samples[..num_centroids].to_vec();
+    for _ in 0..iterations {
+      let mut sums = vec![vec![0.0; bounds.len()]; num_centroids];
+      let mut counts = vec![0usize; num_centroids];
+      for sample in samples.iter() {
+        let nearest = nearest(&centroids, sample);
+        counts[nearest] += 1;
+        for (sum, &x) in sums[nearest].iter_mut().zip(sample.iter()) {
+          *sum += x;
+        }
+      }
+      for ((centroid, sum), &count) in centroids.iter_mut().zip(sums).zip(counts.iter()) {
+        // a centroid attracting no sample stays in place
+        if count > 0 {
+          *centroid = sum.iter().map(|&x| x / count as f32).collect();
+        }
+      }
+    }
+    CvtArchive::with_centroids(centroids)
+  }
+
+  /// Uses the given `centroids`, returning error if they are empty or have different lengths.
+  pub fn with_centroids(centroids: Vec<Vec<f32>>) -> Result<Self, &'static str> {
+    if centroids.is_empty() {
+      return Err("there should be at least one centroid");
+    }
+    if centroids.iter().any(|centroid| centroid.len() != centroids[0].len()) {
+      return Err("centroids should have the same length");
+    }
+    let cells = (0..centroids.len()).map(|_| None).collect();
+    Ok(CvtArchive { centroids, cells })
+  }
+
+  pub fn centroids(&self) -> &Vec<Vec<f32>> {
+    &self.centroids
+  }
+}
+
+fn nearest(centroids: &[Vec<f32>], point: &[f32]) -> usize {
+  let squared_distance = |centroid: &Vec<f32>| centroid.iter().zip(point.iter()).map(|(a, b)| (a - b).powi(2)).sum::<f32>();
+  let mut best = 0;
+  let mut best_distance = f32::INFINITY;
+  for (idx, centroid) in centroids.iter().enumerate() {
+    let d = squared_distance(centroid);
+    if d < best_distance {
+      best = idx;
+      best_distance = d;
+    }
+  }
+  best
+}
+
+impl<T> Archive<T> for CvtArchive<T> {
+  fn num_cells(&self) -> usize {
+    self.cells.len()
+  }
+
+  fn cell_of(&self, descriptor: &Vec<f32>) -> Option<usize> {
+    if descriptor.len() != self.centroids[0].len() || descriptor.iter().any(|x| x.is_nan()) {
+      return None;
+    }
+    Some(nearest(&self.centroids, descriptor))
+  }
+
+  fn get(&self, cell: usize) -> Option<&Elite<T>> {
+    self.cells.get(cell).and_then(|elite| elite.as_ref())
+  }
+
+  fn elites(&self) -> Vec<&Elite<T>> {
+    self.cells.iter().flatten().collect()
+  }
+
+  fn insert(&mut self, individual: T, fitness: f32, descriptor: Vec<f32>) -> bool {
+    if !fitness.is_finite() {
+      return false;
+    }
+    let cell = match self.cell_of(&descriptor) {
+      Some(cell) => cell,
+      None => return false,
+    };
+    if self.cells[cell].as_ref().is_some_and(|elite| elite.fitness >= fitness) {
+      return false;
+    }
+    self.cells[cell] = Some(Elite { individual, fitness, descriptor });
+    true
+  }
+}
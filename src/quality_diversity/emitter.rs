@@ -0,0 +1,89 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{random_elite_selection, Archive};
+
+/**
+## Description
+`MutationEmitter` generates new candidate solutions for an archive by applying a `mutation` to copies of randomly
+selected elites, so that any mutation operator of `genx::mutation` can drive a MAP-Elites search through a closure
+taking the individual to mutate and an optional seed.
+
+Each call to `emit` returns a batch of `batch_size` offsprings, which should then be evaluated and inserted into the
+archive, as done by `step`.
+
+## Example
+```rust
+  use genx::{mutation::flipping_mutation, quality_diversity::{Archive, GridArchive, MutationEmitter}};
+  let weights = vec![9, 13, 15, 50, 15, 68];
+  // fitness is the number of items, described by the total weight
+  let evaluate = |individual: &Vec<bool>| {
+    let weight = individual.iter().zip(weights.iter()).filter(|(&x, _)| x).map(|(_, &w)| w).sum::<i32>();
+    (individual.iter().filter(|&&x| x).count() as f32, vec![weight as f32])
+  };
+
+  let mut archive = GridArchive::new(vec![(0.0, 170.0)], vec![17]).unwrap();
+  let individual = vec![false; 6];
+  let (fitness, descriptor) = evaluate(&individual);
+  archive.insert(individual, fitness, descriptor);
+
+  let emitter = MutationEmitter::new(|x: &mut Vec<bool>, seed| { flipping_mutation(x, 0.3, seed).unwrap(); }, 8);
+  for _ in 0..50 {
+    emitter.step(&mut archive, &evaluate, None);
+  }
+  assert!(archive.len() > 1);
+```
+*/
+pub struct MutationEmitter<M> {
+  pub mutation: M,
+  pub batch_size: usize,
+}
+
+impl<M> MutationEmitter<M> {
+  pub fn new(mutation: M, batch_size: usize) -> Self {
+    MutationEmitter { mutation, batch_size }
+  }
+
+  /// Mutated copies of `batch_size` random elites, empty if the archive is empty.
+  pub fn emit<T, A>(&self, archive: &A, seed: Option<u64>) -> Vec<T>
+  where
+    T: Clone,
+    A: Archive<T>,
+    M: Fn(&mut T, Option<u64>),
+  {
+    let mut prng = match seed {
+      Some(val) => StdRng::seed_from_u64(val),
+      None => StdRng::from_entropy()
+    };
+    let parents = random_elite_selection(archive, self.batch_size, seed.map(|_| prng.gen::<u64>()));
+    parents
+      .into_iter()
+      .map(|elite| {
+        let mut offspring = elite.individual.clone();
+        (self.mutation)(&mut offspring, seed.map(|_| prng.gen::<u64>()));
+        offspring
+      })
+      .collect()
+  }
+
+  /**
+  Emits a batch of offsprings, evaluates each one into its fitness value and behaviour descriptor using `evaluate`,
+  and inserts them into the archive, returning the number of offsprings stored.
+  */
+  pub fn step<T, A, E>(&self, archive: &mut A, evaluate: E, seed: Option<u64>) -> usize
+  where
+    T: Clone,
+    A: Archive<T>,
+    M: Fn(&mut T, Option<u64>),
+    E: Fn(&T) -> (f32, Vec<f32>),
+  {
+    let offsprings = self.emit(archive, seed);
+    let mut stored = 0;
+    for offspring in offsprings {
+      let (fitness, descriptor) = evaluate(&offspring);
+      if archive.insert(offspring, fitness, descriptor) {
+        stored += 1;
+      }
+    }
+    stored
+  }
+}
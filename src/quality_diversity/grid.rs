@@ -0,0 +1,105 @@
+use std::cmp::Ordering;
+
+use super::{Archive, Elite};
+
+/**
+## Description
+`GridArchive` is the archive of MAP-Elites, dividing every dimension of the behaviour space between its `bounds` into
+`resolution` equal intervals, so that the archive has the product of the resolutions as cells.
+
+Descriptors outside the bounds are clamped into the border cells.
+
+## Example
+```rust
+  use genx::quality_diversity::{Archive, GridArchive};
+  // knapsacks described by their weight and number of items
+  let mut archive = GridArchive::new(vec![(0.0, 100.0), (0.0, 10.0)], vec![10, 10]).unwrap();
+
+  assert!(archive.insert(vec![true, false, true], 120.0, vec![35.0, 2.0]));
+  assert!(!archive.insert(vec![true, true, false], 90.0, vec![36.0, 2.0]));
+  assert_eq!(archive.coverage(), 0.01);
+```
+*/
+#[derive(Clone, Debug)]
+pub struct GridArchive<T> {
+  bounds: Vec<(f32, f32)>,
+  resolution: Vec<usize>,
+  cells: Vec<Option<Elite<T>>>,
+}
+
+impl<T> GridArchive<T> {
+  /// Returns error if `bounds` and `resolution` have different lengths, a resolution is zero or a lower bound isn't below its upper bound.
+  pub fn new(bounds: Vec<(f32, f32)>, resolution: Vec<usize>) -> Result<Self, &'static str> {
+    if bounds.len() != resolution.len() {
+      return Err("bounds and resolution should have the same length");
+    }
+    if resolution.contains(&0) {
+      return Err("resolution should be at least one in every dimension");
+    }
+    if bounds.iter().any(|&(lower, upper)| lower.partial_cmp(&upper) != Some(Ordering::Less)) {
+      return Err("lower bounds should be below upper bounds");
+    }
+    let num_cells = resolution.iter().product();
+    Ok(GridArchive { bounds, resolution, cells: (0..num_cells).map(|_| None).collect() })
+  }
+
+  pub fn bounds(&self) -> &Vec<(f32, f32)> {
+    &self.bounds
+  }
+
+  pub fn resolution(&self) -> &Vec<usize> {
+    &self.resolution
+  }
+
+  /// Position of the `cell` along every dimension of the grid.
+  pub fn coordinates(&self, cell: usize) -> Vec<usize> {
+    let mut cell = cell;
+    let mut coordinates = vec![0; self.resolution.len()];
+    for (dimension, &resolution) in self.resolution.iter().enumerate().rev() {
+      coordinates[dimension] = cell % resolution;
+      cell /= resolution;
+    }
+    coordinates
+  }
+}
+
+impl<T> Archive<T> for GridArchive<T> {
+  fn num_cells(&self) -> usize {
+    self.cells.len()
+  }
+
+  fn cell_of(&self, descriptor: &Vec<f32>) -> Option<usize> {
+    if descriptor.len() != self.bounds.len() || descriptor.iter().any(|x| x.is_nan()) {
+      return None;
+    }
+    let mut cell = 0;
+    for ((&value, &(lower, upper)), &resolution) in descriptor.iter().zip(self.bounds.iter()).zip(self.resolution.iter()) {
+      let position = ((value - lower) / (upper - lower) * resolution as f32).floor();
+      cell = cell * resolution + (position.max(0.0) as usize).min(resolution - 1);
+    }
+    Some(cell)
+  }
+
+  fn get(&self, cell: usize) -> Option<&Elite<T>> {
+    self.cells.get(cell).and_then(|elite| elite.as_ref())
+  }
+
+  fn elites(&self) -> Vec<&Elite<T>> {
+    self.cells.iter().flatten().collect()
+  }
+
+  fn insert(&mut self, individual: T, fitness: f32, descriptor: Vec<f32>) -> bool {
+    if !fitness.is_finite() {
+      return false;
+    }
+    let cell = match self.cell_of(&descriptor) {
+      Some(cell) => cell,
+      None => return false,
+    };
+    if self.cells[cell].as_ref().is_some_and(|elite| elite.fitness >= fitness) {
+      return false;
+    }
+    self.cells[cell] = Some(Elite { individual, fitness, descriptor });
+    true
+  }
+}
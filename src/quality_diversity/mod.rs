@@ -0,0 +1,40 @@
+//! The `quality_diversity` module provides implementation of the
+//! quality diversity algorithms, which search for a diverse set of
+//! high performing solutions across user defined behaviour descriptors
+//! instead of a single best solution.
+//!
+//! The provided types are organized in sub-modules:
+//! * `archive`: the [`Archive`] trait, elites and random elite selection
+//! * `grid`: the grid archive of MAP-Elites
+//! * `cvt`: the centroidal Voronoi tessellation archive of CVT-MAP-Elites
+//! * `emitter`: emitters generating offsprings using the mutation operators
+//! * `novelty`: novelty scores and the behaviour archive of novelty search
+//!
+//! A MAP-Elites search starts from an archive filled with random individuals,
+//! then repeatedly selects random elites, mutates them, and inserts the
+//! offsprings back into the archive, which keeps the fittest individual of
+//! every cell of the behaviour space. The coverage and QD-score of the
+//! archive measure the progress of the search.
+//!
+//! Novelty search instead replaces, or weighs, the fitness values by novelty
+//! scores, which can be passed to any function of the `selection` module.
+
+pub mod archive;
+
+pub mod cvt;
+
+pub mod emitter;
+
+pub mod grid;
+
+pub mod novelty;
+
+pub use self::archive::{random_elite_selection, Archive, Elite};
+
+pub use self::cvt::CvtArchive;
+
+pub use self::emitter::MutationEmitter;
+
+pub use self::grid::GridArchive;
+
+pub use self::novelty::{novelty_scores, weighted_novelty_fitness, ArchivePolicy, NoveltyArchive};
@@ -0,0 +1,224 @@
+use std::cmp::Ordering;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn euclidean(a: &[f32], b: &[f32]) -> f32 {
+  a.iter().zip(b.iter()).map(|(x, y)| (x - y).powi(2)).sum::<f32>().sqrt()
+}
+
+/**
+## Description
+Novelty Scores measure how different the behaviour of every individual is from the behaviours seen so far, as the
+average Euclidean distance to its `k` nearest neighbours among the other `behaviours` of the population and the
+behaviours of the `archive`.
+
+The scores can be used in place of fitness values by any selection function, rewarding individuals for exploring new
+behaviours instead of following a possibly deceptive fitness landscape.
+
+### Note
+
+- If there are less than `k` neighbours, the average is taken over all of them, and the score is `0.0` if there are none.
+
+## Return
+
+The return value is a `Vec<f32>` containing the novelty score of every behaviour.
+
+## Example
+```rust
+  use genx::{quality_diversity::novelty_scores, selection::tournament_selection};
+  let behaviours = vec![vec![0.0, 0.0], vec![0.1, 0.0], vec![5.0, 5.0]];
+  let archive = vec![vec![0.0, 0.1]];
+
+  let novelty = novelty_scores(&behaviours, &archive, 2);
+  assert!(novelty[2] > novelty[0]);
+  let result = tournament_selection(&novelty, 2, 2, None);
+```
+*/
+pub fn novelty_scores(behaviours: &Vec<Vec<f32>>, archive: &Vec<Vec<f32>>, k: usize) -> Vec<f32> {
+  behaviours
+    .iter()
+    .enumerate()
+    .map(|(i, behaviour)| {
+      let mut distances = behaviours
+        .iter()
+        .enumerate()
+        .filter(|&(j, _)| j != i)
+        .map(|(_, other)| other)
+        .chain(archive.iter())
+        .map(|other| euclidean(behaviour, other))
+        .collect::<Vec<f32>>();
+      let k = k.min(distances.len());
+      if k == 0 {
+        return 0.0;
+      }
+      distances.select_nth_unstable_by(k - 1, |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+      distances[..k].iter().sum::<f32>() / k as f32
+    })
+    .collect()
+}
+
+/// Decides which behaviours of a generation are added to a [`NoveltyArchive`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArchivePolicy {
+  /// Adds every behaviour whose novelty score exceeds the threshold.
+  Threshold(f32),
+  /// Adds every behaviour with the given probability, which should lie between `0.0` and `1.0`, regardless of its novelty score.
+  Random(f32),
+  /// Adds the given number of behaviours with the highest novelty scores.
+  MostNovel(usize),
+}
+
+/**
+## Description
+`NoveltyArchive` keeps the behaviours of past individuals for novelty search, so that individuals are rewarded for
+behaviours which differ from the ones seen in the previous generations too, and not only from their current population.
+
+Each generation `evaluate` maps the population to behaviours using a behaviour characterisation closure, scores them
+with `novelty_scores` against the population and the archive, then adds some of them to the archive according to the
+[`ArchivePolicy`]. When a `capacity` is given the oldest behaviours are forgotten first.
+
+### Note
+
+- `evaluate` can also take in an optional `seed` value of type `Option<u64>` for deterministic results of `ArchivePolicy::Random`.
+
+## Example
+```rust
+  use genx::{quality_diversity::{weighted_novelty_fitness, ArchivePolicy, NoveltyArchive}, selection::tournament_selection};
+  let mut archive = NoveltyArchive::new(3, ArchivePolicy::MostNovel(2), Some(100)).unwrap();
+  // a robot described by the position it ended at
+  let population = vec![(0.0, 1.0), (0.5, 1.2), (3.0, -2.0), (0.1, 0.9)];
+  let fitness_values = vec![1.0, 1.2, 0.1, 0.9];
+
+  let novelty = archive.evaluate(&population, |&(x, y): &(f32, f32)| vec![x, y], None);
+  assert_eq!(archive.len(), 2);
+  let scores = weighted_novelty_fitness(&novelty, &fitness_values, 0.8).unwrap();
+  let result = tournament_selection(&scores, 2, 2, None);
+```
+*/
+#[derive(Clone, Debug)]
+pub struct NoveltyArchive {
+  behaviours: Vec<Vec<f32>>,
+  k: usize,
+  policy: ArchivePolicy,
+  capacity: Option<usize>,
+}
+
+impl NoveltyArchive {
+  /// Returns error if the probability of `ArchivePolicy::Random` does not lie between `0.0` and `1.0`.
+  pub fn new(k: usize, policy: ArchivePolicy, capacity: Option<usize>) -> Result<Self, &'static str> {
+    if let ArchivePolicy::Random(probability) = policy {
+      if !(0.0..=1.0).contains(&probability) {
+        return Err("probability should lie between 0.0 and 1.0");
+      }
+    }
+    Ok(NoveltyArchive { behaviours: Vec::new(), k, policy, capacity })
+  }
+
+  /// Archived behaviours, oldest first.
+  pub fn behaviours(&self) -> &Vec<Vec<f32>> {
+    &self.behaviours
+  }
+
+  pub fn len(&self) -> usize {
+    self.behaviours.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.behaviours.is_empty()
+  }
+
+  /// Novelty scores of `behaviours` against themselves and the archive, without updating the archive.
+  pub fn novelty(&self, behaviours: &Vec<Vec<f32>>) -> Vec<f32> {
+    novelty_scores(behaviours, &self.behaviours, self.k)
+  }
+
+  /**
+  Adds the `behaviours` chosen by the policy given their `novelty` scores, returning the number of added behaviours,
+  which returns error if `behaviours` and `novelty` have different lengths.
+  */
+  pub fn update(&mut self, behaviours: Vec<Vec<f32>>, novelty: &Vec<f32>, seed: Option<u64>) -> Result<usize, &'static str> {
+    if behaviours.len() != novelty.len() {
+      return Err("behaviours and novelty should have the same length");
+    }
+    Ok(self.archive(behaviours, novelty, seed))
+  }
+
+  fn archive(&mut self, behaviours: Vec<Vec<f32>>, novelty: &[f32], seed: Option<u64>) -> usize {
+    let chosen = match self.policy {
+      ArchivePolicy::Threshold(threshold) => (0..behaviours.len()).filter(|&idx| novelty[idx] > threshold).collect::<Vec<usize>>(),
+      ArchivePolicy::Random(probability) => {
+        let mut prng = match seed {
+          Some(val) => StdRng::seed_from_u64(val),
+          None => StdRng::from_entropy()
+        };
+        (0..behaviours.len()).filter(|_| prng.gen::<f32>() < probability).collect()
+      }
+      ArchivePolicy::MostNovel(count) => {
+        let mut order = (0..behaviours.len()).collect::<Vec<usize>>();
+        order.sort_by(|&a, &b| novelty[b].partial_cmp(&novelty[a]).unwrap_or(Ordering::Equal));
+        order.truncate(count);
+        order.sort_unstable();
+        order
+      }
+    };
+
+    let added = chosen.len();
+    let mut behaviours = behaviours.into_iter().map(Some).collect::<Vec<Option<Vec<f32>>>>();
+    for idx in chosen {
+      self.behaviours.push(behaviours[idx].take().unwrap());
+    }
+    if let Some(capacity) = self.capacity {
+      let excess = self.behaviours.len().saturating_sub(capacity);
+      self.behaviours.drain(..excess);
+    }
+    added
+  }
+
+  /// Characterises the `population`, returns its novelty scores and updates the archive.
+  pub fn evaluate<T, C>(&mut self, population: &Vec<T>, characterize: C, seed: Option<u64>) -> Vec<f32>
+  where
+    C: Fn(&T) -> Vec<f32>,
+  {
+    let behaviours = population.iter().map(characterize).collect::<Vec<Vec<f32>>>();
+    let novelty = self.novelty(&behaviours);
+    self.archive(behaviours, &novelty, seed);
+    novelty
+  }
+}
+
+/**
+## Description
+Weighted Novelty Fitness combines novelty scores and fitness values into a single score, so that the search explores
+new behaviours while still favouring good solutions. Both are normalized to lie between `0.0` and `1.0` over the
+population, and combined as w n + (1 - w) f, w being the `novelty_weight`.
+
+## Return
+
+The return value is a `Result<Vec<f32>, &'static str>` containing the combined non negative scores, which will return error
+if `novelty` and `fitness_values` have different lengths, or `novelty_weight` does not lie between `0.0` and `1.0`.
+
+## Example
+```rust
+  use genx::quality_diversity::weighted_novelty_fitness;
+
+  let scores = weighted_novelty_fitness(&vec![0.0, 2.0, 4.0], &vec![10.0, 5.0, 0.0], 0.5).unwrap();
+  assert_eq!(scores, vec![0.5, 0.5, 0.5]);
+```
+*/
+pub fn weighted_novelty_fitness(novelty: &Vec<f32>, fitness_values: &Vec<f32>, novelty_weight: f32) -> Result<Vec<f32>, &'static str> {
+  if novelty.len() != fitness_values.len() {
+    return Err("novelty and fitness_values should have the same length");
+  }
+  if !(0.0..=1.0).contains(&novelty_weight) {
+    return Err("novelty_weight should lie between 0.0 and 1.0");
+  }
+  let normalize = |values: &Vec<f32>| {
+    let min = values.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = values.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    values.iter().map(|&x| if max > min { (x - min) / (max - min) } else { 0.0 }).collect::<Vec<f32>>()
+  };
+  Ok(normalize(novelty)
+    .iter()
+    .zip(normalize(fitness_values).iter())
+    .map(|(&n, &f)| novelty_weight * n + (1.0 - novelty_weight) * f)
+    .collect())
+}
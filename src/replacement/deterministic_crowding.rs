@@ -0,0 +1,61 @@
+use super::crowding_pairs;
+
+/**
+## Description
+Deterministic Crowding makes every offspring compete with its most similar parent. The two offsprings are paired with
+the two parents so that the total `distance` between the pairs is the lowest, and each offspring replaces its parent
+in the population if its fitness value is higher.
+
+As offsprings only replace similar individuals, niches around different optima survive in the population.
+
+### Note
+
+- `parents` are the indices in the population of the parents the `offsprings` were generated from, which should be
+  passed along with their fitness values.
+- When both parents are the same individual, only the fitter winning offspring replaces it.
+
+## Return
+
+The return value is a `Result<(bool, bool), &'static str>` telling whether each offspring entered the population, which
+will return error if `population` and `fitness_values` have different lengths or a parent index is out of bounds.
+
+## Example
+```rust
+  use genx::replacement::deterministic_crowding;
+  let mut population = vec![vec![false, false, false], vec![true, true, true]];
+  let mut fitness_values = vec![1.0, 2.0];
+  let hamming = |a: &Vec<bool>, b: &Vec<bool>| a.iter().zip(b.iter()).filter(|(x, y)| x != y).count() as f32;
+
+  let offsprings = ((vec![true, true, false], 3.0), (vec![false, false, true], 0.5));
+  let replaced = deterministic_crowding(&mut population, &mut fitness_values, (0, 1), offsprings, hamming).unwrap();
+  assert_eq!(replaced, (true, false));
+  assert_eq!(population[1], vec![true, true, false]);
+```
+*/
+pub fn deterministic_crowding<T, D>(
+  population: &mut Vec<T>,
+  fitness_values: &mut Vec<f32>,
+  parents: (usize, usize),
+  offsprings: ((T, f32), (T, f32)),
+  distance: D,
+) -> Result<(bool, bool), &'static str>
+where
+  D: Fn(&T, &T) -> f32,
+{
+  let pairs = crowding_pairs(population, fitness_values, parents, (&(offsprings.0).0, &(offsprings.1).0), &distance)?;
+  let mut offsprings = [Some(offsprings.0), Some(offsprings.1)];
+  let mut replaced = [false; 2];
+  for &(parent, offspring) in pairs.iter() {
+    if (offsprings[offspring].as_ref().unwrap().1) > fitness_values[parent] {
+      let (individual, fitness) = offsprings[offspring].take().unwrap();
+      population[parent] = individual;
+      fitness_values[parent] = fitness;
+      replaced[offspring] = true;
+    }
+  }
+  // the second offspring overwrote the first one competing for the same parent
+  if parents.0 == parents.1 && replaced[0] && replaced[1] {
+    replaced[pairs[0].1] = false;
+  }
+  Ok((replaced[0], replaced[1]))
+}
@@ -0,0 +1,42 @@
+use std::cmp::Ordering;
+
+use super::{check_population, replace_worst};
+
+/**
+## Description
+Generation Gap Replacement replaces a fraction `generation_gap` of the population each generation, interpolating between
+steady state algorithms replacing a single individual and generational ones replacing the whole population with a gap of `1.0`.
+
+The ⌈G μ⌉ fittest `offsprings`, G being the generation gap and μ the population size, replace the ⌈G μ⌉ worst individuals
+of the population, and the other offsprings are discarded.
+
+## Return
+
+The return value is a `Result<(), &'static str>` which will return error if `population` and `fitness_values` have
+different lengths, if `generation_gap` does not lie between `0.0` (exclusive) and `1.0` (inclusive), or if there are
+less offsprings than individuals to replace.
+
+## Example
+```rust
+  use genx::replacement::generation_gap_replacement;
+  let mut population = vec![vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+  let mut fitness_values = vec![1.0, 4.0, 0.5, 3.0];
+
+  let offsprings = vec![(vec![0, 0], 2.0), (vec![1, 1], 0.1), (vec![2, 2], 5.0)];
+  generation_gap_replacement(&mut population, &mut fitness_values, offsprings, 0.5).unwrap();
+  assert_eq!(population, vec![vec![0, 0], vec![3, 4], vec![2, 2], vec![7, 8]]);
+```
+*/
+pub fn generation_gap_replacement<T>(population: &mut Vec<T>, fitness_values: &mut Vec<f32>, mut offsprings: Vec<(T, f32)>, generation_gap: f32) -> Result<(), &'static str> {
+  check_population(population, fitness_values)?;
+  if !(generation_gap > 0.0 && generation_gap <= 1.0) {
+    return Err("generation_gap should lie between 0.0 exclusive and 1.0 inclusive");
+  }
+  let replaced = ((generation_gap * population.len() as f32).ceil() as usize).min(population.len());
+  if offsprings.len() < replaced {
+    return Err("there should be at least as many offsprings as individuals to replace");
+  }
+  offsprings.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+  offsprings.truncate(replaced);
+  replace_worst(population, fitness_values, offsprings)
+}
@@ -0,0 +1,105 @@
+//! The `replacement` module provides implementation of the
+//! functions to decide which individuals of the population are
+//! replaced by the newly generated offsprings, i.e. which survive
+//! into the next generation.
+//!
+//! The provided functions are organized in sub-modules
+//! named after the utilized replacement method:
+//! * `deterministic_crowding`
+//! * `probabilistic_crowding`
+//! * `restricted_tournament`
+//! * `mu_plus_lambda`
+//! * `mu_comma_lambda`
+//! * `replace_worst`
+//! * `replace_random`
+//! * `replace_oldest`
+//! * `generation_gap`
+//!
+//! All the functions take in mutable references to the population and
+//! to the fitness values of its individuals, which are kept in sync, along
+//! with the offsprings paired with their fitness values. Higher fitness
+//! values are better.
+//!
+//! Schemes inserting a batch of offsprings are also available as structs
+//! implementing the [`Replacement`] trait from `strategy`, so the scheme can
+//! be chosen at runtime.
+//!
+//! Crowding and restricted tournament replacements make offsprings compete
+//! with similar individuals only, according to a distance metric over
+//! individuals, which preserves multiple optima of multimodal problems.
+
+pub mod deterministic_crowding;
+
+pub mod generation_gap;
+
+pub mod mu_comma_lambda;
+
+pub mod mu_plus_lambda;
+
+pub mod probabilistic_crowding;
+
+pub mod replace_oldest;
+
+pub mod replace_random;
+
+pub mod replace_worst;
+
+pub mod restricted_tournament;
+
+pub mod strategy;
+
+pub use self::deterministic_crowding::deterministic_crowding;
+
+pub use self::generation_gap::generation_gap_replacement;
+
+pub use self::mu_comma_lambda::mu_comma_lambda_replacement;
+
+pub use self::mu_plus_lambda::mu_plus_lambda_replacement;
+
+pub use self::probabilistic_crowding::probabilistic_crowding;
+
+pub use self::replace_oldest::replace_oldest;
+
+pub use self::replace_random::replace_random;
+
+pub use self::replace_worst::replace_worst;
+
+pub use self::restricted_tournament::restricted_tournament_replacement;
+
+pub use self::strategy::{
+  GenerationGapReplacement, MuCommaLambdaReplacement, MuPlusLambdaReplacement, ReplaceOldest, ReplaceRandom, ReplaceWorst,
+  Replacement, RestrictedTournamentReplacement,
+};
+
+fn check_population<T>(population: &Vec<T>, fitness_values: &Vec<f32>) -> Result<(), &'static str> {
+  if population.len() != fitness_values.len() {
+    return Err("population and fitness_values should have the same length");
+  }
+  Ok(())
+}
+
+/// Validates the parent indices and pairs them with the offsprings such that the total distance
+/// between the pairs is the lowest, returning `(parent, offspring)` index pairs.
+fn crowding_pairs<T, D>(
+  population: &Vec<T>,
+  fitness_values: &Vec<f32>,
+  parents: (usize, usize),
+  offsprings: (&T, &T),
+  distance: &D,
+) -> Result<[(usize, usize); 2], &'static str>
+where
+  D: Fn(&T, &T) -> f32,
+{
+  check_population(population, fitness_values)?;
+  if parents.0 >= population.len() || parents.1 >= population.len() {
+    return Err("parent indices should be within the population");
+  }
+  let (parent1, parent2) = (&population[parents.0], &population[parents.1]);
+  let straight = distance(parent1, offsprings.0) + distance(parent2, offsprings.1);
+  let crossed = distance(parent1, offsprings.1) + distance(parent2, offsprings.0);
+  if crossed < straight {
+    Ok([(parents.0, 1), (parents.1, 0)])
+  } else {
+    Ok([(parents.0, 0), (parents.1, 1)])
+  }
+}
@@ -0,0 +1,46 @@
+use std::cmp::Ordering;
+
+use super::check_population;
+
+/**
+## Description
+(μ, λ) Replacement discards the μ individuals of the population and keeps the μ fittest of the λ `offsprings` as
+the next population. Every individual lives for a single generation, which helps escaping local optima and following
+moving optima, at the risk of losing the best individual found.
+
+### Note
+
+- The next population is sorted by non increasing fitness values.
+
+## Return
+
+The return value is a `Result<(), &'static str>` which will return error if `population` and `fitness_values` have
+different lengths, or if there are less offsprings than individuals in the population.
+
+## Example
+```rust
+  use genx::replacement::mu_comma_lambda_replacement;
+  let mut population = vec![vec![1, 2], vec![3, 4]];
+  let mut fitness_values = vec![1.0, 4.0];
+
+  let offsprings = vec![(vec![5, 6], 2.0), (vec![7, 8], 0.5), (vec![9, 0], 3.0)];
+  mu_comma_lambda_replacement(&mut population, &mut fitness_values, offsprings).unwrap();
+  assert_eq!(population, vec![vec![9, 0], vec![5, 6]]);
+```
+*/
+pub fn mu_comma_lambda_replacement<T>(population: &mut Vec<T>, fitness_values: &mut Vec<f32>, mut offsprings: Vec<(T, f32)>) -> Result<(), &'static str> {
+  check_population(population, fitness_values)?;
+  let mu = population.len();
+  if offsprings.len() < mu {
+    return Err("there should be at least as many offsprings as individuals in the population");
+  }
+  offsprings.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+  offsprings.truncate(mu);
+  population.clear();
+  fitness_values.clear();
+  for (individual, fitness) in offsprings {
+    population.push(individual);
+    fitness_values.push(fitness);
+  }
+  Ok(())
+}
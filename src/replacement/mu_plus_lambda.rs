@@ -0,0 +1,41 @@
+use std::cmp::Ordering;
+
+use super::check_population;
+
+/**
+## Description
+(μ + λ) Replacement pools the μ individuals of the population together with the λ `offsprings`, and keeps the μ fittest
+of them as the next population. Parents survive for as long as no better offspring is generated, so the best individual
+is never lost.
+
+### Note
+
+- The next population is sorted by non increasing fitness values, parents winning ties against offsprings.
+
+## Return
+
+The return value is a `Result<(), &'static str>` which will return error if `population` and `fitness_values` have different lengths.
+
+## Example
+```rust
+  use genx::replacement::mu_plus_lambda_replacement;
+  let mut population = vec![vec![1, 2], vec![3, 4]];
+  let mut fitness_values = vec![1.0, 4.0];
+
+  mu_plus_lambda_replacement(&mut population, &mut fitness_values, vec![(vec![5, 6], 2.0), (vec![7, 8], 0.5)]).unwrap();
+  assert_eq!(population, vec![vec![3, 4], vec![5, 6]]);
+  assert_eq!(fitness_values, vec![4.0, 2.0]);
+```
+*/
+pub fn mu_plus_lambda_replacement<T>(population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>) -> Result<(), &'static str> {
+  check_population(population, fitness_values)?;
+  let mu = population.len();
+  let mut pool = population.drain(..).zip(fitness_values.drain(..)).chain(offsprings).collect::<Vec<(T, f32)>>();
+  pool.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+  pool.truncate(mu);
+  for (individual, fitness) in pool {
+    population.push(individual);
+    fitness_values.push(fitness);
+  }
+  Ok(())
+}
@@ -0,0 +1,76 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::crowding_pairs;
+
+/**
+## Description
+Probabilistic Crowding is `deterministic_crowding` where the competition between an offspring and its most similar parent
+isn't won by the fitter one for sure. An offspring with fitness value f<sub>c</sub> replaces its parent with fitness
+value f<sub>p</sub> with probability f<sub>c</sub> / (f<sub>c</sub> + f<sub>p</sub>).
+
+Weaker individuals thus keep a chance to survive, which lets niches around lower optima be maintained in the population
+in proportion to their fitness.
+
+### Note
+
+- Fitness values should be non negative, when both fitness values are zero each one wins with equal probability.
+- `parents` are the indices in the population of the parents the `offsprings` were generated from, which should be
+  passed along with their fitness values.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<(bool, bool), &'static str>` telling whether each offspring entered the population, which
+will return error if `population` and `fitness_values` have different lengths or a parent index is out of bounds.
+
+## Example
+```rust
+  use genx::replacement::probabilistic_crowding;
+  let mut population = vec![vec![false, false, false], vec![true, true, true]];
+  let mut fitness_values = vec![1.0, 2.0];
+  let hamming = |a: &Vec<bool>, b: &Vec<bool>| a.iter().zip(b.iter()).filter(|(x, y)| x != y).count() as f32;
+
+  let offsprings = ((vec![true, true, false], 3.0), (vec![false, false, true], 0.5));
+  let replaced = probabilistic_crowding(&mut population, &mut fitness_values, (0, 1), offsprings, hamming, None).unwrap();
+```
+*/
+pub fn probabilistic_crowding<T, D>(
+  population: &mut Vec<T>,
+  fitness_values: &mut Vec<f32>,
+  parents: (usize, usize),
+  offsprings: ((T, f32), (T, f32)),
+  distance: D,
+  seed: Option<u64>,
+) -> Result<(bool, bool), &'static str>
+where
+  D: Fn(&T, &T) -> f32,
+{
+  let pairs = crowding_pairs(population, fitness_values, parents, (&(offsprings.0).0, &(offsprings.1).0), &distance)?;
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+
+  let mut offsprings = [Some(offsprings.0), Some(offsprings.1)];
+  let mut replaced = [false; 2];
+  for &(parent, offspring) in pairs.iter() {
+    let offspring_fitness = offsprings[offspring].as_ref().unwrap().1.max(0.0);
+    let parent_fitness = fitness_values[parent].max(0.0);
+    let probability = if offspring_fitness + parent_fitness > 0.0 {
+      offspring_fitness / (offspring_fitness + parent_fitness)
+    } else {
+      0.5
+    };
+    if prng.gen::<f32>() < probability {
+      let (individual, fitness) = offsprings[offspring].take().unwrap();
+      population[parent] = individual;
+      fitness_values[parent] = fitness;
+      replaced[offspring] = true;
+    }
+  }
+  // the second offspring overwrote the first one competing for the same parent
+  if parents.0 == parents.1 && replaced[0] && replaced[1] {
+    replaced[pairs[0].1] = false;
+  }
+  Ok((replaced[0], replaced[1]))
+}
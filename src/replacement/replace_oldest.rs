@@ -0,0 +1,40 @@
+use super::check_population;
+
+/**
+## Description
+Replace Oldest Replacement treats the population as a queue ordered by age, the oldest individual being the first one.
+The oldest individuals are removed from the front of the population and the `offsprings` are appended at its end, so
+every individual survives for the same number of insertions regardless of its fitness.
+
+### Note
+
+- Indices of the remaining individuals shift towards the front, keep the population in insertion order for the ages to hold.
+
+## Return
+
+The return value is a `Result<(), &'static str>` which will return error if `population` and `fitness_values` have
+different lengths, or if there are more offsprings than individuals in the population.
+
+## Example
+```rust
+  use genx::replacement::replace_oldest;
+  let mut population = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+  let mut fitness_values = vec![1.0, 4.0, 0.5];
+
+  replace_oldest(&mut population, &mut fitness_values, vec![(vec![7, 8], 2.0)]).unwrap();
+  assert_eq!(population, vec![vec![3, 4], vec![5, 6], vec![7, 8]]);
+```
+*/
+pub fn replace_oldest<T>(population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>) -> Result<(), &'static str> {
+  check_population(population, fitness_values)?;
+  if offsprings.len() > population.len() {
+    return Err("there should be at most as many offsprings as individuals in the population");
+  }
+  population.drain(..offsprings.len());
+  fitness_values.drain(..offsprings.len());
+  for (individual, fitness) in offsprings {
+    population.push(individual);
+    fitness_values.push(fitness);
+  }
+  Ok(())
+}
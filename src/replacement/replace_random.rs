@@ -0,0 +1,43 @@
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+
+use super::check_population;
+
+/**
+## Description
+Replace Random Replacement inserts the `offsprings` in place of distinct individuals of the population chosen uniformly
+at random. It applies no selection pressure by itself, which is then left entirely to the parent selection.
+
+### Note
+
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<(), &'static str>` which will return error if `population` and `fitness_values` have
+different lengths, or if there are more offsprings than individuals in the population.
+
+## Example
+```rust
+  use genx::replacement::replace_random;
+  let mut population = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+  let mut fitness_values = vec![1.0, 4.0, 0.5];
+
+  replace_random(&mut population, &mut fitness_values, vec![(vec![7, 8], 2.0)], None).unwrap();
+```
+*/
+pub fn replace_random<T>(population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>, seed: Option<u64>) -> Result<(), &'static str> {
+  check_population(population, fitness_values)?;
+  if offsprings.len() > population.len() {
+    return Err("there should be at most as many offsprings as individuals in the population");
+  }
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+  let replaced = sample(&mut prng, population.len(), offsprings.len());
+  for (idx, (individual, fitness)) in replaced.iter().zip(offsprings) {
+    population[idx] = individual;
+    fitness_values[idx] = fitness;
+  }
+  Ok(())
+}
@@ -0,0 +1,38 @@
+use std::cmp::Ordering;
+
+use super::check_population;
+
+/**
+## Description
+Replace Worst Replacement inserts the `offsprings` in place of the worst individuals of the population, as in the
+steady state GENITOR algorithm. Offsprings replace the worst individuals even when they are worse themselves, so the
+population size is kept constant while the best individuals are never lost.
+
+## Return
+
+The return value is a `Result<(), &'static str>` which will return error if `population` and `fitness_values` have
+different lengths, or if there are more offsprings than individuals in the population.
+
+## Example
+```rust
+  use genx::replacement::replace_worst;
+  let mut population = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+  let mut fitness_values = vec![1.0, 4.0, 0.5];
+
+  replace_worst(&mut population, &mut fitness_values, vec![(vec![7, 8], 2.0)]).unwrap();
+  assert_eq!(population, vec![vec![1, 2], vec![3, 4], vec![7, 8]]);
+```
+*/
+pub fn replace_worst<T>(population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>) -> Result<(), &'static str> {
+  check_population(population, fitness_values)?;
+  if offsprings.len() > population.len() {
+    return Err("there should be at most as many offsprings as individuals in the population");
+  }
+  let mut order = (0..population.len()).collect::<Vec<usize>>();
+  order.sort_by(|&a, &b| fitness_values[a].partial_cmp(&fitness_values[b]).unwrap_or(Ordering::Equal));
+  for (&idx, (individual, fitness)) in order.iter().zip(offsprings) {
+    population[idx] = individual;
+    fitness_values[idx] = fitness;
+  }
+  Ok(())
+}
@@ -0,0 +1,71 @@
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+
+use super::check_population;
+
+/**
+## Description
+Restricted Tournament Replacement, the replacement step of restricted tournament selection, makes an offspring compete
+with the most similar individual of a random window. `window_size` individuals are picked at random from the population,
+and the offspring replaces the one nearest to it according to `distance` if its fitness value is higher.
+
+Larger windows find more similar competitors, preserving more niches at the cost of more distance computations.
+
+### Note
+
+- If `window_size` is greater than the population size, the whole population is searched.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<Option<usize>, &'static str>` containing the index the offspring was placed at, if any,
+which will return error if `population` and `fitness_values` have different lengths.
+
+## Example
+```rust
+  use genx::replacement::restricted_tournament_replacement;
+  let mut population = vec![0.0, 1.0, 5.0, 6.0];
+  let mut fitness_values = vec![1.0, 2.0, 1.0, 0.5];
+  let distance = |a: &f32, b: &f32| (a - b).abs();
+
+  let replaced = restricted_tournament_replacement(&mut population, &mut fitness_values, (5.8, 3.0), 4, distance, None).unwrap();
+  assert_eq!(replaced, Some(3));
+```
+*/
+pub fn restricted_tournament_replacement<T, D>(
+  population: &mut Vec<T>,
+  fitness_values: &mut Vec<f32>,
+  offspring: (T, f32),
+  window_size: usize,
+  distance: D,
+  seed: Option<u64>,
+) -> Result<Option<usize>, &'static str>
+where
+  D: Fn(&T, &T) -> f32,
+{
+  check_population(population, fitness_values)?;
+  if population.is_empty() {
+    return Ok(None);
+  }
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+
+  let window = sample(&mut prng, population.len(), window_size.clamp(1, population.len()));
+  let nearest = window
+    .iter()
+    .map(|idx| (idx, distance(&population[idx], &offspring.0)))
+    .fold(None, |nearest: Option<(usize, f32)>, (idx, d)| match nearest {
+      Some((_, best)) if best <= d => nearest,
+      _ => Some((idx, d)),
+    })
+    .unwrap()
+    .0;
+
+  if offspring.1 > fitness_values[nearest] {
+    population[nearest] = offspring.0;
+    fitness_values[nearest] = offspring.1;
+    return Ok(Some(nearest));
+  }
+  Ok(None)
+}
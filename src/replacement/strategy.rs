@@ -0,0 +1,146 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{
+  check_population, generation_gap_replacement, mu_comma_lambda_replacement, mu_plus_lambda_replacement, replace_oldest,
+  replace_random, replace_worst, restricted_tournament_replacement,
+};
+
+/**
+## Description
+`Replacement` is implemented by the replacement schemes which insert a batch of offsprings, paired with their fitness
+values, into the population. The replacement used by a genetic algorithm can then be chosen at runtime, passed to a
+runner as a generic parameter, or called directly from a hand written loop.
+
+It is also implemented for every function or closure with the matching signature.
+
+## Example
+```rust
+use genx::replacement::{GenerationGapReplacement, MuPlusLambdaReplacement, Replacement, ReplaceWorst};
+
+fn survive<R: Replacement<Vec<bool>>>(replacement: &R, population: &mut Vec<Vec<bool>>, fitness_values: &mut Vec<f32>) {
+    let offsprings = vec![(vec![true, true], 2.0), (vec![false, true], 1.0)];
+    replacement.replace(population, fitness_values, offsprings, None).unwrap();
+}
+
+let mut population = vec![vec![false, false], vec![true, false], vec![false, false]];
+let mut fitness_values = vec![0.0, 1.0, 0.0];
+survive(&MuPlusLambdaReplacement, &mut population, &mut fitness_values);
+survive(&GenerationGapReplacement::new(0.5).unwrap(), &mut population, &mut fitness_values);
+
+let schemes: Vec<Box<dyn Replacement<Vec<bool>>>> = vec![Box::new(ReplaceWorst), Box::new(MuPlusLambdaReplacement)];
+```
+ */
+pub trait Replacement<T> {
+  fn replace(&self, population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>, seed: Option<u64>) -> Result<(), &'static str>;
+}
+
+impl<T, F> Replacement<T> for F
+where
+  F: Fn(&mut Vec<T>, &mut Vec<f32>, Vec<(T, f32)>, Option<u64>) -> Result<(), &'static str>,
+{
+  fn replace(&self, population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>, seed: Option<u64>) -> Result<(), &'static str> {
+    self(population, fitness_values, offsprings, seed)
+  }
+}
+
+/// `mu_plus_lambda_replacement` as a [`Replacement`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MuPlusLambdaReplacement;
+
+impl<T> Replacement<T> for MuPlusLambdaReplacement {
+  fn replace(&self, population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>, _seed: Option<u64>) -> Result<(), &'static str> {
+    mu_plus_lambda_replacement(population, fitness_values, offsprings)
+  }
+}
+
+/// `mu_comma_lambda_replacement` as a [`Replacement`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MuCommaLambdaReplacement;
+
+impl<T> Replacement<T> for MuCommaLambdaReplacement {
+  fn replace(&self, population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>, _seed: Option<u64>) -> Result<(), &'static str> {
+    mu_comma_lambda_replacement(population, fitness_values, offsprings)
+  }
+}
+
+/// `replace_worst` as a [`Replacement`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ReplaceWorst;
+
+impl<T> Replacement<T> for ReplaceWorst {
+  fn replace(&self, population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>, _seed: Option<u64>) -> Result<(), &'static str> {
+    replace_worst(population, fitness_values, offsprings)
+  }
+}
+
+/// `replace_random` as a [`Replacement`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ReplaceRandom;
+
+impl<T> Replacement<T> for ReplaceRandom {
+  fn replace(&self, population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>, seed: Option<u64>) -> Result<(), &'static str> {
+    replace_random(population, fitness_values, offsprings, seed)
+  }
+}
+
+/// `replace_oldest` as a [`Replacement`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ReplaceOldest;
+
+impl<T> Replacement<T> for ReplaceOldest {
+  fn replace(&self, population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>, _seed: Option<u64>) -> Result<(), &'static str> {
+    replace_oldest(population, fitness_values, offsprings)
+  }
+}
+
+/// `generation_gap_replacement` as a [`Replacement`].
+#[derive(Clone, Copy, Debug)]
+pub struct GenerationGapReplacement {
+  generation_gap: f32,
+}
+
+impl GenerationGapReplacement {
+  /// Returns error if `generation_gap` does not lie between `0.0` (exclusive) and `1.0` (inclusive).
+  pub fn new(generation_gap: f32) -> Result<Self, &'static str> {
+    if !(generation_gap > 0.0 && generation_gap <= 1.0) {
+      return Err("generation_gap should lie between 0.0 exclusive and 1.0 inclusive");
+    }
+    Ok(GenerationGapReplacement { generation_gap })
+  }
+}
+
+impl<T> Replacement<T> for GenerationGapReplacement {
+  fn replace(&self, population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>, _seed: Option<u64>) -> Result<(), &'static str> {
+    generation_gap_replacement(population, fitness_values, offsprings, self.generation_gap)
+  }
+}
+
+/// `restricted_tournament_replacement` as a [`Replacement`], inserting the offsprings one after the other.
+pub struct RestrictedTournamentReplacement<D> {
+  pub window_size: usize,
+  pub distance: D,
+}
+
+impl<D> RestrictedTournamentReplacement<D> {
+  pub fn new(window_size: usize, distance: D) -> Self {
+    RestrictedTournamentReplacement { window_size, distance }
+  }
+}
+
+impl<T, D> Replacement<T> for RestrictedTournamentReplacement<D>
+where
+  D: Fn(&T, &T) -> f32,
+{
+  fn replace(&self, population: &mut Vec<T>, fitness_values: &mut Vec<f32>, offsprings: Vec<(T, f32)>, seed: Option<u64>) -> Result<(), &'static str> {
+    check_population(population, fitness_values)?;
+    let mut prng = match seed {
+      Some(val) => StdRng::seed_from_u64(val),
+      None => StdRng::from_entropy()
+    };
+    for offspring in offsprings {
+      let seed = seed.map(|_| prng.gen::<u64>());
+      restricted_tournament_replacement(population, fitness_values, offspring, self.window_size, &self.distance, seed)?;
+    }
+    Ok(())
+  }
+}
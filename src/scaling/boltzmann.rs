@@ -0,0 +1,43 @@
+use crate::selection::boltzmann::check_temperature;
+
+/**
+## Description
+Boltzmann Scaling maps every fitness value f to e<sup>f / T</sup> divided by the average of e<sup>f / T</sup> over the population,
+T being the `temperature`. The scaled values thus average to `1.0`.
+
+A high temperature gives every individual almost the same scaled fitness while a low one strongly favours the fittest
+individuals, so the temperature is usually lowered over the generations using `genx::selection::CoolingSchedule`.
+
+### Note
+
+- The computation is shifted by the maximum fitness value, which avoids overflow without changing the result.
+
+## Return
+
+The return value is a `Result<(), &'static str>` which will return error if `temperature` isn't positive, leaving the
+fitness values unchanged.
+
+## Example
+```rust
+  use genx::{scaling::boltzmann_scaling, selection::CoolingSchedule};
+  let schedule = CoolingSchedule::Exponential { initial_temperature: 10.0, cooling_rate: 0.9 };
+
+  for generation in 0..5 {
+    let mut fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+    boltzmann_scaling(&mut fitness_values, schedule.temperature(generation)).unwrap();
+  }
+```
+*/
+pub fn boltzmann_scaling(fitness_values: &mut Vec<f32>, temperature: f32) -> Result<(), &'static str> {
+  check_temperature(temperature)?;
+
+  let max = fitness_values.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+  for x in fitness_values.iter_mut() {
+    *x = ((*x - max) / temperature).exp();
+  }
+  let average = fitness_values.iter().sum::<f32>() / (fitness_values.len() as f32);
+  for x in fitness_values {
+    *x /= average;
+  }
+  Ok(())
+}
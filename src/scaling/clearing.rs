@@ -0,0 +1,69 @@
+use std::cmp::Ordering;
+
+use super::check_population;
+
+/**
+## Description
+Clearing keeps the fitness of only the `capacity` best individuals of every niche, the winners, and clears the fitness
+of the others to `0.0`. Individuals are visited from the fittest one, and every individual which hasn't been cleared
+yet becomes the winner of a niche containing all remaining individuals within `clearing_radius` of it.
+
+Unlike fitness sharing the winners keep their full fitness, which keeps a strong selection pressure inside each niche
+while making space for other niches.
+
+### Note
+
+- Fitness values should be non negative, so that cleared individuals are the least likely to be selected.
+
+## Return
+
+The return value is a `Result<(), &'static str>` which will return error if `population` and `fitness_values` have
+different lengths, or if `capacity` is zero.
+
+## Example
+```rust
+  use genx::scaling::clearing;
+  let population = vec![0.0, 0.1, 0.2, 5.0];
+  let mut fitness_values = vec![1.0, 3.0, 2.0, 0.5];
+  let distance = |a: &f32, b: &f32| (a - b).abs();
+
+  clearing(&mut fitness_values, &population, distance, 1.0, 1).unwrap();
+  assert_eq!(fitness_values, vec![0.0, 3.0, 0.0, 0.5]);
+```
+*/
+pub fn clearing<T, D>(fitness_values: &mut Vec<f32>, population: &Vec<T>, distance: D, clearing_radius: f32, capacity: usize) -> Result<(), &'static str>
+where
+  D: Fn(&T, &T) -> f32,
+{
+  check_population(fitness_values, population)?;
+  if capacity == 0 {
+    return Err("capacity should be at least one");
+  }
+
+  let mut order = (0..population.len()).collect::<Vec<usize>>();
+  order.sort_by(|&a, &b| fitness_values[b].partial_cmp(&fitness_values[a]).unwrap_or(Ordering::Equal));
+
+  let mut cleared = vec![false; population.len()];
+  for (position, &i) in order.iter().enumerate() {
+    if cleared[i] {
+      continue;
+    }
+    let mut winners = 1;
+    for &j in order[(position + 1)..].iter() {
+      if cleared[j] || distance(&population[i], &population[j]) >= clearing_radius {
+        continue;
+      }
+      if winners < capacity {
+        winners += 1;
+      } else {
+        cleared[j] = true;
+      }
+    }
+  }
+  for (x, cleared) in fitness_values.iter_mut().zip(cleared) {
+    if cleared {
+      *x = 0.0;
+    }
+  }
+  Ok(())
+}
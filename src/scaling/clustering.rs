@@ -0,0 +1,72 @@
+use std::cmp::Ordering;
+
+use super::check_population;
+
+/**
+## Description
+Clustering Niching is a fitness sharing where the niches are found by clustering the population, which only needs the
+distance of every individual to the cluster leaders instead of every pair of individuals.
+
+Individuals are visited from the fittest one, each one joining the first cluster whose leader is within `radius` of it,
+or becoming the leader of a new cluster otherwise. The fitness value of every individual is then divided by
+n<sub>c</sub> (1 - (d<sub>ic</sub> / 2r)<sup>α</sup>), n<sub>c</sub> being the size of its cluster and
+d<sub>ic</sub> its distance to the leader of the cluster, so that individuals close to crowded leaders share the most.
+
+### Note
+
+- The shared fitness values keep the sign of the fitness values, so use non negative fitness values for proportional selections.
+
+## Return
+
+The return value is a `Result<Vec<usize>, &'static str>` containing the index of the leader of the cluster of every individual,
+which will return error if `population` and `fitness_values` have different lengths, or if `radius` or `alpha` isn't positive.
+
+## Example
+```rust
+  use genx::scaling::clustering_niching;
+  let population = vec![0.0, 0.1, 0.2, 5.0];
+  let mut fitness_values = vec![1.0, 3.0, 2.0, 0.5];
+  let distance = |a: &f32, b: &f32| (a - b).abs();
+
+  let leaders = clustering_niching(&mut fitness_values, &population, distance, 1.0, 1.0).unwrap();
+  assert_eq!(leaders, vec![1, 1, 1, 3]);
+  assert_eq!(fitness_values[3], 0.5);
+```
+*/
+pub fn clustering_niching<T, D>(fitness_values: &mut Vec<f32>, population: &Vec<T>, distance: D, radius: f32, alpha: f32) -> Result<Vec<usize>, &'static str>
+where
+  D: Fn(&T, &T) -> f32,
+{
+  check_population(fitness_values, population)?;
+  if radius <= 0.0 || radius.is_nan() {
+    return Err("radius should be positive");
+  }
+  if alpha <= 0.0 || alpha.is_nan() {
+    return Err("alpha should be positive");
+  }
+
+  let mut order = (0..population.len()).collect::<Vec<usize>>();
+  order.sort_by(|&a, &b| fitness_values[b].partial_cmp(&fitness_values[a]).unwrap_or(Ordering::Equal));
+
+  let mut leaders:Vec<usize> = Vec::new();
+  let mut assignment = vec![(0usize, 0.0f32); population.len()];
+  for &i in order.iter() {
+    let nearest = leaders.iter().map(|&leader| (leader, distance(&population[leader], &population[i]))).find(|&(_, d)| d < radius);
+    match nearest {
+      Some(cluster) => assignment[i] = cluster,
+      None => {
+        leaders.push(i);
+        assignment[i] = (i, 0.0);
+      }
+    }
+  }
+
+  let mut cluster_sizes = vec![0usize; population.len()];
+  for &(leader, _) in assignment.iter() {
+    cluster_sizes[leader] += 1;
+  }
+  for (x, &(leader, d)) in fitness_values.iter_mut().zip(assignment.iter()) {
+    *x /= cluster_sizes[leader] as f32 * (1.0 - (d / (2.0 * radius)).powf(alpha));
+  }
+  Ok(assignment.into_iter().map(|(leader, _)| leader).collect())
+}
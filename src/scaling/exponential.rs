@@ -0,0 +1,23 @@
+/**
+## Description
+Exponential Scaling maps every fitness value f to e<sup>c f</sup>, c being the `scaling_factor`. Differences between
+fitness values become ratios between the scaled ones, so any sign of fitness values results in positive scaled values.
+
+### Note
+
+- Large values of c f overflow to infinity, use `boltzmann_scaling` which normalizes the scaled values if that's a concern.
+
+## Example
+```rust
+  use genx::scaling::exponential_scaling;
+  let mut fitness_values = vec![-1.0, 0.0, 1.0];
+
+  exponential_scaling(&mut fitness_values, 1.0);
+  assert_eq!(fitness_values[1], 1.0);
+```
+*/
+pub fn exponential_scaling(fitness_values: &mut Vec<f32>, scaling_factor: f32) {
+  for x in fitness_values {
+    *x = (scaling_factor * *x).exp();
+  }
+}
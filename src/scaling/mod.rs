@@ -1,7 +1,89 @@
+//! The `scaling` module provides implementation of the
+//! functions to scale the fitness values of the population
+//! before doing a fitness proportionate selection, controlling
+//! the selection pressure.
+//!
+//! The provided functions are organized in sub-modules
+//! named after the utilized scaling method:
+//! * `linear`
+//! * `sigma`
+//! * `power_law`
+//! * `exponential`
+//! * `boltzmann`
+//! * `rank`
+//! * `top`
+//! * `windowing`
+//! * `sharing`
+//! * `clearing`
+//! * `clustering`
+//!
+//! All the functions take in a mutable reference to a Vector of
+//! floating point values that contains fitness values of individuals,
+//! and replace them by the scaled fitness values.
+//!
+//! Fitness sharing, clearing and clustering niching also take in the
+//! population along with a distance metric between individuals, lowering
+//! the fitness of individuals crowding the same niche so that proportional
+//! selections maintain multiple niches of a multimodal problem.
+//!
+//! Every other scheme is also available as a struct implementing the [`Scaling`]
+//! trait from `strategy`, which keeps state across generations and is told
+//! the current generation. Windowing scaling needs to remember the previous
+//! generations, so it is only available as a [`Scaling`].
+
+pub mod boltzmann;
+
+pub mod clearing;
+
+pub mod clustering;
+
+pub mod exponential;
+
 pub mod linear;
 
+pub mod power_law;
+
+pub mod rank;
+
+pub mod sharing;
+
 pub mod sigma;
 
+pub mod strategy;
+
+pub mod top;
+
+pub mod windowing;
+
+pub use self::boltzmann::boltzmann_scaling;
+
+pub use self::clearing::clearing;
+
+pub use self::clustering::clustering_niching;
+
+pub use self::exponential::exponential_scaling;
+
 pub use self::linear::linear_scaling;
 
+pub use self::power_law::power_law_scaling;
+
+pub use self::rank::rank_scaling;
+
+pub use self::sharing::fitness_sharing;
+
 pub use self::sigma::sigma_scaling;
+
+pub use self::strategy::{
+  BoltzmannScaling, ExponentialScaling, LinearScaling, PowerLawScaling, RankScaling, Scaling, SigmaScaling, TopScaling,
+};
+
+pub use self::top::top_scaling;
+
+pub use self::windowing::WindowingScaling;
+
+fn check_population<T>(fitness_values: &Vec<f32>, population: &Vec<T>) -> Result<(), &'static str> {
+  if fitness_values.len() != population.len() {
+    return Err("fitness_values and population should have the same length");
+  }
+  Ok(())
+}
@@ -0,0 +1,23 @@
+/**
+## Description
+Power Law Scaling raises every fitness value to the power `exponent`, f' = f<sup>k</sup>.
+An `exponent` greater than `1.0` increases the selection pressure while one lower than `1.0` reduces it.
+
+### Note
+
+- Fitness values should be non negative, as negative values raised to a fractional power are `NaN`.
+
+## Example
+```rust
+  use genx::scaling::power_law_scaling;
+  let mut fitness_values = vec![1.0, 2.0, 3.0];
+
+  power_law_scaling(&mut fitness_values, 2.0);
+  assert_eq!(fitness_values, vec![1.0, 4.0, 9.0]);
+```
+*/
+pub fn power_law_scaling(fitness_values: &mut Vec<f32>, exponent: f32) {
+  for x in fitness_values {
+    *x = x.powf(exponent);
+  }
+}
@@ -0,0 +1,40 @@
+use std::cmp::Ordering;
+
+/**
+## Description
+Rank Scaling replaces every fitness value by its rank when sorted in non decreasing order, the worst individual getting
+`1.0` and the best one `n`. Only the order of the fitness values matters, so outliers can't take over the population.
+
+### Note
+
+- Individuals with same fitness value get the average of their ranks.
+
+## Example
+```rust
+  use genx::scaling::rank_scaling;
+  let mut fitness_values = vec![-5.0, 100.0, 2.0, 2.0];
+
+  rank_scaling(&mut fitness_values);
+  assert_eq!(fitness_values, vec![1.0, 4.0, 2.5, 2.5]);
+```
+*/
+pub fn rank_scaling(fitness_values: &mut Vec<f32>) {
+  let mut order = (0..fitness_values.len()).collect::<Vec<usize>>();
+  order.sort_by(|&a, &b| fitness_values[a].partial_cmp(&fitness_values[b]).unwrap_or(Ordering::Equal));
+
+  let mut ranks = vec![0.0; fitness_values.len()];
+  let mut start = 0;
+  while start < order.len() {
+    let mut end = start + 1;
+    while end < order.len() && fitness_values[order[end]] == fitness_values[order[start]] {
+      end += 1;
+    }
+    // ranks start..end are 1-based, so their average is the midpoint of start + 1 and end
+    let average = (start + 1 + end) as f32 / 2.0;
+    for &idx in order[start..end].iter() {
+      ranks[idx] = average;
+    }
+    start = end;
+  }
+  *fitness_values = ranks;
+}
@@ -0,0 +1,65 @@
+use super::check_population;
+
+/**
+## Description
+Fitness Sharing divides the fitness value of every individual by its niche count m<sub>i</sub>, the number of individuals
+around it weighted by how close they are, so that individuals crowding the same peak share its fitness and the
+population spreads over multiple peaks of a multimodal problem.
+
+With d<sub>ij</sub> the `distance` between the `i`th and `j`th individuals, the triangular sharing function is
+sh(d) = 1 - (d / σ<sub>share</sub>)<sup>α</sup> for d < σ<sub>share</sub> and 0 otherwise, and
+m<sub>i</sub> = Σ<sub>j</sub> sh(d<sub>ij</sub>) which is at least `1.0` as every individual shares with itself.
+
+`sigma_share` is the radius of a niche, and `alpha` controls the shape of the sharing function, `1.0` being linear.
+
+### Note
+
+- The shared fitness values keep the sign of the fitness values, so use non negative fitness values for proportional selections.
+- Computing the niche counts needs the distance between every pair of individuals, which is O(n<sup>2</sup>).
+
+## Return
+
+The return value is a `Result<(), &'static str>` which will return error if `population` and `fitness_values` have
+different lengths, or if `sigma_share` or `alpha` isn't positive.
+
+## Example
+```rust
+  use genx::{scaling::fitness_sharing, selection::stochastic_universal_selection};
+  let population = vec![vec![0.0, 0.1], vec![0.0, 0.2], vec![5.0, 5.0]];
+  let mut fitness_values = vec![1.0, 1.0, 1.0];
+  let distance = |a: &Vec<f32>, b: &Vec<f32>| a.iter().zip(b.iter()).map(|(x, y)| (x - y).powi(2)).sum::<f32>().sqrt();
+
+  fitness_sharing(&mut fitness_values, &population, distance, 1.0, 1.0).unwrap();
+  assert!(fitness_values[2] > fitness_values[0]);
+  let result = stochastic_universal_selection(&fitness_values, 2, None);
+```
+*/
+pub fn fitness_sharing<T, D>(fitness_values: &mut Vec<f32>, population: &Vec<T>, distance: D, sigma_share: f32, alpha: f32) -> Result<(), &'static str>
+where
+  D: Fn(&T, &T) -> f32,
+{
+  check_population(fitness_values, population)?;
+  if sigma_share <= 0.0 || sigma_share.is_nan() {
+    return Err("sigma_share should be positive");
+  }
+  if alpha <= 0.0 || alpha.is_nan() {
+    return Err("alpha should be positive");
+  }
+
+  let n = population.len();
+  let mut niche_counts = vec![1.0f32; n];
+  for i in 0..n {
+    for j in (i + 1)..n {
+      let d = distance(&population[i], &population[j]);
+      if d < sigma_share {
+        let share = 1.0 - (d / sigma_share).powf(alpha);
+        niche_counts[i] += share;
+        niche_counts[j] += share;
+      }
+    }
+  }
+  for (x, niche_count) in fitness_values.iter_mut().zip(niche_counts) {
+    *x /= niche_count;
+  }
+  Ok(())
+}
@@ -0,0 +1,186 @@
+use crate::selection::{boltzmann::check_schedule, CoolingSchedule};
+
+use super::{
+  boltzmann_scaling, exponential_scaling, linear_scaling, power_law_scaling, rank_scaling, sigma_scaling, top_scaling,
+};
+
+/**
+## Description
+`Scaling` is implemented by every scaling scheme of this module. Unlike the scaling functions, it can keep state across
+generations and is told the current `generation` on each call, so that schedules such as the annealing temperature of
+[`BoltzmannScaling`] or the window of `WindowingScaling` don't need to be tracked in the genetic algorithm loop.
+
+It is also implemented for every function or closure taking the fitness values and the generation.
+
+## Example
+```rust
+use genx::{
+    scaling::{BoltzmannScaling, LinearScaling, Scaling, WindowingScaling},
+    selection::{stochastic_universal_selection, CoolingSchedule},
+};
+
+let mut schemes: Vec<Box<dyn Scaling>> = vec![
+    Box::new(LinearScaling::new(1.5)),
+    Box::new(BoltzmannScaling::new(CoolingSchedule::Exponential { initial_temperature: 10.0, cooling_rate: 0.9 }).unwrap()),
+    Box::new(WindowingScaling::new(5)),
+    Box::new(|fitness_values: &mut Vec<f32>, generation: usize| {
+        fitness_values.iter_mut().for_each(|x| *x += generation as f32)
+    }),
+];
+
+for generation in 0..10 {
+    for scaling in schemes.iter_mut() {
+        let mut fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+        scaling.scale(&mut fitness_values, generation);
+        let result = stochastic_universal_selection(&fitness_values, 4, None);
+    }
+}
+```
+ */
+pub trait Scaling {
+  fn scale(&mut self, fitness_values: &mut Vec<f32>, generation: usize);
+
+  /// Forgets any state kept from the previous generations.
+  fn reset(&mut self) {}
+}
+
+impl<F> Scaling for F
+where
+  F: FnMut(&mut Vec<f32>, usize),
+{
+  fn scale(&mut self, fitness_values: &mut Vec<f32>, generation: usize) {
+    self(fitness_values, generation)
+  }
+}
+
+/// `linear_scaling` as a [`Scaling`].
+#[derive(Clone, Copy, Debug)]
+pub struct LinearScaling {
+  pub scaling_factor: f32,
+}
+
+impl LinearScaling {
+  pub fn new(scaling_factor: f32) -> Self {
+    LinearScaling { scaling_factor }
+  }
+}
+
+impl Scaling for LinearScaling {
+  fn scale(&mut self, fitness_values: &mut Vec<f32>, _generation: usize) {
+    linear_scaling(fitness_values, self.scaling_factor)
+  }
+}
+
+/// `sigma_scaling` as a [`Scaling`].
+#[derive(Clone, Copy, Debug)]
+pub struct SigmaScaling {
+  pub scaling_factor: f32,
+}
+
+impl SigmaScaling {
+  pub fn new(scaling_factor: f32) -> Self {
+    SigmaScaling { scaling_factor }
+  }
+}
+
+impl Scaling for SigmaScaling {
+  fn scale(&mut self, fitness_values: &mut Vec<f32>, _generation: usize) {
+    sigma_scaling(fitness_values, self.scaling_factor)
+  }
+}
+
+/// `power_law_scaling` as a [`Scaling`].
+#[derive(Clone, Copy, Debug)]
+pub struct PowerLawScaling {
+  pub exponent: f32,
+}
+
+impl PowerLawScaling {
+  pub fn new(exponent: f32) -> Self {
+    PowerLawScaling { exponent }
+  }
+}
+
+impl Scaling for PowerLawScaling {
+  fn scale(&mut self, fitness_values: &mut Vec<f32>, _generation: usize) {
+    power_law_scaling(fitness_values, self.exponent)
+  }
+}
+
+/// `exponential_scaling` as a [`Scaling`].
+#[derive(Clone, Copy, Debug)]
+pub struct ExponentialScaling {
+  pub scaling_factor: f32,
+}
+
+impl ExponentialScaling {
+  pub fn new(scaling_factor: f32) -> Self {
+    ExponentialScaling { scaling_factor }
+  }
+}
+
+impl Scaling for ExponentialScaling {
+  fn scale(&mut self, fitness_values: &mut Vec<f32>, _generation: usize) {
+    exponential_scaling(fitness_values, self.scaling_factor)
+  }
+}
+
+/**
+`boltzmann_scaling` as a [`Scaling`], annealed with the temperature the `schedule` gives for the generation.
+*/
+#[derive(Clone, Copy, Debug)]
+pub struct BoltzmannScaling {
+  schedule: CoolingSchedule,
+}
+
+impl BoltzmannScaling {
+  /**
+  Returns error if the `schedule` can give a non positive temperature: an initial temperature or a cooling rate of an
+  exponential schedule which isn't positive, a minimum temperature of a linear schedule which isn't positive, or an
+  initial temperature of a logarithmic schedule which isn't positive.
+  */
+  pub fn new(schedule: CoolingSchedule) -> Result<Self, &'static str> {
+    check_schedule(&schedule)?;
+    Ok(BoltzmannScaling { schedule })
+  }
+
+  pub fn schedule(&self) -> CoolingSchedule {
+    self.schedule
+  }
+}
+
+impl Scaling for BoltzmannScaling {
+  fn scale(&mut self, fitness_values: &mut Vec<f32>, generation: usize) {
+    // exponential cooling eventually underflows to 0, the coldest temperature left is the smallest positive one
+    let temperature = self.schedule.temperature(generation).max(f32::MIN_POSITIVE);
+    boltzmann_scaling(fitness_values, temperature).unwrap()
+  }
+}
+
+/// `rank_scaling` as a [`Scaling`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RankScaling;
+
+impl Scaling for RankScaling {
+  fn scale(&mut self, fitness_values: &mut Vec<f32>, _generation: usize) {
+    rank_scaling(fitness_values)
+  }
+}
+
+/// `top_scaling` as a [`Scaling`].
+#[derive(Clone, Copy, Debug)]
+pub struct TopScaling {
+  pub top: usize,
+}
+
+impl TopScaling {
+  pub fn new(top: usize) -> Self {
+    TopScaling { top }
+  }
+}
+
+impl Scaling for TopScaling {
+  fn scale(&mut self, fitness_values: &mut Vec<f32>, _generation: usize) {
+    top_scaling(fitness_values, self.top)
+  }
+}
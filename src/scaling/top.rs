@@ -0,0 +1,30 @@
+use std::cmp::Ordering;
+
+/**
+## Description
+Top Scaling gives a scaled fitness of `1.0` to the `top` fittest individuals and `0.0` to all the others, so proportional
+selections pick uniformly among the best individuals only.
+
+### Note
+
+- Exactly `top` individuals are kept, ties at the boundary are broken in favour of the lower index.
+
+## Example
+```rust
+  use genx::scaling::top_scaling;
+  let mut fitness_values = vec![3.0, 7.0, 1.0, 5.0];
+
+  top_scaling(&mut fitness_values, 2);
+  assert_eq!(fitness_values, vec![0.0, 1.0, 0.0, 1.0]);
+```
+*/
+pub fn top_scaling(fitness_values: &mut Vec<f32>, top: usize) {
+  let mut order = (0..fitness_values.len()).collect::<Vec<usize>>();
+  order.sort_by(|&a, &b| fitness_values[b].partial_cmp(&fitness_values[a]).unwrap_or(Ordering::Equal));
+
+  let mut scaled = vec![0.0; fitness_values.len()];
+  for &idx in order.iter().take(top) {
+    scaled[idx] = 1.0;
+  }
+  *fitness_values = scaled;
+}
@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+
+use super::Scaling;
+
+/**
+## Description
+Windowing Scaling subtracts the worst fitness value seen over the last `window` generations from every fitness value,
+f' = f - f<sub>worst</sub>. Unlike subtracting the worst value of the current generation only, the worst individual of
+a generation keeps a chance of being selected whenever a previous generation of the window had a worse individual, and
+the selection pressure doesn't jump from one generation to the next.
+
+It has to remember the worst fitness values of the previous generations, so it should be kept across generations. The
+[`Scaling`] implementation is told the current generation on every call, while `scale_next` treats every call as a new
+generation.
+
+### Note
+
+- Calling `scale` multiple times in a generation, as in steady state algorithms, keeps the worst value of that generation.
+- A `window` of `0` is treated as `1`, subtracting the worst fitness value of the current generation.
+
+## Example
+```rust
+  use genx::scaling::{Scaling, WindowingScaling};
+  let mut scaling = WindowingScaling::new(2);
+
+  let mut fitness_values = vec![1.0, 3.0, 2.0];
+  scaling.scale(&mut fitness_values, 0);
+  assert_eq!(fitness_values, vec![0.0, 2.0, 1.0]);
+
+  let mut fitness_values = vec![4.0, 5.0];
+  scaling.scale(&mut fitness_values, 1);
+  assert_eq!(fitness_values, vec![3.0, 4.0]);
+
+  // a second call within generation 1
+  let mut fitness_values = vec![0.5, 5.0];
+  scaling.scale(&mut fitness_values, 1);
+  assert_eq!(fitness_values, vec![0.0, 4.5]);
+
+  // the next generation after the last recorded one
+  let mut fitness_values = vec![2.0, 3.0];
+  scaling.scale_next(&mut fitness_values);
+  assert_eq!(fitness_values, vec![1.5, 2.5]);
+```
+*/
+#[derive(Clone, Debug)]
+pub struct WindowingScaling {
+  window: usize,
+  history: VecDeque<(usize, f32)>,
+}
+
+impl WindowingScaling {
+  pub fn new(window: usize) -> Self {
+    WindowingScaling { window: window.max(1), history: VecDeque::new() }
+  }
+
+  /// Number of generations over which the worst fitness value is taken.
+  pub fn window(&self) -> usize {
+    self.window
+  }
+
+  /// Generations in the window along with their worst fitness values, oldest first.
+  pub fn history(&self) -> &VecDeque<(usize, f32)> {
+    &self.history
+  }
+
+  /// Records the worst fitness value of a new generation, following the last recorded one, and subtracts the worst one of the window.
+  pub fn scale_next(&mut self, fitness_values: &mut Vec<f32>) {
+    let generation = self.history.back().map_or(0, |&(last, _)| last + 1);
+    Scaling::scale(self, fitness_values, generation);
+  }
+}
+
+impl Scaling for WindowingScaling {
+  fn scale(&mut self, fitness_values: &mut Vec<f32>, generation: usize) {
+    let worst = fitness_values.iter().cloned().fold(f32::INFINITY, f32::min);
+    if worst.is_finite() {
+      match self.history.back_mut() {
+        Some((last, value)) if *last == generation => *value = value.min(worst),
+        _ => self.history.push_back((generation, worst)),
+      }
+    }
+    while self.history.front().is_some_and(|&(first, _)| first + self.window <= generation) {
+      self.history.pop_front();
+    }
+
+    let worst = self.history.iter().map(|&(_, value)| value).fold(f32::INFINITY, f32::min);
+    if worst.is_finite() {
+      for x in fitness_values {
+        *x -= worst;
+      }
+    }
+  }
+
+  /// Forgets the previous generations.
+  fn reset(&mut self) {
+    self.history.clear();
+  }
+}
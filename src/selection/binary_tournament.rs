@@ -0,0 +1,51 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/**
+## Description
+Binary Tournament Selection is `tournament_selection` with a `tournament_size` of two. For each of the `num_parents`
+tournaments two distinct individuals are picked at random and the fitter one is selected.
+
+It compares the two fitness values directly, without allocating or sorting the participants of the tournaments,
+which makes it the fastest of the tournament selections.
+
+### Note
+
+- Ties are won by the first picked individual.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Vec<usize>` pointing to the selected indices.
+
+## Example
+```rust
+  use genx::selection::binary_tournament_selection;
+  let num_parents:usize = 10;
+  let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let result = binary_tournament_selection(&fitness_values, num_parents, None);
+```
+*/
+pub fn binary_tournament_selection(fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+  let n = fitness_values.len();
+  if n == 0 {
+    return Vec::new();
+  }
+  if n == 1 {
+    return vec![0; num_parents];
+  }
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+
+  (0..num_parents).map(|_| {
+    let first = prng.gen_range(0..n);
+    // shifting past the first pick keeps the second one uniform over the others
+    let mut second = prng.gen_range(0..n - 1);
+    if second >= first {
+      second += 1;
+    }
+    if fitness_values[second] > fitness_values[first] { second } else { first }
+  }).collect()
+}
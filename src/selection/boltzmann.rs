@@ -0,0 +1,85 @@
+use super::roulette_wheel_selection;
+
+/**
+## Description
+Boltzmann Selection is a proportionate selection where the area occupied by an individual on the roulette wheel
+is proportional to e<sup>f / T</sup>, f being its fitness value and T the `temperature`.
+
+A high temperature makes the selection almost uniform while a low one strongly favours the fittest individuals,
+so the temperature is usually lowered over the generations using a [`CoolingSchedule`].
+
+### Note
+
+- Fitness values are shifted by the maximum before exponentiation, which avoids overflow without changing the probabilities.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<Vec<usize>, &'static str>` pointing to the selected indices, which will return error
+if `temperature` isn't positive.
+
+## Example
+```rust
+  use genx::selection::{boltzmann_selection, CoolingSchedule};
+  let num_parents:usize = 10;
+  let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+  let schedule = CoolingSchedule::Exponential { initial_temperature: 10.0, cooling_rate: 0.95 };
+
+  for generation in 0..5 {
+    let result = boltzmann_selection(&fitness_values, num_parents, schedule.temperature(generation), None).unwrap();
+  }
+```
+*/
+pub fn boltzmann_selection(fitness_values: &Vec<f32>, num_parents: usize, temperature: f32, seed: Option<u64>) -> Result<Vec<usize>, &'static str> {
+  check_temperature(temperature)?;
+
+  let max = fitness_values.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+  let weights = fitness_values.iter().map(|&x| ((x - max) / temperature).exp()).collect::<Vec<f32>>();
+  Ok(roulette_wheel_selection(&weights, num_parents, seed))
+}
+
+pub(crate) fn check_temperature(temperature: f32) -> Result<(), &'static str> {
+  if temperature <= 0.0 || temperature.is_nan() {
+    return Err("temperature should be positive");
+  }
+  Ok(())
+}
+
+/// Checks that the `schedule` only gives positive temperatures, apart from the underflow of exponential cooling.
+pub(crate) fn check_schedule(schedule: &CoolingSchedule) -> Result<(), &'static str> {
+  match *schedule {
+    CoolingSchedule::Exponential { initial_temperature, cooling_rate } => {
+      check_temperature(initial_temperature)?;
+      if cooling_rate <= 0.0 || cooling_rate.is_nan() {
+        return Err("cooling_rate should be positive");
+      }
+      Ok(())
+    }
+    CoolingSchedule::Linear { minimum_temperature, .. } => check_temperature(minimum_temperature),
+    CoolingSchedule::Logarithmic { initial_temperature } => check_temperature(initial_temperature),
+  }
+}
+
+/// Temperature of [`boltzmann_selection`] as a function of the generation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoolingSchedule {
+  /// T<sub>g</sub> = `initial_temperature` * `cooling_rate`<sup>g</sup>
+  Exponential { initial_temperature: f32, cooling_rate: f32 },
+  /// T<sub>g</sub> = max(`initial_temperature` - g * `cooling_rate`, `minimum_temperature`)
+  Linear { initial_temperature: f32, cooling_rate: f32, minimum_temperature: f32 },
+  /// T<sub>g</sub> = `initial_temperature` / ln(g + e)
+  Logarithmic { initial_temperature: f32 },
+}
+
+impl CoolingSchedule {
+  /// Returns the temperature to be used at the given `generation`, counting from `0`.
+  pub fn temperature(&self, generation: usize) -> f32 {
+    match *self {
+      CoolingSchedule::Exponential { initial_temperature, cooling_rate } => initial_temperature * cooling_rate.powi(generation as i32),
+      CoolingSchedule::Linear { initial_temperature, cooling_rate, minimum_temperature } => {
+        (initial_temperature - generation as f32 * cooling_rate).max(minimum_temperature)
+      }
+      CoolingSchedule::Logarithmic { initial_temperature } => initial_temperature / (generation as f32 + std::f32::consts::E).ln(),
+    }
+  }
+}
@@ -0,0 +1,50 @@
+use super::{ranked_probabilities, sample_proportional, Sampling};
+
+/**
+## Description
+Exponential Ranking Selection weighs individuals by a geometric sequence of their ranks. With `n` individuals
+ranked `0` (worst) to `n - 1` (best) and `base` c, the probability of rank i is proportional to c<sup>n - 1 - i</sup>,
+so every step down the ranking multiplies the probability by c.
+
+A `base` close to `1.0` gives almost uniform selection, while smaller values increase the selection pressure.
+Once probabilities are computed the individuals are drawn using the chosen `Sampling`.
+
+### Note
+
+- Individuals with same fitness value share the average probability of their ranks.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<Vec<usize>, &'static str>` pointing to the selected indices, which will return error
+if `base` does not lie between `0.0` (exclusive) and `1.0` (inclusive).
+
+## Example
+```rust
+  use genx::selection::{exponential_ranking_selection, Sampling};
+  let num_parents:usize = 10;
+  let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let result = exponential_ranking_selection(&fitness_values, num_parents, 0.8, Sampling::RouletteWheel, None).unwrap();
+```
+*/
+pub fn exponential_ranking_selection(
+  fitness_values: &Vec<f32>,
+  num_parents: usize,
+  base: f32,
+  sampling: Sampling,
+  seed: Option<u64>,
+) -> Result<Vec<usize>, &'static str> {
+  check_base(base)?;
+
+  let n = fitness_values.len();
+  let probabilities = ranked_probabilities(fitness_values, |rank| base.powi((n - 1 - rank) as i32));
+  Ok(sample_proportional(&probabilities, num_parents, sampling, seed))
+}
+
+pub(crate) fn check_base(base: f32) -> Result<(), &'static str> {
+  if !(base > 0.0 && base <= 1.0) {
+    return Err("base should lie between 0.0 exclusive and 1.0 inclusive");
+  }
+  Ok(())
+}
@@ -0,0 +1,60 @@
+use std::cmp::Ordering;
+use rand::{rngs::StdRng, SeedableRng, Rng};
+
+/**
+## Description
+Fitness Uniform Selection Scheme (FUSS) favours diversity instead of the fittest individuals. For each of the `num_parents`
+iterations a fitness value is drawn uniformly between the lowest and highest fitness values of the population, and the
+individual whose fitness value is nearest to it is selected.
+
+Individuals in sparsely populated regions of the fitness space are therefore selected more often than the ones
+sharing a crowded fitness level, which preserves the diversity of the population.
+
+### Note
+
+- Ties between equally near individuals are broken uniformly at random.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Vec<usize>` pointing to the selected indices.
+
+## Example
+```rust
+  use genx::selection::fitness_uniform_selection;
+  let num_parents:usize = 10;
+  let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let result = fitness_uniform_selection(&fitness_values, num_parents, None);
+```
+*/
+pub fn fitness_uniform_selection(fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+  if fitness_values.is_empty() {
+    return Vec::new();
+  }
+
+  let mut order = (0..fitness_values.len()).collect::<Vec<usize>>();
+  order.sort_by(|&a, &b| fitness_values[a].partial_cmp(&fitness_values[b]).unwrap_or(Ordering::Equal));
+  let sorted = order.iter().map(|&idx| fitness_values[idx]).collect::<Vec<f32>>();
+  let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+  let mut selected_indices:Vec<usize> = Vec::new();
+  for _ in 0..num_parents {
+    let target = min + prng.gen::<f32>() * (max - min);
+    // first individual not below the target and the last one below it are the candidates
+    let upper = sorted.partition_point(|&x| x < target).min(sorted.len() - 1);
+    let nearest = if upper > 0 && target - sorted[upper - 1] < sorted[upper] - target {
+      sorted[upper - 1]
+    } else {
+      sorted[upper]
+    };
+    let start = sorted.partition_point(|&x| x < nearest);
+    let end = sorted.partition_point(|&x| x <= nearest);
+    selected_indices.push(order[prng.gen_range(start..end.max(start + 1))]);
+  }
+  selected_indices
+}
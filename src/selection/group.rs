@@ -0,0 +1,80 @@
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use super::{stochastic_universal_selection, tournament_selection};
+
+/**
+## Description
+Tournament Group Selection selects `num_groups` groups of `group_size` parents each for multi parent
+crossovers. Every parent is the winner of a separate tournament of `tournament_size` individuals,
+held in the same way as in `tournament_selection`.
+
+_Note: The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results._
+
+## Return
+
+The return value is a `Vec<Vec<usize>>` containing the selected indices of every group.
+
+## Example
+```rust
+  use genx::selection::tournament_group_selection;
+  let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let groups = tournament_group_selection(&fitness_values, 4, 3, 2, None);
+  assert_eq!(groups.len(), 4);
+  assert!(groups.iter().all(|group| group.len() == 3));
+```
+*/
+pub fn tournament_group_selection(
+    fitness_values: &Vec<f32>,
+    num_groups: usize,
+    group_size: usize,
+    tournament_size: usize,
+    seed: Option<u64>,
+) -> Vec<Vec<usize>> {
+    let selected = tournament_selection(fitness_values, num_groups * group_size, tournament_size, seed);
+    into_groups(selected, group_size)
+}
+
+/**
+## Description
+Stochastic Universal Group Selection selects `num_groups` groups of `group_size` parents each for multi
+parent crossovers. All the parents are selected in a single spin of the wheel as in
+`stochastic_universal_selection`, then shuffled so each group mixes individuals from across the wheel.
+
+_Note: The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results._
+
+## Return
+
+The return value is a `Vec<Vec<usize>>` containing the selected indices of every group.
+
+## Example
+```rust
+  use genx::selection::stochastic_universal_group_selection;
+  let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let groups = stochastic_universal_group_selection(&fitness_values, 4, 3, None);
+  assert_eq!(groups.len(), 4);
+  assert!(groups.iter().all(|group| group.len() == 3));
+```
+*/
+pub fn stochastic_universal_group_selection(
+    fitness_values: &Vec<f32>,
+    num_groups: usize,
+    group_size: usize,
+    seed: Option<u64>,
+) -> Vec<Vec<usize>> {
+    let mut selected = stochastic_universal_selection(fitness_values, num_groups * group_size, seed);
+    let mut prng = match seed {
+        Some(val) => StdRng::seed_from_u64(val),
+        None => StdRng::from_entropy(),
+    };
+    selected.shuffle(&mut prng);
+    into_groups(selected, group_size)
+}
+
+fn into_groups(selected: Vec<usize>, group_size: usize) -> Vec<Vec<usize>> {
+    if group_size == 0 {
+        return Vec::new();
+    }
+    selected.chunks(group_size).map(|group| group.to_vec()).collect()
+}
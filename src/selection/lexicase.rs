@@ -0,0 +1,161 @@
+use std::cmp::Ordering;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/**
+## Description
+Lexicase Selection selects individuals using their errors on each of the test cases separately, instead of an aggregated
+fitness value. `errors[i][c]` is the error of the `i`th individual on the `c`th case, lower being better.
+
+For each of the `num_parents` selections the cases are shuffled and considered one at a time. Out of the remaining
+candidates only the ones with the lowest error on the current case are kept, until a single candidate is left or
+the cases run out, in which case one of the remaining candidates is chosen at random.
+
+Specialists that solve a few hard cases get selected even if their total error is high, which preserves diversity.
+
+### Note
+
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<Vec<usize>, &'static str>` pointing to the selected indices, which will return error
+if the individuals aren't evaluated on the same number of cases.
+
+## Example
+```rust
+  use genx::selection::lexicase_selection;
+  let errors = vec![vec![0.0, 3.0, 1.0], vec![2.0, 0.0, 1.0], vec![1.0, 1.0, 1.0]];
+
+  let result = lexicase_selection(&errors, 10, None).unwrap();
+```
+*/
+pub fn lexicase_selection(errors: &Vec<Vec<f32>>, num_parents: usize, seed: Option<u64>) -> Result<Vec<usize>, &'static str> {
+  let num_cases = check_errors(errors)?;
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+  let cases = (0..num_cases).collect::<Vec<usize>>();
+  Ok(select(errors, num_parents, &cases, &vec![0.0; num_cases], &mut prng))
+}
+
+/**
+## Description
+Epsilon Lexicase Selection is a variant of `lexicase_selection` for continuous errors, where exact ties on a case are rare.
+Instead of keeping only the candidates with the lowest error on a case, every candidate whose error is within ε<sub>c</sub>
+of the lowest one is kept.
+
+ε<sub>c</sub> is computed automatically for each case as the median absolute deviation of the errors of the whole
+population on that case, so no tuning is required.
+
+### Note
+
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<Vec<usize>, &'static str>` pointing to the selected indices, which will return error
+if the individuals aren't evaluated on the same number of cases.
+
+## Example
+```rust
+  use genx::selection::epsilon_lexicase_selection;
+  let errors = vec![vec![0.1, 3.2, 1.0], vec![2.5, 0.05, 1.1], vec![1.2, 1.3, 0.9]];
+
+  let result = epsilon_lexicase_selection(&errors, 10, None).unwrap();
+```
+*/
+pub fn epsilon_lexicase_selection(errors: &Vec<Vec<f32>>, num_parents: usize, seed: Option<u64>) -> Result<Vec<usize>, &'static str> {
+  let num_cases = check_errors(errors)?;
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+  let cases = (0..num_cases).collect::<Vec<usize>>();
+  let epsilons = cases.iter().map(|&case| median_absolute_deviation(errors.iter().map(|x| x[case]).collect())).collect::<Vec<f32>>();
+  Ok(select(errors, num_parents, &cases, &epsilons, &mut prng))
+}
+
+/**
+## Description
+Down-sampled Lexicase Selection is `lexicase_selection` using a random subset of the cases. A fraction `sample_rate` of the
+cases is sampled once per call and every one of the `num_parents` selections only considers those cases.
+
+Errors are usually evaluated only on the sampled cases, which saves evaluations that can be spent on more generations.
+
+### Note
+
+- At least one case is always sampled.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<Vec<usize>, &'static str>` pointing to the selected indices, which will return error
+if the individuals aren't evaluated on the same number of cases or `sample_rate` does not lie between `0.0` (exclusive) and `1.0` (inclusive).
+
+## Example
+```rust
+  use genx::selection::downsampled_lexicase_selection;
+  let errors = vec![vec![0.0, 3.0, 1.0, 2.0], vec![2.0, 0.0, 1.0, 0.0], vec![1.0, 1.0, 1.0, 1.0]];
+
+  let result = downsampled_lexicase_selection(&errors, 10, 0.5, None).unwrap();
+```
+*/
+pub fn downsampled_lexicase_selection(errors: &Vec<Vec<f32>>, num_parents: usize, sample_rate: f32, seed: Option<u64>) -> Result<Vec<usize>, &'static str> {
+  let num_cases = check_errors(errors)?;
+  if !(sample_rate > 0.0 && sample_rate <= 1.0) {
+    return Err("sample_rate should lie between 0.0 exclusive and 1.0 inclusive");
+  }
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+  let num_sampled = ((sample_rate * num_cases as f32).ceil() as usize).clamp(1.min(num_cases), num_cases);
+  let cases = (0..num_cases).collect::<Vec<usize>>().choose_multiple(&mut prng, num_sampled).cloned().collect::<Vec<usize>>();
+  Ok(select(errors, num_parents, &cases, &vec![0.0; num_cases], &mut prng))
+}
+
+fn check_errors(errors: &Vec<Vec<f32>>) -> Result<usize, &'static str> {
+  let num_cases = errors.first().map_or(0, |x| x.len());
+  if errors.iter().any(|x| x.len() != num_cases) {
+    return Err("all individuals should be evaluated on the same number of cases");
+  }
+  Ok(num_cases)
+}
+
+fn median_absolute_deviation(mut values: Vec<f32>) -> f32 {
+  let median = |values: &mut Vec<f32>| {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let mid = values.len() / 2;
+    if mid * 2 == values.len() { (values[mid - 1] + values[mid]) / 2.0 } else { values[mid] }
+  };
+  let center = median(&mut values);
+  let mut deviations = values.iter().map(|&x| (x - center).abs()).collect::<Vec<f32>>();
+  median(&mut deviations)
+}
+
+fn select(errors: &Vec<Vec<f32>>, num_parents: usize, cases: &Vec<usize>, epsilons: &Vec<f32>, prng: &mut StdRng) -> Vec<usize> {
+  if errors.is_empty() {
+    return Vec::new();
+  }
+
+  let mut order = cases.clone();
+  let mut selected_indices:Vec<usize> = Vec::new();
+  for _ in 0..num_parents {
+    order.shuffle(prng);
+    let mut candidates = (0..errors.len()).collect::<Vec<usize>>();
+    for &case in order.iter() {
+      if candidates.len() == 1 {
+        break;
+      }
+      let best = candidates.iter().map(|&idx| errors[idx][case]).fold(f32::INFINITY, f32::min);
+      let remaining = candidates.iter().cloned().filter(|&idx| errors[idx][case] <= best + epsilons[case]).collect::<Vec<usize>>();
+      // a case where every error is NaN can't tell the candidates apart
+      if !remaining.is_empty() {
+        candidates = remaining;
+      }
+    }
+    selected_indices.push(candidates[prng.gen_range(0..candidates.len())]);
+  }
+  selected_indices
+}
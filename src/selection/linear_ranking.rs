@@ -0,0 +1,57 @@
+use super::{ranked_probabilities, sample_proportional, Sampling};
+
+/**
+## Description
+Linear Ranking Selection is Baker's ranking scheme where the probability of selecting an individual
+grows linearly with its rank. With `n` individuals ranked `0` (worst) to `n - 1` (best) and
+`selection_pressure` s, the probability of rank i is:
+    p<sub>i</sub> = (2 - s)/n + 2i(s - 1)/(n(n - 1))
+
+The best individual is thus expected to be selected s times as often as the average one, from no
+preference at all for `1.0` up to never selecting the worst individual for `2.0`.
+Once probabilities are computed the individuals are drawn using the chosen `Sampling`.
+
+### Note
+
+- Individuals with same fitness value share the average probability of their ranks.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<Vec<usize>, &'static str>` pointing to the selected indices, which will return error
+if `selection_pressure` does not lie between `1.0` and `2.0`.
+
+## Example
+```rust
+  use genx::selection::{linear_ranking_selection, Sampling};
+  let num_parents:usize = 10;
+  let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let result = linear_ranking_selection(&fitness_values, num_parents, 1.5, Sampling::StochasticUniversal, None).unwrap();
+```
+*/
+pub fn linear_ranking_selection(
+  fitness_values: &Vec<f32>,
+  num_parents: usize,
+  selection_pressure: f32,
+  sampling: Sampling,
+  seed: Option<u64>,
+) -> Result<Vec<usize>, &'static str> {
+  check_selection_pressure(selection_pressure)?;
+
+  let n = fitness_values.len() as f32;
+  let probabilities = ranked_probabilities(fitness_values, |rank| {
+    if n <= 1.0 {
+      return 1.0;
+    }
+    (2.0 - selection_pressure) / n + 2.0 * rank as f32 * (selection_pressure - 1.0) / (n * (n - 1.0))
+  });
+  Ok(sample_proportional(&probabilities, num_parents, sampling, seed))
+}
+
+pub(crate) fn check_selection_pressure(selection_pressure: f32) -> Result<(), &'static str> {
+  if !(1.0..=2.0).contains(&selection_pressure) {
+    return Err("selection_pressure should lie between 1.0 and 2.0 inclusive");
+  }
+  Ok(())
+}
@@ -4,36 +4,157 @@
 //!
 //! The provided functions are organized in sub-modules
 //! named after the utilized selection method:
+//! * `boltzmann`
+//! * `fitness_uniform`
+//! * `lexicase`
 //! * `random`
 //! * `rank`
+//! * `linear_ranking`
+//! * `exponential_ranking`
 //! * `roulette_wheel`
 //! * `steady_state`
 //! * `stochastic_universal`
 //! * `tournament`
+//! * `tournament_without_replacement`
+//! * `probabilistic_tournament`
+//! * `binary_tournament`
+//! * `truncation`
 //!
 //! All the functions take in atleast two arguments a Vector of floating
 //! point values that contains fitness values of individuals, and
 //! number of individuals to select. Finally functions return indices of
 //! selected individuals.
 //!
+//! Ranking based selections with adjustable pressure draw the individuals
+//! according to a [`Sampling`] scheme, either independent spins of a roulette
+//! wheel or a single spin of stochastic universal sampling.
+//!
+//! Selections maximize the fitness values, and the proportional ones also
+//! need them to be non negative. Costs, or fitness values of any sign, are
+//! converted using `transform_fitness` with an [`Objective`] and a
+//! [`FitnessTransform`], or directly through `Selection::select_with`,
+//! `roulette_wheel_selection_with` and `stochastic_universal_selection_with`
+//! which return errors for values proportional selection can't use.
+//!
+//! Lexicase selections instead take in a matrix of errors of every
+//! individual on each test case, with lower errors being better.
+//!
+//! When many individuals are drawn from the same fitness values, a
+//! [`ProportionalSampler`] can be prepared once and reused for every draw.
+//!
+//! Every fitness based scheme is also available as a struct implementing the [`Selection`]
+//! trait from `strategy`, so the scheme can be chosen at runtime.
+//!
+//! Multi parent crossovers need groups of parents, which are selected
+//! with the functions in `group` returning a `Vec<Vec<usize>>` of indices.
+//!
 //! You can read more about selection schemas and their working from the [wikipedia page](https://en.wikipedia.org/wiki/Selection_(genetic_algorithm))
 
+pub mod binary_tournament;
+
+pub mod boltzmann;
+
+pub mod exponential_ranking;
+
+pub mod fitness_uniform;
+
+pub mod group;
+
+pub mod lexicase;
+
+pub mod linear_ranking;
+
+pub mod objective;
+
+pub mod probabilistic_tournament;
+
 pub mod random;
 
 pub mod rank;
 
 pub mod roulette_wheel;
 
+pub mod sampler;
+
 pub mod steady_state;
 
 pub mod stochastic_universal;
 
+pub mod strategy;
+
 pub mod tournament;
 
+pub mod tournament_without_replacement;
+
+pub mod truncation;
+
+use std::cmp::Ordering;
+
 // Re-exports
+pub use self::binary_tournament::binary_tournament_selection;
+pub use self::boltzmann::{boltzmann_selection, CoolingSchedule};
+pub use self::exponential_ranking::exponential_ranking_selection;
+pub use self::fitness_uniform::fitness_uniform_selection;
+pub use self::group::{stochastic_universal_group_selection, tournament_group_selection};
+pub use self::lexicase::{downsampled_lexicase_selection, epsilon_lexicase_selection, lexicase_selection};
+pub use self::linear_ranking::linear_ranking_selection;
+pub use self::objective::{check_proportional_fitness, transform_fitness, FitnessTransform, Objective};
+pub use self::probabilistic_tournament::probabilistic_tournament_selection;
 pub use self::random::random_selection;
 pub use self::rank::rank_selection;
-pub use self::roulette_wheel::roulette_wheel_selection;
+pub use self::roulette_wheel::{roulette_wheel_selection, roulette_wheel_selection_with};
+pub use self::sampler::ProportionalSampler;
 pub use self::steady_state::steady_state_selection;
-pub use self::stochastic_universal::stochastic_universal_selection;
+pub use self::stochastic_universal::{stochastic_universal_selection, stochastic_universal_selection_with};
+pub use self::strategy::{
+  BinaryTournamentSelection, BoltzmannSelection, ExponentialRankingSelection, FitnessUniformSelection,
+  LinearRankingSelection, ProbabilisticTournamentSelection, RandomSelection, RankSelection, RouletteWheelSelection,
+  Selection, SteadyStateSelection, StochasticUniversalSelection, TournamentSelection,
+  TournamentWithoutReplacementSelection, TruncationSelection,
+};
 pub use self::tournament::tournament_selection;
+pub use self::tournament_without_replacement::tournament_without_replacement_selection;
+pub use self::truncation::truncation_selection;
+
+/// Scheme used to draw individuals once their selection probabilities are known.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sampling {
+  /// Independent spins of the wheel, as in `roulette_wheel_selection`.
+  RouletteWheel,
+  /// A single spin with equally spaced pointers, as in `stochastic_universal_selection`.
+  StochasticUniversal,
+}
+
+fn sample_proportional(probabilities: &Vec<f32>, num_parents: usize, sampling: Sampling, seed: Option<u64>) -> Vec<usize> {
+  // there is nothing to draw, and the pointers of a stochastic universal spin would be spaced by 0 or infinity
+  if probabilities.is_empty() || num_parents == 0 {
+    return Vec::new();
+  }
+  match sampling {
+    Sampling::RouletteWheel => roulette_wheel_selection(probabilities, num_parents, seed),
+    Sampling::StochasticUniversal => stochastic_universal_selection(probabilities, num_parents, seed),
+  }
+}
+
+/// Assigns every individual the weight of its rank (`0` for the worst), averaging weights across ties.
+fn ranked_probabilities<F: Fn(usize) -> f32>(fitness_values: &Vec<f32>, weight: F) -> Vec<f32> {
+  let mut order = (0..fitness_values.len()).collect::<Vec<usize>>();
+  order.sort_by(|&a, &b| fitness_values[a].partial_cmp(&fitness_values[b]).unwrap_or(Ordering::Equal));
+
+  let mut probabilities = vec![0.0; fitness_values.len()];
+  let mut start = 0;
+  while start < order.len() {
+    let mut end = start + 1;
+    while end < order.len() && fitness_values[order[end]] == fitness_values[order[start]] {
+      end += 1;
+    }
+    let average = (start..end).map(&weight).sum::<f32>() / (end - start) as f32;
+    for &idx in order[start..end].iter() {
+      probabilities[idx] = average;
+    }
+    start = end;
+  }
+
+  let sum = probabilities.iter().sum::<f32>();
+  probabilities.iter().map(|&x| x / sum).collect()
+}
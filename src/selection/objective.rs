@@ -0,0 +1,121 @@
+/**
+## Description
+`Objective` tells whether the values passed to the selections should be maximized, like fitness values,
+or minimized, like costs or errors. Use it along with a [`FitnessTransform`] in `transform_fitness` or `Selection::select_with`.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Objective {
+  Maximize,
+  Minimize,
+}
+
+/**
+## Description
+`FitnessTransform` maps values of any sign into fitness values where higher is better, which proportional selections
+such as `roulette_wheel_selection` and `stochastic_universal_selection` need to be non negative with a positive sum.
+
+With f<sub>min</sub> and f<sub>max</sub> the lowest and highest values, each value f is mapped to:
+
+| Transform | `Objective::Maximize` | `Objective::Minimize` |
+|-----------|-----------------------|-----------------------|
+| `Identity` | f | -f |
+| `Windowing` | f - f<sub>min</sub> | f<sub>max</sub> - f |
+| `Inverse` | error | 1 / (1 + f), requires f ≥ 0 |
+| `Offset(c)` | f + c | c - f |
+
+`Identity` keeps the order of the values only, which is enough for the ranking and tournament based selections.
+When all the values are equal, as in a converged population, `Windowing` maps every value to `1.0` instead, so that the
+individuals stay equally likely to be selected.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FitnessTransform {
+  Identity,
+  Windowing,
+  Inverse,
+  Offset(f32),
+}
+
+/**
+## Description
+Transforms `values` according to the `objective` and `transform`, so that higher transformed values are always better.
+Refer to [`FitnessTransform`] for the applied mapping.
+
+## Return
+
+The return value is a `Result<Vec<f32>, &'static str>` containing the transformed values, which will return error if a
+value isn't finite, if `FitnessTransform::Inverse` is used to maximize or on negative values, or if the offset is too small
+to make every value non negative.
+
+## Example
+```rust
+  use genx::selection::{roulette_wheel_selection, transform_fitness, FitnessTransform, Objective};
+  let costs = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let fitness_values = transform_fitness(&costs, Objective::Minimize, FitnessTransform::Inverse).unwrap();
+  let result = roulette_wheel_selection(&fitness_values, 10, None);
+```
+*/
+pub fn transform_fitness(values: &Vec<f32>, objective: Objective, transform: FitnessTransform) -> Result<Vec<f32>, &'static str> {
+  if values.iter().any(|x| !x.is_finite()) {
+    return Err("fitness values should be finite");
+  }
+  let min = values.iter().cloned().fold(f32::INFINITY, f32::min);
+  let max = values.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+
+  let transformed = match (transform, objective) {
+    (FitnessTransform::Identity, Objective::Maximize) => values.clone(),
+    (FitnessTransform::Identity, Objective::Minimize) => values.iter().map(|&x| -x).collect(),
+    (FitnessTransform::Windowing, _) if max == min => vec![1.0; values.len()],
+    (FitnessTransform::Windowing, Objective::Maximize) => values.iter().map(|&x| x - min).collect(),
+    (FitnessTransform::Windowing, Objective::Minimize) => values.iter().map(|&x| max - x).collect(),
+    (FitnessTransform::Inverse, Objective::Maximize) => return Err("inverse transform can only be used to minimize"),
+    (FitnessTransform::Inverse, Objective::Minimize) => {
+      if min < 0.0 {
+        return Err("inverse transform requires non negative values");
+      }
+      values.iter().map(|&x| 1.0 / (1.0 + x)).collect()
+    }
+    (FitnessTransform::Offset(offset), objective) => {
+      let transformed = match objective {
+        Objective::Maximize => values.iter().map(|&x| x + offset).collect::<Vec<f32>>(),
+        Objective::Minimize => values.iter().map(|&x| offset - x).collect::<Vec<f32>>(),
+      };
+      if transformed.iter().any(|&x| x < 0.0) {
+        return Err("offset is too small to make every value non negative");
+      }
+      transformed
+    }
+  };
+  Ok(transformed)
+}
+
+/**
+## Description
+Checks that `fitness_values` can be used by the proportional selections, `roulette_wheel_selection`,
+`stochastic_universal_selection` and `ProportionalSampler`, which silently misbehave otherwise.
+
+## Return
+
+The return value is a `Result<(), &'static str>` which will return error if `fitness_values` is empty, contains values
+which are negative or not finite, or only contains zeros.
+
+## Example
+```rust
+  use genx::selection::check_proportional_fitness;
+
+  assert!(check_proportional_fitness(&vec![1.0, 0.0, 2.5]).is_ok());
+  assert!(check_proportional_fitness(&vec![1.0, -2.0]).is_err());
+```
+*/
+pub fn check_proportional_fitness(fitness_values: &Vec<f32>) -> Result<(), &'static str> {
+  if fitness_values.is_empty() {
+    return Err("fitness values should not be empty");
+  }
+  if fitness_values.iter().any(|&x| !x.is_finite() || x < 0.0) {
+    return Err("fitness values should be finite and non negative for proportional selection");
+  }
+  if fitness_values.iter().all(|&x| x == 0.0) {
+    return Err("fitness values should not be all zero for proportional selection");
+  }
+  Ok(())
+}
@@ -0,0 +1,61 @@
+use std::cmp::{min, Ordering};
+use rand::{rngs::StdRng, seq::index::sample, Rng, SeedableRng};
+
+/**
+## Description
+Probabilistic Tournament Selection is `tournament_selection` where the fittest individual of a tournament doesn't
+always win. The participants of a tournament are sorted by their fitness values, and the fittest one is selected with
+`probability` p, the second one with p(1 - p), the third one with p(1 - p)<sup>2</sup> and so on, with the least fit
+participant getting the remaining probability.
+
+Lowering `probability` below `1.0` reduces the selection pressure and lets weaker individuals pass on their genes.
+
+### Note
+
+- If `tournament_size` is greater than length of `fitness_values` vector (n), then every tournament contains all the individuals.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<Vec<usize>, &'static str>` pointing to the selected indices, which will return error
+if `probability` does not lie between `0.0` (exclusive) and `1.0` (inclusive).
+
+## Example
+```rust
+  use genx::selection::probabilistic_tournament_selection;
+  let num_parents:usize = 10;
+  let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let result = probabilistic_tournament_selection(&fitness_values, num_parents, 4, 0.8, None).unwrap();
+```
+*/
+pub fn probabilistic_tournament_selection(fitness_values: &Vec<f32>, num_parents: usize, tournament_size: usize, probability: f32, seed: Option<u64>) -> Result<Vec<usize>, &'static str> {
+  check_probability(probability)?;
+  if fitness_values.is_empty() {
+    return Ok(Vec::new());
+  }
+  let tournament_size = min(tournament_size.max(1), fitness_values.len());
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+
+  let mut selected_indices:Vec<usize> = Vec::new();
+  for _ in 0..num_parents {
+    let mut tournament = sample(&mut prng, fitness_values.len(), tournament_size).into_vec();
+    tournament.sort_by(|&a, &b| fitness_values[b].partial_cmp(&fitness_values[a]).unwrap_or(Ordering::Equal));
+    let mut place = 0;
+    while place + 1 < tournament_size && !prng.gen_bool(probability as f64) {
+      place += 1;
+    }
+    selected_indices.push(tournament[place]);
+  }
+  Ok(selected_indices)
+}
+
+pub(crate) fn check_probability(probability: f32) -> Result<(), &'static str> {
+  if !(probability > 0.0 && probability <= 1.0) {
+    return Err("probability should lie between 0.0 exclusive and 1.0 inclusive");
+  }
+  Ok(())
+}
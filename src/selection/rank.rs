@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 use rand::{rngs::StdRng, SeedableRng, Rng};
+use super::sampler::{cumulative_probabilities, search_cumulative};
 
 /**
 ## Description
@@ -26,13 +27,15 @@ The return value is a `Vec<usize>` pointing to the selected indices.
 ```
 */
 pub fn rank_selection(fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+  if fitness_values.is_empty() {
+    return Vec::new();
+  }
   let mut fitness_values_with_index : Vec<(f32, usize)> = Vec::new();
   for (i, &value) in fitness_values.iter().enumerate() {
       fitness_values_with_index.push((value, i));
   };
   fitness_values_with_index.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
   let mut ranks:Vec<i32> = vec![1];
-  let mut sum_of_fitness = 1;
   for (i, _) in fitness_values_with_index.iter().enumerate() {
       if i == 0 {
           continue;
@@ -43,25 +46,12 @@ pub fn rank_selection(fitness_values: &Vec<f32>, num_parents: usize, seed: Optio
       else {
           ranks.push(ranks[i-1] + 1);
       }
-      sum_of_fitness += ranks[i];
   };
-  let normalized_probabilities = ranks.iter().map(|&a| (a as f32)/(sum_of_fitness as f32)).collect::<Vec<f32>>();
+  let cumulative = cumulative_probabilities(&ranks.iter().map(|&a| a as f32).collect());
 
   let mut prng = match seed {
     Some(val) => StdRng::seed_from_u64(val),
     None => StdRng::from_entropy()
   };
-  let mut selected_indices:Vec<usize> = Vec::new();
-  for _ in 0..num_parents {
-      let val = prng.gen();
-      let mut cummulative_probability = 0f32;
-      for (i, &(_, idx)) in fitness_values_with_index.iter().enumerate() {
-          cummulative_probability += normalized_probabilities[i];
-          if cummulative_probability >= val {
-              selected_indices.push(idx);
-              break;
-          }
-      };
-  };
-  selected_indices
+  (0..num_parents).map(|_| fitness_values_with_index[search_cumulative(&cumulative, prng.gen())].1).collect()
 }
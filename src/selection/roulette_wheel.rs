@@ -1,4 +1,6 @@
 use rand::{rngs::StdRng, SeedableRng, Rng};
+use super::{check_proportional_fitness, transform_fitness, FitnessTransform, Objective};
+use super::sampler::{cumulative_probabilities, search_cumulative};
 
 /**
 ## Description
@@ -9,6 +11,8 @@ For each of the `num_parents` iterations a random number between `0.0..1.0` is g
 ### Note
 
 - Individuals with same fitness value occupy same area on roulette wheel.
+- Fitness values should be non negative with a positive sum, otherwise the selected indices are meaningless. Use
+  `roulette_wheel_selection_with` to select using costs or fitness values of any sign, with errors for invalid values.
 - The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
 
 ## Return
@@ -25,24 +29,43 @@ The return value is a `Vec<usize>` pointing to the selected indices.
 ```
 */
 pub fn roulette_wheel_selection(fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
-  let sum_of_fitness = fitness_values.iter().sum::<f32>();
-  let normalized_probabilities = fitness_values.iter().map(|&a| a/sum_of_fitness).collect::<Vec<f32>>();
+  if fitness_values.is_empty() {
+    return Vec::new();
+  }
+  let cumulative = cumulative_probabilities(fitness_values);
 
   let mut prng = match seed {
     Some(val) => StdRng::seed_from_u64(val),
     None => StdRng::from_entropy()
   };
-  let mut selected_indices:Vec<usize> = Vec::new();
-  for _ in 0..num_parents {
-      let val = prng.gen();
-      let mut cummulative_probability = 0f32;
-      for (index, _) in fitness_values.iter().enumerate() {
-          cummulative_probability += normalized_probabilities[index];
-          if cummulative_probability >= val {
-              selected_indices.push(index);
-              break;
-          }
-      };
-  };
-  selected_indices
+  (0..num_parents).map(|_| search_cumulative(&cumulative, prng.gen())).collect()
+}
+
+/**
+## Description
+Roulette Wheel Selection of values to maximize or minimize, which are first transformed into fitness values by
+`transform_fitness` and checked by `check_proportional_fitness`.
+
+### Note
+
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<Vec<usize>, &'static str>` pointing to the selected indices, which will return error if the
+`transform` can't be applied to `values` or doesn't map them to non negative fitness values with a positive sum.
+
+## Example
+```rust
+  use genx::selection::{roulette_wheel_selection_with, FitnessTransform, Objective};
+  let costs = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let result = roulette_wheel_selection_with(&costs, 10, Objective::Minimize, FitnessTransform::Inverse, None).unwrap();
+  assert!(roulette_wheel_selection_with(&costs, 10, Objective::Minimize, FitnessTransform::Identity, None).is_err());
+```
+*/
+pub fn roulette_wheel_selection_with(values: &Vec<f32>, num_parents: usize, objective: Objective, transform: FitnessTransform, seed: Option<u64>) -> Result<Vec<usize>, &'static str> {
+  let fitness_values = transform_fitness(values, objective, transform)?;
+  check_proportional_fitness(&fitness_values)?;
+  Ok(roulette_wheel_selection(&fitness_values, num_parents, seed))
 }
@@ -0,0 +1,132 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::check_proportional_fitness;
+
+/**
+## Description
+`ProportionalSampler` draws indices with probability proportional to their weights, like `roulette_wheel_selection`,
+but does all the preparation work once so that it can be reused for any number of draws, e.g. once per generation.
+
+Two methods of sampling are available:
+- `ProportionalSampler::alias` builds a table using Walker's alias method in O(n), after which every draw takes O(1).
+- `ProportionalSampler::cdf` builds the cumulative distribution in O(n), after which every draw is a binary search taking O(log n).
+
+The latter uses a single random number per draw and selects exactly the same individuals as `roulette_wheel_selection`.
+
+### Note
+
+- Weights should be finite and non negative with a positive sum, use fitness scaling for other fitness values.
+
+## Example
+```rust
+  use genx::selection::ProportionalSampler;
+  use rand::{rngs::StdRng, SeedableRng};
+  let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let sampler = ProportionalSampler::alias(&fitness_values).unwrap();
+  let mut prng = StdRng::seed_from_u64(42);
+  let first = sampler.sample(&mut prng);
+  let result = sampler.select(10, None);
+```
+*/
+#[derive(Clone, Debug)]
+pub struct ProportionalSampler {
+  method: Method,
+}
+
+#[derive(Clone, Debug)]
+enum Method {
+  Alias { probability: Vec<f64>, alias: Vec<usize> },
+  Cdf { cumulative: Vec<f32> },
+}
+
+impl ProportionalSampler {
+  /// Prepares an alias table, returns error if the weights are empty, negative, not finite or all zero.
+  pub fn alias(weights: &Vec<f32>) -> Result<Self, &'static str> {
+    check_proportional_fitness(weights)?;
+    let n = weights.len();
+    let sum = weights.iter().map(|&x| x as f64).sum::<f64>();
+    let mut probability = weights.iter().map(|&x| x as f64 * n as f64 / sum).collect::<Vec<f64>>();
+    let mut alias = (0..n).collect::<Vec<usize>>();
+
+    // Vose's variant, every column below the average is topped up by one above it
+    let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&idx| probability[idx] < 1.0);
+    while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+      small.pop();
+      alias[less] = more;
+      probability[more] -= 1.0 - probability[less];
+      if probability[more] < 1.0 {
+        large.pop();
+        small.push(more);
+      }
+    }
+    // leftovers are only off from 1.0 by rounding errors
+    for idx in small.into_iter().chain(large) {
+      probability[idx] = 1.0;
+    }
+    Ok(ProportionalSampler { method: Method::Alias { probability, alias } })
+  }
+
+  /// Prepares a cumulative distribution, returns error if the weights are empty, negative, not finite or all zero.
+  pub fn cdf(weights: &Vec<f32>) -> Result<Self, &'static str> {
+    check_proportional_fitness(weights)?;
+    Ok(ProportionalSampler { method: Method::Cdf { cumulative: cumulative_probabilities(weights) } })
+  }
+
+  /// Number of weights the sampler draws from.
+  pub fn len(&self) -> usize {
+    match &self.method {
+      Method::Alias { probability, .. } => probability.len(),
+      Method::Cdf { cumulative } => cumulative.len(),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Draws a single index using the given random number generator.
+  pub fn sample<R: Rng + ?Sized>(&self, prng: &mut R) -> usize {
+    match &self.method {
+      Method::Alias { probability, alias } => {
+        let idx = prng.gen_range(0..probability.len());
+        if prng.gen::<f64>() < probability[idx] { idx } else { alias[idx] }
+      }
+      Method::Cdf { cumulative } => search_cumulative(cumulative, prng.gen()),
+    }
+  }
+
+  /**
+  Draws `num_parents` indices independently, which can also take in an optional `seed` value of type `Option<u64>`
+  for deterministic results.
+  */
+  pub fn select(&self, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    let mut prng = match seed {
+      Some(val) => StdRng::seed_from_u64(val),
+      None => StdRng::from_entropy()
+    };
+    (0..num_parents).map(|_| self.sample(&mut prng)).collect()
+  }
+}
+
+/// Running sum of the normalized `weights`, accumulated in order.
+pub(crate) fn cumulative_probabilities(weights: &Vec<f32>) -> Vec<f32> {
+  let sum = weights.iter().sum::<f32>();
+  let mut cummulative_probability = 0f32;
+  weights.iter().map(|&x| {
+    cummulative_probability += x / sum;
+    cummulative_probability
+  }).collect()
+}
+
+/**
+First index whose cumulative probability exceeds `val`, which never is an index with a zero weight. If rounding left
+the total short of `val`, the last index with a non-zero weight is returned instead.
+*/
+pub(crate) fn search_cumulative(cumulative: &Vec<f32>, val: f32) -> usize {
+  let idx = cumulative.partition_point(|&x| x <= val);
+  if idx < cumulative.len() {
+    return idx;
+  }
+  (1..cumulative.len()).rev().find(|&idx| cumulative[idx] > cumulative[idx - 1]).unwrap_or(0)
+}
@@ -1,5 +1,7 @@
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
+use super::{check_proportional_fitness, transform_fitness, FitnessTransform, Objective};
+
 /**
 ## Description
 Stochastic Universal Selection/Sampling is also a proportionate selection method very much like roulette wheel selection. The major differences is that it selects the required number of individuals (`num_parents`) in a single spin of the wheel which allows for population diversity.
@@ -9,6 +11,8 @@ To know more about stochastic universal sampling refer the [wikipedia page](http
 ### Note
 
 - Individuals with same fitness value occupy same area on roulette wheel.
+- Fitness values should be non negative with a positive sum, otherwise the function may panic. Use
+  `stochastic_universal_selection_with` to select using costs or fitness values of any sign, with errors for invalid values.
 - The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
 
 ## Return
@@ -60,3 +64,35 @@ pub fn stochastic_universal_selection(
 
     selected_indices
 }
+
+/**
+## Description
+Stochastic Universal Selection of values to maximize or minimize, which are first transformed into fitness values by
+`transform_fitness` and checked by `check_proportional_fitness`.
+
+### Note
+
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<Vec<usize>, &'static str>` pointing to the selected indices, which will return error if the
+`transform` can't be applied to `values` or doesn't map them to non negative fitness values with a positive sum.
+
+## Example
+```rust
+  use genx::selection::{stochastic_universal_selection_with, FitnessTransform, Objective};
+  let costs = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let result = stochastic_universal_selection_with(&costs, 10, Objective::Minimize, FitnessTransform::Windowing, None).unwrap();
+  assert!(stochastic_universal_selection_with(&costs, 10, Objective::Minimize, FitnessTransform::Identity, None).is_err());
+```
+*/
+pub fn stochastic_universal_selection_with(values: &Vec<f32>, num_parents: usize, objective: Objective, transform: FitnessTransform, seed: Option<u64>) -> Result<Vec<usize>, &'static str> {
+  let fitness_values = transform_fitness(values, objective, transform)?;
+  check_proportional_fitness(&fitness_values)?;
+  if num_parents == 0 {
+    return Ok(Vec::new());
+  }
+  Ok(stochastic_universal_selection(&fitness_values, num_parents, seed))
+}
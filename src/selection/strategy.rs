@@ -0,0 +1,289 @@
+use super::{
+  boltzmann::check_temperature, exponential_ranking::check_base, linear_ranking::check_selection_pressure,
+  probabilistic_tournament::check_probability, truncation::check_fraction,
+};
+use super::{
+  check_proportional_fitness, transform_fitness, FitnessTransform, Objective,
+  binary_tournament_selection, probabilistic_tournament_selection, tournament_without_replacement_selection,
+  boltzmann_selection, exponential_ranking_selection, fitness_uniform_selection, linear_ranking_selection, random_selection,
+  rank_selection, roulette_wheel_selection, steady_state_selection, stochastic_universal_selection, tournament_selection,
+  truncation_selection, Sampling,
+};
+
+/**
+## Description
+`Selection` is implemented by every selection scheme of this module, so that the scheme used by a genetic
+algorithm can be chosen at runtime or passed around as a generic parameter. Its `select` method takes the
+same arguments as the selection functions, with the scheme specific parameters stored in the implementing struct.
+
+Parameters are validated when constructing the structs, so `select` itself never fails. The provided `select_with`
+method first converts the values using an [`Objective`] and a [`FitnessTransform`], and checks them for the
+proportional schemes, for which `is_proportional` returns `true`.
+
+## Example
+```rust
+use genx::selection::{FitnessTransform, Objective, RouletteWheelSelection, Selection, TournamentSelection, TruncationSelection};
+
+fn parents<S: Selection>(selection: &S, fitness_values: &Vec<f32>) -> Vec<usize> {
+    selection.select(fitness_values, 4, None)
+}
+
+let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+let result = parents(&TournamentSelection::new(3), &fitness_values);
+let result = parents(&TruncationSelection::new(0.5).unwrap(), &fitness_values);
+
+let costs = vec![3.0, -1.0, 2.5, 0.0];
+let result = TournamentSelection::new(2).select_with(&costs, 4, Objective::Minimize, FitnessTransform::Identity, None).unwrap();
+let result = RouletteWheelSelection.select_with(&costs, 4, Objective::Minimize, FitnessTransform::Windowing, None).unwrap();
+
+let schemes: Vec<Box<dyn Selection>> = vec![Box::new(TournamentSelection::new(3)), Box::new(TruncationSelection::new(0.5).unwrap())];
+```
+ */
+pub trait Selection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize>;
+
+  /// Whether the scheme needs non negative fitness values with a positive sum.
+  fn is_proportional(&self) -> bool {
+    false
+  }
+
+  /// Selects using `values` transformed by `transform_fitness`, returning error if they can't be used by the scheme.
+  fn select_with(&self, values: &Vec<f32>, num_parents: usize, objective: Objective, transform: FitnessTransform, seed: Option<u64>) -> Result<Vec<usize>, &'static str> {
+    let fitness_values = transform_fitness(values, objective, transform)?;
+    if self.is_proportional() {
+      check_proportional_fitness(&fitness_values)?;
+    }
+    Ok(self.select(&fitness_values, num_parents, seed))
+  }
+}
+
+/// `random_selection` as a [`Selection`], the population size is the number of fitness values.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RandomSelection;
+
+impl Selection for RandomSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    random_selection(fitness_values.len(), num_parents, seed)
+  }
+}
+
+/// `rank_selection` as a [`Selection`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RankSelection;
+
+impl Selection for RankSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    rank_selection(fitness_values, num_parents, seed)
+  }
+}
+
+/// `roulette_wheel_selection` as a [`Selection`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RouletteWheelSelection;
+
+impl Selection for RouletteWheelSelection {
+  fn is_proportional(&self) -> bool {
+    true
+  }
+
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    roulette_wheel_selection(fitness_values, num_parents, seed)
+  }
+}
+
+/// `steady_state_selection` as a [`Selection`], the `seed` is ignored as the selection is deterministic.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SteadyStateSelection;
+
+impl Selection for SteadyStateSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, _seed: Option<u64>) -> Vec<usize> {
+    steady_state_selection(fitness_values, num_parents)
+  }
+}
+
+/// `stochastic_universal_selection` as a [`Selection`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StochasticUniversalSelection;
+
+impl Selection for StochasticUniversalSelection {
+  fn is_proportional(&self) -> bool {
+    true
+  }
+
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    // a single spin has no pointers to space out
+    if num_parents == 0 {
+      return Vec::new();
+    }
+    stochastic_universal_selection(fitness_values, num_parents, seed)
+  }
+}
+
+/// `tournament_selection` as a [`Selection`].
+#[derive(Clone, Copy, Debug)]
+pub struct TournamentSelection {
+  pub tournament_size: usize,
+}
+
+impl TournamentSelection {
+  pub fn new(tournament_size: usize) -> Self {
+    TournamentSelection { tournament_size }
+  }
+}
+
+impl Selection for TournamentSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    tournament_selection(fitness_values, num_parents, self.tournament_size, seed)
+  }
+}
+
+/// `tournament_without_replacement_selection` as a [`Selection`].
+#[derive(Clone, Copy, Debug)]
+pub struct TournamentWithoutReplacementSelection {
+  pub tournament_size: usize,
+}
+
+impl TournamentWithoutReplacementSelection {
+  pub fn new(tournament_size: usize) -> Self {
+    TournamentWithoutReplacementSelection { tournament_size }
+  }
+}
+
+impl Selection for TournamentWithoutReplacementSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    tournament_without_replacement_selection(fitness_values, num_parents, self.tournament_size, seed)
+  }
+}
+
+/// `probabilistic_tournament_selection` as a [`Selection`].
+#[derive(Clone, Copy, Debug)]
+pub struct ProbabilisticTournamentSelection {
+  tournament_size: usize,
+  probability: f32,
+}
+
+impl ProbabilisticTournamentSelection {
+  /// Returns error if `probability` does not lie between `0.0` (exclusive) and `1.0` (inclusive).
+  pub fn new(tournament_size: usize, probability: f32) -> Result<Self, &'static str> {
+    check_probability(probability)?;
+    Ok(ProbabilisticTournamentSelection { tournament_size, probability })
+  }
+}
+
+impl Selection for ProbabilisticTournamentSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    probabilistic_tournament_selection(fitness_values, num_parents, self.tournament_size, self.probability, seed).unwrap()
+  }
+}
+
+/// `binary_tournament_selection` as a [`Selection`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BinaryTournamentSelection;
+
+impl Selection for BinaryTournamentSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    binary_tournament_selection(fitness_values, num_parents, seed)
+  }
+}
+
+/// `linear_ranking_selection` as a [`Selection`].
+#[derive(Clone, Copy, Debug)]
+pub struct LinearRankingSelection {
+  selection_pressure: f32,
+  sampling: Sampling,
+}
+
+impl LinearRankingSelection {
+  /// Returns error if `selection_pressure` does not lie between `1.0` and `2.0`.
+  pub fn new(selection_pressure: f32, sampling: Sampling) -> Result<Self, &'static str> {
+    check_selection_pressure(selection_pressure)?;
+    Ok(LinearRankingSelection { selection_pressure, sampling })
+  }
+}
+
+impl Selection for LinearRankingSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    linear_ranking_selection(fitness_values, num_parents, self.selection_pressure, self.sampling, seed).unwrap()
+  }
+}
+
+/// `exponential_ranking_selection` as a [`Selection`].
+#[derive(Clone, Copy, Debug)]
+pub struct ExponentialRankingSelection {
+  base: f32,
+  sampling: Sampling,
+}
+
+impl ExponentialRankingSelection {
+  /// Returns error if `base` does not lie between `0.0` (exclusive) and `1.0` (inclusive).
+  pub fn new(base: f32, sampling: Sampling) -> Result<Self, &'static str> {
+    check_base(base)?;
+    Ok(ExponentialRankingSelection { base, sampling })
+  }
+}
+
+impl Selection for ExponentialRankingSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    exponential_ranking_selection(fitness_values, num_parents, self.base, self.sampling, seed).unwrap()
+  }
+}
+
+/// `truncation_selection` as a [`Selection`].
+#[derive(Clone, Copy, Debug)]
+pub struct TruncationSelection {
+  fraction: f32,
+}
+
+impl TruncationSelection {
+  /// Returns error if `fraction` does not lie between `0.0` (exclusive) and `1.0` (inclusive).
+  pub fn new(fraction: f32) -> Result<Self, &'static str> {
+    check_fraction(fraction)?;
+    Ok(TruncationSelection { fraction })
+  }
+}
+
+impl Selection for TruncationSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    truncation_selection(fitness_values, num_parents, self.fraction, seed).unwrap()
+  }
+}
+
+/// `boltzmann_selection` as a [`Selection`] at a fixed temperature, use `set_temperature` to follow a cooling schedule.
+#[derive(Clone, Copy, Debug)]
+pub struct BoltzmannSelection {
+  temperature: f32,
+}
+
+impl BoltzmannSelection {
+  /// Returns error if `temperature` isn't positive.
+  pub fn new(temperature: f32) -> Result<Self, &'static str> {
+    check_temperature(temperature)?;
+    Ok(BoltzmannSelection { temperature })
+  }
+
+  pub fn temperature(&self) -> f32 {
+    self.temperature
+  }
+
+  /// Returns error if `temperature` isn't positive, keeping the previous temperature.
+  pub fn set_temperature(&mut self, temperature: f32) -> Result<(), &'static str> {
+    *self = BoltzmannSelection::new(temperature)?;
+    Ok(())
+  }
+}
+
+impl Selection for BoltzmannSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    boltzmann_selection(fitness_values, num_parents, self.temperature, seed).unwrap()
+  }
+}
+
+/// `fitness_uniform_selection` as a [`Selection`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FitnessUniformSelection;
+
+impl Selection for FitnessUniformSelection {
+  fn select(&self, fitness_values: &Vec<f32>, num_parents: usize, seed: Option<u64>) -> Vec<usize> {
+    fitness_uniform_selection(fitness_values, num_parents, seed)
+  }
+}
@@ -0,0 +1,61 @@
+use std::cmp::Ordering;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+/**
+## Description
+Tournament Selection without replacement holds tournaments in passes, such that every individual takes part in
+exactly `tournament_size` tournaments per pass. For each pass the population is shuffled `tournament_size` times
+and the concatenated orders are split into consecutive tournaments of `tournament_size` individuals, the fittest of
+which gets selected. A pass thus selects as many individuals as there are in the population, and passes are repeated
+until `num_parents` individuals are selected.
+
+Compared to `tournament_selection` the variance in the number of tournaments an individual enters is removed,
+so the best individual wins every tournament it enters and is selected up to `tournament_size` times per pass.
+
+### Note
+
+- A tournament spanning two shuffled orders can contain the same individual twice, in which case the individual
+  takes part in fewer distinct tournaments and the best one is selected fewer than `tournament_size` times.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Vec<usize>` pointing to the selected indices.
+
+## Example
+```rust
+  use genx::selection::tournament_without_replacement_selection;
+  let num_parents:usize = 10;
+  let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let result = tournament_without_replacement_selection(&fitness_values, num_parents, 2, None);
+```
+*/
+pub fn tournament_without_replacement_selection(fitness_values: &Vec<f32>, num_parents: usize, tournament_size: usize, seed: Option<u64>) -> Vec<usize> {
+  if fitness_values.is_empty() {
+    return Vec::new();
+  }
+  let tournament_size = tournament_size.max(1);
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+
+  let mut order = (0..fitness_values.len()).collect::<Vec<usize>>();
+  let mut selected_indices:Vec<usize> = Vec::new();
+  while selected_indices.len() < num_parents {
+    let mut pass:Vec<usize> = Vec::new();
+    for _ in 0..tournament_size {
+      order.shuffle(&mut prng);
+      pass.extend(order.iter());
+    }
+    for tournament in pass.chunks(tournament_size) {
+      if selected_indices.len() == num_parents {
+        break;
+      }
+      let winner = tournament.iter().max_by(|&&a, &&b| fitness_values[a].partial_cmp(&fitness_values[b]).unwrap_or(Ordering::Equal)).unwrap();
+      selected_indices.push(*winner);
+    }
+  }
+  selected_indices
+}
@@ -0,0 +1,50 @@
+use std::cmp::Ordering;
+use rand::{rngs::StdRng, SeedableRng, Rng};
+
+/**
+## Description
+Truncation Selection sorts the individuals by their fitness values and keeps only the best `fraction` of them.
+Each of the `num_parents` selected individuals is then chosen uniformly at random out of the ones kept.
+
+### Note
+
+- At least one individual is always kept, so small fractions degrade to always selecting the fittest individual.
+- The function can also take in an optional `seed` value of type `Option<u64>` for deterministic results.
+
+## Return
+
+The return value is a `Result<Vec<usize>, &'static str>` pointing to the selected indices, which will return error
+if `fraction` does not lie between `0.0` (exclusive) and `1.0` (inclusive).
+
+## Example
+```rust
+  use genx::selection::truncation_selection;
+  let num_parents:usize = 10;
+  let fitness_values = vec![10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+  let result = truncation_selection(&fitness_values, num_parents, 0.3, None).unwrap();
+```
+*/
+pub fn truncation_selection(fitness_values: &Vec<f32>, num_parents: usize, fraction: f32, seed: Option<u64>) -> Result<Vec<usize>, &'static str> {
+  check_fraction(fraction)?;
+  if fitness_values.is_empty() {
+    return Ok(Vec::new());
+  }
+
+  let mut order = (0..fitness_values.len()).collect::<Vec<usize>>();
+  order.sort_by(|&a, &b| fitness_values[b].partial_cmp(&fitness_values[a]).unwrap_or(Ordering::Equal));
+  let kept = ((fraction * fitness_values.len() as f32).ceil() as usize).clamp(1, fitness_values.len());
+
+  let mut prng = match seed {
+    Some(val) => StdRng::seed_from_u64(val),
+    None => StdRng::from_entropy()
+  };
+  Ok((0..num_parents).map(|_| order[prng.gen_range(0..kept)]).collect())
+}
+
+pub(crate) fn check_fraction(fraction: f32) -> Result<(), &'static str> {
+  if !(fraction > 0.0 && fraction <= 1.0) {
+    return Err("fraction should lie between 0.0 exclusive and 1.0 inclusive");
+  }
+  Ok(())
+}
@@ -0,0 +1,23 @@
+//! The `speciation` module provides implementation of the
+//! NEAT style speciation of a population, generalised to any
+//! individual for which a distance function can be defined.
+//!
+//! A [`Speciator`] keeps the species across generations:
+//! * `speciate` assigns every individual to the first species whose
+//!   representative is within the compatibility threshold of it.
+//! * `adjust_threshold` moves the compatibility threshold towards a
+//!   targeted number of species.
+//! * `allot_offspring` shares the offsprings of the next generation
+//!   between the species proportionally to their adjusted fitness.
+//! * `cull_stagnant` removes species which stopped improving.
+//!
+//! Higher fitness values are better, and fitness values should be non
+//! negative for the offsprings to be allotted proportionally.
+
+pub mod species;
+
+pub mod speciator;
+
+pub use self::species::Species;
+
+pub use self::speciator::Speciator;
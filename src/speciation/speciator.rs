@@ -0,0 +1,179 @@
+use std::cmp::Ordering;
+
+use super::Species;
+
+/**
+## Description
+`Speciator` divides a population into species of similar individuals according to a user `distance` function, and keeps
+those species across generations so that their stagnation can be tracked.
+
+Every generation `speciate` assigns each individual to the first species whose representative is within the
+`compatibility_threshold` of it, creating a new species represented by the individual otherwise. Species left without
+members disappear, and the fittest member of every species becomes its representative for the next generation.
+
+## Example
+```rust
+  use genx::speciation::Speciator;
+  let distance = |a: &f32, b: &f32| (a - b).abs();
+  let mut speciator = Speciator::new(1.0);
+
+  for generation in 0..10 {
+    let population = vec![0.0, 0.5, 5.0, 5.2, 9.0];
+    let fitness_values = vec![1.0, 2.0, 3.0, 1.0, 0.5];
+
+    speciator.speciate(&population, &fitness_values, distance, generation).unwrap();
+    speciator.adjust_threshold(2, 0.5);
+    let offsprings = speciator.allot_offspring(&fitness_values, population.len()).unwrap();
+    speciator.cull_stagnant(generation, 15, 2);
+  }
+```
+*/
+#[derive(Clone, Debug)]
+pub struct Speciator<T> {
+  species: Vec<Species<T>>,
+  compatibility_threshold: f32,
+  next_id: usize,
+}
+
+impl<T: Clone> Speciator<T> {
+  pub fn new(compatibility_threshold: f32) -> Self {
+    Speciator { species: Vec::new(), compatibility_threshold, next_id: 0 }
+  }
+
+  /// Species of the last speciation, in order of appearance.
+  pub fn species(&self) -> &Vec<Species<T>> {
+    &self.species
+  }
+
+  pub fn compatibility_threshold(&self) -> f32 {
+    self.compatibility_threshold
+  }
+
+  pub fn set_compatibility_threshold(&mut self, compatibility_threshold: f32) {
+    self.compatibility_threshold = compatibility_threshold;
+  }
+
+  /// Index in `species` of the species the `idx`th individual of the population belongs to.
+  pub fn species_of(&self, idx: usize) -> Option<usize> {
+    self.species.iter().position(|species| species.members.contains(&idx))
+  }
+
+  /**
+  Assigns every individual of `population` to a species, which returns error if `population` and `fitness_values`
+  have different lengths.
+  */
+  pub fn speciate<D>(&mut self, population: &Vec<T>, fitness_values: &Vec<f32>, distance: D, generation: usize) -> Result<(), &'static str>
+  where
+    D: Fn(&T, &T) -> f32,
+  {
+    if population.len() != fitness_values.len() {
+      return Err("population and fitness_values should have the same length");
+    }
+
+    for species in self.species.iter_mut() {
+      species.members.clear();
+    }
+    for (idx, individual) in population.iter().enumerate() {
+      let compatible = self
+        .species
+        .iter()
+        .position(|species| distance(&species.representative, individual) < self.compatibility_threshold);
+      match compatible {
+        Some(position) => self.species[position].members.push(idx),
+        None => {
+          let mut species = Species::new(self.next_id, individual.clone(), generation);
+          species.members.push(idx);
+          self.species.push(species);
+          self.next_id += 1;
+        }
+      }
+    }
+    self.species.retain(|species| !species.members.is_empty());
+
+    for species in self.species.iter_mut() {
+      let best = *species
+        .members
+        .iter()
+        .max_by(|&&a, &&b| fitness_values[a].partial_cmp(&fitness_values[b]).unwrap_or(Ordering::Equal))
+        .unwrap();
+      species.representative = population[best].clone();
+      if fitness_values[best] > species.best_fitness {
+        species.best_fitness = fitness_values[best];
+        species.last_improvement = generation;
+      }
+    }
+    Ok(())
+  }
+
+  /**
+  Moves the compatibility threshold by `step` towards getting `target_species` species, lowering it when there are
+  too few species and raising it when there are too many. The threshold never gets below `step`.
+  */
+  pub fn adjust_threshold(&mut self, target_species: usize, step: f32) {
+    match self.species.len().cmp(&target_species) {
+      Ordering::Less => self.compatibility_threshold = (self.compatibility_threshold - step).max(step),
+      Ordering::Greater => self.compatibility_threshold += step,
+      Ordering::Equal => {}
+    }
+  }
+
+  /**
+  Shares `total_offspring` offsprings between the species proportionally to the sum of the adjusted fitness values of
+  their members, rounding with the largest remainders, and returns the number of offsprings of every species in order.
+  If every adjusted fitness value is zero the offsprings are shared proportionally to the sizes of the species.
+
+  Returns error if `fitness_values` contain negative or non finite values, or don't cover the members of every species.
+  */
+  pub fn allot_offspring(&self, fitness_values: &Vec<f32>, total_offspring: usize) -> Result<Vec<usize>, &'static str> {
+    if self.species.iter().flat_map(|species| species.members.iter()).any(|&idx| idx >= fitness_values.len()) {
+      return Err("fitness_values should cover every member of the species");
+    }
+    if fitness_values.iter().any(|&x| !x.is_finite() || x < 0.0) {
+      return Err("fitness values should be finite and non negative to allot offsprings");
+    }
+    if self.species.is_empty() {
+      return Ok(Vec::new());
+    }
+
+    let mut shares = self.species.iter().map(|species| species.adjusted_fitness(fitness_values).iter().sum::<f32>()).collect::<Vec<f32>>();
+    if shares.iter().all(|&x| x == 0.0) {
+      shares = self.species.iter().map(|species| species.members.len() as f32).collect();
+    }
+    let total = shares.iter().sum::<f32>();
+    let exact = shares.iter().map(|&x| x / total * total_offspring as f32).collect::<Vec<f32>>();
+
+    let mut allotted = exact.iter().map(|&x| x.floor() as usize).collect::<Vec<usize>>();
+    let mut order = (0..exact.len()).collect::<Vec<usize>>();
+    order.sort_by(|&a, &b| (exact[b] - exact[b].floor()).partial_cmp(&(exact[a] - exact[a].floor())).unwrap_or(Ordering::Equal));
+    let remaining = total_offspring.saturating_sub(allotted.iter().sum::<usize>());
+    for &idx in order.iter().cycle().take(remaining) {
+      allotted[idx] += 1;
+    }
+    Ok(allotted)
+  }
+
+  /**
+  Removes the species whose best fitness value hasn't improved for more than `max_stagnation` generations, keeping
+  at least the `min_species` species with the highest best fitness values, and returns the removed species.
+  */
+  pub fn cull_stagnant(&mut self, generation: usize, max_stagnation: usize, min_species: usize) -> Vec<Species<T>> {
+    let mut order = (0..self.species.len()).collect::<Vec<usize>>();
+    order.sort_by(|&a, &b| self.species[b].best_fitness.partial_cmp(&self.species[a].best_fitness).unwrap_or(Ordering::Equal));
+    let mut protected = vec![false; self.species.len()];
+    for &idx in order.iter().take(min_species) {
+      protected[idx] = true;
+    }
+
+    let mut kept = Vec::new();
+    let mut removed = Vec::new();
+    for (species, protected) in self.species.drain(..).zip(protected) {
+      if protected || species.stagnation(generation) <= max_stagnation {
+        kept.push(species);
+      } else {
+        removed.push(species);
+      }
+    }
+    self.species = kept;
+    removed
+  }
+}
@@ -0,0 +1,39 @@
+/// A species of similar individuals, kept by a [`Speciator`](super::Speciator) across generations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Species<T> {
+  /// Unique identifier of the species, never reused by the speciator.
+  pub id: usize,
+  /// Individual new members are compared against, the fittest member of the previous speciation.
+  pub representative: T,
+  /// Indices in the population of the members of the current generation.
+  pub members: Vec<usize>,
+  /// Highest fitness value ever reached by a member of the species.
+  pub best_fitness: f32,
+  /// Generation in which `best_fitness` last improved.
+  pub last_improvement: usize,
+  /// Generation in which the species appeared.
+  pub created: usize,
+}
+
+impl<T> Species<T> {
+  pub fn new(id: usize, representative: T, generation: usize) -> Self {
+    Species {
+      id,
+      representative,
+      members: Vec::new(),
+      best_fitness: f32::NEG_INFINITY,
+      last_improvement: generation,
+      created: generation,
+    }
+  }
+
+  /// Number of generations since `best_fitness` last improved.
+  pub fn stagnation(&self, generation: usize) -> usize {
+    generation.saturating_sub(self.last_improvement)
+  }
+
+  /// Fitness values of the members divided by the size of the species, so that large species can't take over the population.
+  pub fn adjusted_fitness(&self, fitness_values: &Vec<f32>) -> Vec<f32> {
+    self.members.iter().map(|&idx| fitness_values[idx] / self.members.len() as f32).collect()
+  }
+}
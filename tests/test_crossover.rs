@@ -2,7 +2,8 @@ extern crate genx;
 
 #[cfg(test)]
 mod tests {
-  use genx::crossover::{single_point_crossover, multi_point_crossover};
+  use genx::crossover::{single_point_crossover, multi_point_crossover, integer_arithmetic_crossover, integer_simulated_binary_crossover, discrete_uniform_crossover, packed_uniform_crossover, packed_multi_point_crossover, composite_crossover, order_crossover, uniform_crossover, blend_crossover, cut_and_splice_crossover, messy_splice_crossover, homologous_crossover, diagonal_crossover, scanning_crossover, ScanningStrategy, gene_pool_crossover, unimodal_normal_distribution_crossover, simplex_crossover, parent_centric_crossover, whole_arithmetic_crossover, simple_arithmetic_crossover, single_arithmetic_crossover, heuristic_crossover, laplace_crossover, blend_alpha_beta_crossover, fitness_biased_uniform_crossover, FitnessAwareCrossover, HeuristicCrossover};
+  use genx::genome::{BitGenome, CompositeGenome, Segment, SegmentCrossover, SegmentMutation, SegmentOperator};
 
   #[test]
   fn test_single_point_crossover() {
@@ -48,4 +49,316 @@ mod tests {
     assert_eq!(child1, [false, true, false, true, false, true, true, false, true, false, true]);
     assert_eq!(child2, [true, true, false, false, true, true, false, true, true, false, false]);
   }
+
+  #[test]
+  fn test_integer_arithmetic_crossover() {
+    let parent1 = vec![1, 8, 4, 0, 6];
+    let parent2 = vec![5, 2, 4, 9, 7];
+    let (child1, child2) = integer_arithmetic_crossover(&parent1, &parent2, 0.5);
+    assert_eq!(child1, [3, 5, 4, 5, 7]);
+    assert_eq!(child2, [3, 5, 4, 5, 7]);
+    // alpha weights the other parent, as in whole_arithmetic_crossover
+    let (child1, child2) = integer_arithmetic_crossover(&parent1, &parent2, 0.25);
+    assert_eq!(child1, [2, 7, 4, 2, 6]);
+    assert_eq!(child2, [4, 4, 4, 7, 7]);
+  }
+
+  #[test]
+  fn test_integer_simulated_binary_crossover() {
+    let parent1 = vec![1, 8, 4, 0, 6, 3, 9, 2];
+    let parent2 = vec![5, 2, 4, 9, 7, 3, 0, 8];
+    let bounds = vec![(0, 9); parent1.len()];
+    assert!(integer_simulated_binary_crossover(&parent1, &parent2, &bounds[1..].to_vec(), 2.0, None).is_err());
+    let mut inverted = bounds.clone();
+    inverted[3] = (9, 0);
+    assert!(integer_simulated_binary_crossover(&parent1, &parent2, &inverted, 2.0, None).is_err());
+    let (child1, child2) = integer_simulated_binary_crossover(&parent1, &parent2, &bounds, 2.0, Some(42)).unwrap();
+    assert_eq!(child1.len(), parent1.len());
+    for i in 0..parent1.len() {
+      assert!((0..=9).contains(&child1[i]) && (0..=9).contains(&child2[i]));
+      if parent1[i] == parent2[i] {
+        assert_eq!(child1[i], parent1[i]);
+        assert_eq!(child2[i], parent1[i]);
+      }
+    }
+  }
+
+  #[test]
+  fn test_discrete_uniform_crossover() {
+    let parent1 = vec![1, 8, 4, 0, 6, 3, 9, 2];
+    let parent2 = vec![5, 2, 4, 9, 7, 3, 0, 8];
+    let (child1, child2) = discrete_uniform_crossover(&parent1, &parent2, Some(42));
+    for i in 0..parent1.len() {
+      assert!(child1[i] == parent1[i] || child1[i] == parent2[i]);
+      assert!(child2[i] == parent1[i] || child2[i] == parent2[i]);
+    }
+    assert_eq!((child1, child2), discrete_uniform_crossover(&parent1, &parent2, Some(42)));
+  }
+
+  #[test]
+  fn test_packed_uniform_crossover() {
+    let parent1 = BitGenome::random(200, Some(1));
+    let parent2 = BitGenome::random(200, Some(2));
+    let (child1, child2) = packed_uniform_crossover(&parent1, &parent2, 0.3, Some(42));
+    let (bits1, bits2) = (parent1.to_bools(), parent2.to_bools());
+    let mut swapped = 0;
+    for (i, (x, y)) in child1.to_bools().iter().zip(child2.to_bools().iter()).enumerate() {
+      assert!((*x == bits1[i] && *y == bits2[i]) || (*x == bits2[i] && *y == bits1[i]));
+      if *x != bits1[i] {
+        swapped += 1;
+      }
+    }
+    assert!(swapped > 0);
+    assert_eq!(child1.count_ones() + child2.count_ones(), parent1.count_ones() + parent2.count_ones());
+  }
+
+  #[test]
+  fn test_packed_multi_point_crossover() {
+    let parent1 = BitGenome::new(150);
+    let parent2 = BitGenome::from(&vec![true; 150]);
+    let (child1, child2) = packed_multi_point_crossover(&parent1, &parent2, 3, Some(42));
+    let bits = child1.to_bools();
+    let switches = bits.windows(2).filter(|pair| pair[0] != pair[1]).count() + bits[0] as usize;
+    assert_eq!(switches, 3);
+    assert!(bits[149]);
+    assert_eq!(child1.hamming_distance(&child2), 150);
+  }
+
+  fn composite_operators(crossover_rate: f32) -> Vec<SegmentOperator> {
+    vec![
+      SegmentOperator::new(SegmentCrossover::permutation(order_crossover), crossover_rate, SegmentMutation::permutation(|_, _| ()), 0.0),
+      SegmentOperator::new(SegmentCrossover::binary(|a, b, seed| uniform_crossover(a, b, 0.5, seed)), crossover_rate, SegmentMutation::binary(|_, _| ()), 0.0),
+      SegmentOperator::new(SegmentCrossover::real(|a, b, seed| blend_crossover(a, b, 0.5, seed)), crossover_rate, SegmentMutation::real(|x, _| x), 0.0),
+    ]
+  }
+
+  #[test]
+  fn test_composite_crossover() {
+    let parent1 = CompositeGenome::new(vec![Segment::Permutation(vec![1, 3, 4, 7, 0, 2, 6, 5]), Segment::Binary(vec![true; 8]), Segment::Real(vec![0.0, 1.0, 2.0])]);
+    let parent2 = CompositeGenome::new(vec![Segment::Permutation(vec![2, 3, 4, 0, 7, 6, 1, 5]), Segment::Binary(vec![false; 8]), Segment::Real(vec![2.0, 1.0, 0.0])]);
+
+    let (child1, child2) = composite_crossover(&parent1, &parent2, &composite_operators(1.0), Some(42));
+    assert_eq!((child1.clone(), child2.clone()), composite_crossover(&parent1, &parent2, &composite_operators(1.0), Some(42)));
+    match (&child1.segments[0], &child1.segments[1], &child2.segments[1]) {
+      (Segment::Permutation(order), Segment::Binary(mask1), Segment::Binary(mask2)) => {
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..8).collect::<Vec<usize>>());
+        assert!(mask1.iter().zip(mask2.iter()).all(|(x, y)| x != y));
+      }
+      _ => panic!("Segment kinds changed"),
+    }
+    match &child1.segments[2] {
+      Segment::Real(genes) => assert_eq!(genes[1], 1.0),
+      _ => panic!("Segment kinds changed"),
+    }
+
+    let (child1, child2) = composite_crossover(&parent1, &parent2, &composite_operators(0.0), Some(42));
+    assert_eq!((child1, child2), (parent1, parent2));
+  }
+
+  #[test]
+  #[should_panic(expected = "Segment kinds must match their operators")]
+  fn test_composite_crossover_fail() {
+    let parent1 = CompositeGenome::new(vec![Segment::Binary(vec![true; 8]), Segment::Permutation(vec![0, 1]), Segment::Real(vec![0.0])]);
+    let parent2 = parent1.clone();
+    // the kinds are checked even when no segment is recombined
+    composite_crossover(&parent1, &parent2, &composite_operators(0.0), Some(42));
+  }
+
+  #[test]
+  fn test_cut_and_splice_crossover() {
+    let parent1 = (0..10).collect::<Vec<i32>>();
+    let parent2 = (100..104).collect::<Vec<i32>>();
+    let (child1, child2) = cut_and_splice_crossover(&parent1, &parent2, Some(42));
+    assert_eq!(child1.len() + child2.len(), 14);
+    let head1 = child1.iter().take_while(|&&x| x < 100).count();
+    let head2 = child2.iter().take_while(|&&x| x >= 100).count();
+    assert_eq!(child1[..head1], parent1[..head1]);
+    assert_eq!(child2[head2..], parent1[head1..]);
+    assert_eq!(child1[head1..], parent2[head2..]);
+  }
+
+  #[test]
+  fn test_messy_splice_crossover() {
+    let parent1 = (0..10).collect::<Vec<i32>>();
+    let parent2 = (100..104).collect::<Vec<i32>>();
+    let children = messy_splice_crossover(&parent1, &parent2, 1.0, 0.0, Some(42));
+    assert_eq!(children.len(), 4);
+    assert_eq!(children.concat(), [parent1.clone(), parent2.clone()].concat());
+
+    let children = messy_splice_crossover(&parent1, &parent2, 0.0, 1.0, Some(42));
+    assert_eq!(children, vec![[parent1, parent2].concat()]);
+  }
+
+  #[test]
+  fn test_homologous_crossover() {
+    let parent1 = (0..10).collect::<Vec<i32>>();
+    let parent2 = (100..104).collect::<Vec<i32>>();
+    let (child1, child2) = homologous_crossover(&parent1, &parent2, Some(42));
+    assert_eq!((child1.len(), child2.len()), (10, 4));
+    assert_eq!(child1[4..], parent1[4..]);
+    for i in 0..4 {
+      assert!((child1[i] == parent1[i] && child2[i] == parent2[i]) || (child1[i] == parent2[i] && child2[i] == parent1[i]));
+    }
+  }
+
+  #[test]
+  fn test_diagonal_crossover() {
+    let parents = (1..=3).map(|x| vec![x; 9]).collect::<Vec<Vec<i32>>>();
+    let children = diagonal_crossover(&parents, Some(42));
+    assert_eq!(children.len(), 3);
+    for i in 0..9 {
+      let mut column = children.iter().map(|child| child[i]).collect::<Vec<i32>>();
+      column.sort();
+      assert_eq!(column, [1, 2, 3]);
+    }
+    for (i, child) in children.iter().enumerate() {
+      assert_eq!(child[0], i as i32 + 1);
+      let switches = child.windows(2).filter(|pair| pair[0] != pair[1]).count();
+      assert_eq!(switches, 2);
+    }
+  }
+
+  #[test]
+  fn test_scanning_crossover() {
+    let parents = vec![vec![1, 2, 3, 4], vec![1, 5, 3, 6], vec![7, 5, 3, 4]];
+    assert_eq!(scanning_crossover(&parents, ScanningStrategy::Occurrence, Some(42)), [1, 5, 3, 4]);
+    let child = scanning_crossover(&parents, ScanningStrategy::Uniform, Some(42));
+    for (i, x) in child.iter().enumerate() {
+      assert!(parents.iter().any(|parent| parent[i] == *x));
+    }
+    let fitness_values = vec![0.0, 1.0, 0.0];
+    assert_eq!(scanning_crossover(&parents, ScanningStrategy::FitnessBased(&fitness_values), Some(42)), parents[1]);
+  }
+
+  #[test]
+  fn test_gene_pool_crossover() {
+    let parents = vec![vec![0.0, 1.0, -2.0], vec![1.0, 1.0, 4.0], vec![0.5, 1.0, 3.0]];
+    let child = gene_pool_crossover(&parents, Some(42));
+    assert!((0.0..=1.0).contains(&child[0]));
+    assert_eq!(child[1], 1.0);
+    assert!((-2.0..=4.0).contains(&child[2]));
+  }
+
+  #[test]
+  fn test_unimodal_normal_distribution_crossover() {
+    let parents = vec![vec![0.0, 0.0, 2.0], vec![2.0, 0.0, 2.0], vec![4.0, 4.0, 2.0], vec![1.0, 3.0, 5.0]];
+    let children = unimodal_normal_distribution_crossover(&parents, 0.5, 0.35, 2000, Some(42));
+    assert_eq!(children.len(), 2000);
+    let expected_mean = [2.0, 4.0 / 3.0, 2.0];
+    for i in 0..3 {
+      let mean = children.iter().map(|child| child[i]).sum::<f32>() / 2000.0;
+      assert!((mean - expected_mean[i]).abs() < 0.1);
+    }
+  }
+
+  #[test]
+  fn test_simplex_crossover() {
+    let parents = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]];
+    for child in simplex_crossover(&parents, 1.0, 100, Some(42)) {
+      assert!(child[0] >= -1e-5 && child[1] >= -1e-5 && child[0] + child[1] <= 1.0 + 1e-5);
+    }
+  }
+
+  #[test]
+  fn test_parent_centric_crossover() {
+    let parents = vec![vec![1.0, 1.0, 0.0], vec![0.0, 0.0, 0.0], vec![1.0, 0.0, 1.0]];
+    for child in parent_centric_crossover(&parents, 0.0, 0.0, 3, Some(42)) {
+      assert_eq!(child, parents[0]);
+    }
+    let children = parent_centric_crossover(&parents, 0.1, 0.1, 2000, Some(42));
+    for i in 0..3 {
+      let mean = children.iter().map(|child| child[i]).sum::<f32>() / 2000.0;
+      assert!((mean - parents[0][i]).abs() < 0.05);
+    }
+  }
+
+  #[test]
+  fn test_arithmetic_crossovers() {
+    let parent1 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    let parent2 = vec![5.0, 4.0, 3.0, 2.0, 1.0];
+    assert_eq!(whole_arithmetic_crossover(&parent1, &parent2, 0.5).0, [3.0; 5]);
+
+    let (child1, child2) = simple_arithmetic_crossover(&parent1, &parent2, 0.5, Some(42));
+    let k = child1.iter().zip(parent1.iter()).take_while(|(x, y)| x == y).count();
+    assert!(child1[k..].iter().chain(child2[k..].iter()).all(|&x| x == 3.0));
+    assert_eq!(child2[..k], parent2[..k]);
+
+    let (child1, child2) = single_arithmetic_crossover(&parent1, &parent2, 0.25, Some(42));
+    let changed = (0..5).filter(|&i| child1[i] != parent1[i]).collect::<Vec<usize>>();
+    assert!(changed.len() <= 1);
+    for i in changed {
+      assert_eq!(child1[i], 0.75 * parent1[i] + 0.25 * parent2[i]);
+      assert_eq!(child2[i], 0.75 * parent2[i] + 0.25 * parent1[i]);
+    }
+  }
+
+  #[test]
+  fn test_heuristic_crossover() {
+    let parent1 = vec![1.0, 2.0, 3.0];
+    let parent2 = vec![2.0, 2.0, 1.0];
+    let child = heuristic_crossover((&parent1, 0.9), (&parent2, 0.4), None, Some(42));
+    assert!(child[0] <= 1.0 && child[0] >= 0.0);
+    assert_eq!(child[1], 2.0);
+    assert!(child[2] >= 3.0 && child[2] <= 5.0);
+
+    let bounds = vec![(0.9, 3.1); 3];
+    let child = heuristic_crossover((&parent1, 0.9), (&parent2, 0.4), Some(&bounds), Some(42));
+    assert!(child.iter().all(|&x| (0.9..=3.1).contains(&x)));
+  }
+
+  #[test]
+  fn test_laplace_crossover() {
+    let parent1 = vec![1.0, 2.0, 3.0];
+    let parent2 = vec![2.0, 2.0, 1.0];
+    let (child1, child2) = laplace_crossover(&parent1, &parent2, 0.0, 0.35, Some(42));
+    assert_eq!((child1[1], child2[1]), (2.0, 2.0));
+    assert!((child1[0] - child2[0] + 1.0).abs() < 1e-5);
+    assert_eq!((child1, child2), laplace_crossover(&parent1, &parent2, 0.0, 0.35, Some(42)));
+  }
+
+  #[test]
+  fn test_blend_alpha_beta_crossover() {
+    let parent1 = vec![1.0, 3.0, 0.5];
+    let parent2 = vec![3.0, 1.0, 0.5];
+    let bounds = vec![(0.0, 5.0), (0.0, 5.0), (0.0, 1.0)];
+    for seed in 0..20 {
+      let (child1, child2) = blend_alpha_beta_crossover(&parent1, &parent2, 0.5, 0.0, &bounds, Some(seed));
+      for child in [child1, child2].iter() {
+        assert!(child[0] >= 0.0 && child[0] <= 3.0);
+        assert!(child[1] >= 1.0 && child[1] <= 4.0);
+        assert_eq!(child[2], 0.5);
+      }
+    }
+  }
+
+  #[test]
+  fn test_fitness_biased_uniform_crossover() {
+    let parent1 = vec![true; 1000];
+    let parent2 = vec![false; 1000];
+    let (child1, child2) = fitness_biased_uniform_crossover((&parent1, 3.0), (&parent2, 1.0), Some(42));
+    let taken = child1.iter().filter(|&&x| x).count();
+    assert!(taken > 700 && taken < 800);
+    assert!(child1.iter().zip(child2.iter()).all(|(x, y)| x != y));
+
+    let (child1, _) = fitness_biased_uniform_crossover((&parent1, 0.0), (&parent2, 5.0), Some(42));
+    assert_eq!(child1, parent2);
+  }
+
+  #[test]
+  fn test_fitness_aware_crossover() {
+    fn breed<T, C: FitnessAwareCrossover<T>>(crossover: &C, parents: &Vec<T>, fitness_values: &Vec<f32>) -> (T, T) {
+      crossover.crossover((&parents[0], fitness_values[0]), (&parents[1], fitness_values[1]), Some(42))
+    }
+    let parents = vec![vec![1.0, 2.0, 3.0], vec![2.0, 2.0, 1.0]];
+    let fitness_values = vec![0.2, 0.8];
+    let (child1, child2) = breed(&HeuristicCrossover::new(None), &parents, &fitness_values);
+    for child in [child1, child2].iter() {
+      assert!(child[0] >= 2.0 && child[0] <= 3.0);
+      assert!(child[2] <= 1.0 && child[2] >= -1.0);
+    }
+    let (child1, child2) = breed(&fitness_biased_uniform_crossover, &parents, &fitness_values);
+    assert_eq!(child1[1] + child2[1], 4.0);
+  }
 }
@@ -0,0 +1,264 @@
+extern crate genx;
+
+#[cfg(test)]
+mod tests {
+  use std::{
+    env,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    process::{Command, Stdio},
+    sync::{Arc, Mutex},
+    thread,
+  };
+
+  use genx::{
+    genome::{BitGenome, CompositeGenome, Segment},
+    island::{DistributedIsland, EmigrantPolicy, Endpoint, ImmigrantPolicy, Island, Migrant, Migration, Topology},
+  };
+
+  const INDEX_VAR: &str = "GENX_TEST_ISLAND_INDEX";
+
+  /// Binds listeners on free ports, which are kept until the islands take them over.
+  fn free_listeners(count: usize) -> (Vec<Option<TcpListener>>, Vec<Endpoint>) {
+    let listeners = (0..count).map(|_| TcpListener::bind("127.0.0.1:0").unwrap()).collect::<Vec<TcpListener>>();
+    let endpoints = listeners.iter().map(|listener| Endpoint::Tcp(listener.local_addr().unwrap())).collect();
+    (listeners.into_iter().map(Some).collect(), endpoints)
+  }
+
+  /// Runs an island which does not evolve, so that only migration can spread the best individual.
+  fn run_island(index: usize, endpoints: Vec<Endpoint>, listener: Option<TcpListener>, topology: Topology, generations: usize) -> DistributedIsland<'static, Vec<f32>> {
+    let value = index as f32;
+    let island = Island::new(vec![vec![value; 2]; 3], vec![value; 3], |_: &mut Vec<Vec<f32>>, _: &mut Vec<f32>, _, _| {});
+    let migration = Migration::new(1, 2, EmigrantPolicy::Best, ImmigrantPolicy::ReplaceWorst);
+    let mut island = match listener {
+      Some(listener) => DistributedIsland::from_listener(island, index, endpoints, listener, topology, migration).unwrap(),
+      None => DistributedIsland::new(island, index, endpoints, topology, migration).unwrap(),
+    };
+    island.run(generations, Some(42)).unwrap();
+    assert_eq!(island.generation(), generations);
+    island
+  }
+
+  fn run_in_threads(listeners: Vec<Option<TcpListener>>, endpoints: Vec<Endpoint>, topology: Topology, generations: usize) -> Vec<DistributedIsland<'static, Vec<f32>>> {
+    let handles = listeners
+      .into_iter()
+      .enumerate()
+      .map(|(index, listener)| {
+        let endpoints = endpoints.clone();
+        thread::spawn(move || run_island(index, endpoints, listener, topology, generations))
+      })
+      .collect::<Vec<_>>();
+    handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+  }
+
+  fn populations(islands: Vec<DistributedIsland<'static, Vec<f32>>>) -> Vec<Vec<Vec<f32>>> {
+    islands.into_iter().map(|island| island.island().population.clone()).collect()
+  }
+
+  #[test]
+  fn test_migrant() {
+    assert_eq!(u8::from_bytes(&7u8.to_bytes()), Ok(7));
+    assert_eq!(i32::from_bytes(&(-5i32).to_bytes()), Ok(-5));
+    assert_eq!(f64::from_bytes(&1.5f64.to_bytes()), Ok(1.5));
+    assert_eq!(usize::from_bytes(&42usize.to_bytes()), Ok(42));
+    assert_eq!(true.to_bytes(), vec![1]);
+    assert_eq!(vec![1u16, 2].to_bytes(), vec![2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0]);
+
+    let individual = (vec![true, false, true], vec![0.5f32, -1.5]);
+    assert_eq!(<(Vec<bool>, Vec<f32>)>::from_bytes(&individual.to_bytes()), Ok(individual));
+    let individuals = vec![(vec![3usize, 1, 2], 1.0f32), (vec![], 2.0)];
+    assert_eq!(Vec::<(Vec<usize>, f32)>::from_bytes(&individuals.to_bytes()), Ok(individuals));
+
+    let genome = BitGenome::random(100, Some(42));
+    assert_eq!(BitGenome::from_bytes(&genome.to_bytes()), Ok(genome));
+    let genome = CompositeGenome::new(vec![
+      Segment::Permutation(vec![2, 0, 1]),
+      Segment::Binary(vec![true, false]),
+      Segment::Integer(vec![-3, 4]),
+      Segment::Real(vec![0.25]),
+    ]);
+    assert_eq!(CompositeGenome::from_bytes(&genome.to_bytes()), Ok(genome));
+
+    assert!(bool::from_bytes(&[2]).is_err());
+    assert!(u32::from_bytes(&[1, 2]).is_err());
+    assert!(u8::from_bytes(&[1, 2]).is_err());
+    assert!(Vec::<f32>::from_bytes(&[255; 8]).is_err());
+    assert!(Segment::from_bytes(&[9, 0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+    let bytes = BitGenome::random(100, Some(42)).to_bytes();
+    assert!(BitGenome::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+  }
+
+  #[test]
+  fn test_endpoint() {
+    let endpoint = "127.0.0.1:4000".parse::<Endpoint>().unwrap();
+    assert_eq!(endpoint, "tcp:127.0.0.1:4000".parse().unwrap());
+    assert_eq!(endpoint.to_string(), "127.0.0.1:4000");
+    assert!("127.0.0.1".parse::<Endpoint>().is_err());
+    #[cfg(unix)]
+    {
+      let endpoint = "unix:/tmp/island.sock".parse::<Endpoint>().unwrap();
+      assert_eq!(endpoint, Endpoint::Unix("/tmp/island.sock".into()));
+      assert_eq!(endpoint.to_string(), "unix:/tmp/island.sock");
+    }
+
+    let any_port = vec!["127.0.0.1:0".parse::<Endpoint>().unwrap()];
+    let island = Island::new(vec![1.0f32], vec![1.0], |_: &mut Vec<f32>, _: &mut Vec<f32>, _, _| {});
+    let migration = Migration::new(1, 1, EmigrantPolicy::Best, ImmigrantPolicy::ReplaceWorst);
+    assert!(DistributedIsland::new(island, 1, any_port.clone(), Topology::Ring, migration).is_err());
+    let island = Island::new(vec![1.0f32], vec![1.0], |_: &mut Vec<f32>, _: &mut Vec<f32>, _, _| {});
+    assert!(DistributedIsland::new(island, 0, any_port.clone(), Topology::Ring, Migration { size: 2, ..migration }).is_err());
+    let island = Island::new(vec![1.0f32], vec![1.0], |_: &mut Vec<f32>, _: &mut Vec<f32>, _, _| {});
+    let mut island = DistributedIsland::new(island, 0, any_port.clone(), Topology::Random(1), migration).unwrap();
+    assert!(island.migrate(None).is_err());
+    // the port chosen by the system is reported
+    assert_ne!(island.endpoint(), &any_port[0]);
+    assert_eq!(island.endpoint(), &island.endpoints()[0]);
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let island = Island::new(vec![1.0f32], vec![1.0], |_: &mut Vec<f32>, _: &mut Vec<f32>, _, _| {});
+    let island = DistributedIsland::from_listener(island, 0, any_port, listener, Topology::Ring, migration).unwrap();
+    assert_eq!(island.endpoint(), &Endpoint::Tcp(address));
+  }
+
+  #[test]
+  fn test_distributed_island_seeds() {
+    // islands sharing a seed evolve with unrelated seeds, rather than seeds offset by their index
+    let seeds = (0..2)
+      .map(|index| {
+        let seeds = Arc::new(Mutex::new(Vec::new()));
+        let record = seeds.clone();
+        let island = Island::new(vec![1.0f32], vec![1.0], move |_: &mut Vec<f32>, _: &mut Vec<f32>, _, seed: Option<u64>| {
+          record.lock().unwrap().push(seed.unwrap())
+        });
+        let endpoints = vec!["127.0.0.1:0".parse().unwrap(); 2];
+        let migration = Migration::new(1, 10, EmigrantPolicy::Best, ImmigrantPolicy::ReplaceWorst);
+        let mut island = DistributedIsland::new(island, index, endpoints, Topology::Ring, migration).unwrap();
+        island.run(5, Some(42)).unwrap();
+        let seeds = seeds.lock().unwrap().clone();
+        seeds
+      })
+      .collect::<Vec<Vec<u64>>>();
+    for (seed0, seed1) in seeds[0].iter().zip(seeds[1].iter()) {
+      assert!(seed1.wrapping_sub(*seed0) > 1_000_000 && seed0.wrapping_sub(*seed1) > 1_000_000);
+    }
+  }
+
+  #[test]
+  fn test_distributed_islands_in_threads() {
+    let (listeners, endpoints) = free_listeners(4);
+    let islands = run_in_threads(listeners, endpoints, Topology::Ring, 8);
+    assert!(islands.iter().all(|island| island.rejected() == 0));
+    // the best individual travels one island along the ring every migration
+    for population in populations(islands) {
+      assert!(population.contains(&vec![3.0; 2]));
+    }
+
+    let (listeners, endpoints) = free_listeners(3);
+    let populations = populations(run_in_threads(listeners, endpoints, Topology::Random(1), 10));
+    assert!(populations.iter().filter(|population| population.contains(&vec![2.0; 2])).count() > 1);
+  }
+
+  #[test]
+  fn test_distributed_islands_skip_stray_connections() {
+    let (listeners, endpoints) = free_listeners(2);
+    let batch = |version: u8, source: usize| {
+      let mut bytes = b"GENX".to_vec();
+      bytes.push(version);
+      bytes.extend((0u64, source, vec![(vec![9.0f32; 2], 9.0f32)]).to_bytes());
+      bytes
+    };
+    let strays = [
+      b"GET / HTTP/1.1\r\n\r\n".to_vec(),
+      vec![],
+      batch(2, 1),
+      // an unknown island, and the island itself which is not one of its sources
+      batch(1, 7),
+      batch(1, 0),
+    ];
+    // the connections wait in the backlog of the listener until the first migration
+    for bytes in strays.iter() {
+      TcpStream::connect(endpoints[0].to_string()).unwrap().write_all(bytes).unwrap();
+    }
+
+    let islands = run_in_threads(listeners, endpoints, Topology::Ring, 4);
+    assert_eq!(islands[0].rejected(), strays.len());
+    assert_eq!(islands[1].rejected(), 0);
+    for population in populations(islands) {
+      assert!(!population.contains(&vec![9.0; 2]));
+      assert!(population.contains(&vec![1.0; 2]));
+    }
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_distributed_islands_over_unix_sockets() {
+    let directory = env::temp_dir();
+    let endpoints = (0..3)
+      .map(|index| Endpoint::Unix(directory.join(format!("genx-island-{}-{}.sock", std::process::id(), index))))
+      .collect::<Vec<Endpoint>>();
+    let populations = populations(run_in_threads(vec![None, None, None], endpoints.clone(), Topology::Star, 2));
+    // the hub is the first island, which receives the best individual of the others and sends its own
+    assert_eq!(populations[0], vec![vec![1.0; 2], vec![2.0; 2], vec![0.0; 2]]);
+    assert_eq!(populations[2], vec![vec![0.0; 2], vec![2.0; 2], vec![2.0; 2]]);
+    for endpoint in endpoints {
+      if let Endpoint::Unix(path) = endpoint {
+        assert!(!path.exists());
+      }
+    }
+  }
+
+  /**
+  Runs every island in its own process by invoking the test binary again on this test only, in island mode. Every
+  island listens on a port of its own choosing and reports it, then reads the endpoints of all the islands.
+  */
+  #[test]
+  fn test_distributed_islands_in_processes() {
+    if let Ok(index) = env::var(INDEX_VAR) {
+      let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+      println!("island endpoint {}", listener.local_addr().unwrap());
+      let mut endpoints = String::new();
+      std::io::stdin().read_line(&mut endpoints).unwrap();
+      let endpoints = endpoints.split_whitespace().map(|endpoint| endpoint.parse().unwrap()).collect();
+      let island = run_island(index.parse().unwrap(), endpoints, Some(listener), Topology::Ring, 6);
+      println!("island population {:?}", island.island().population);
+      return;
+    }
+
+    let mut children = (0..3)
+      .map(|index| {
+        Command::new(env::current_exe().unwrap())
+          .args(["tests::test_distributed_islands_in_processes", "--exact", "--nocapture", "--test-threads=1"])
+          .env(INDEX_VAR, index.to_string())
+          .stdin(Stdio::piped())
+          .stdout(Stdio::piped())
+          .stderr(Stdio::piped())
+          .spawn()
+          .unwrap()
+      })
+      .collect::<Vec<_>>();
+    let mut outputs = children.iter_mut().map(|child| BufReader::new(child.stdout.take().unwrap())).collect::<Vec<_>>();
+    let endpoints = outputs
+      .iter_mut()
+      .map(|output| {
+        output
+          .by_ref()
+          .lines()
+          .find_map(|line| line.unwrap().split("island endpoint ").nth(1).map(String::from))
+          .unwrap()
+      })
+      .collect::<Vec<String>>()
+      .join(" ");
+    for child in children.iter_mut() {
+      writeln!(child.stdin.take().unwrap(), "{}", endpoints).unwrap();
+    }
+    for (child, mut output) in children.into_iter().zip(outputs) {
+      let mut stdout = String::new();
+      output.read_to_string(&mut stdout).unwrap();
+      let output = child.wait_with_output().unwrap();
+      assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+      let population = stdout.lines().find_map(|line| line.split("island population ").nth(1)).unwrap();
+      assert!(population.contains("[2.0, 2.0]"), "{}", population);
+    }
+  }
+}
@@ -0,0 +1,53 @@
+extern crate genx;
+
+#[cfg(test)]
+mod tests {
+  use genx::encoding::{binary_to_gray, gray_to_binary, bits_for_precision, encode_real, decode_real, encode_reals, decode_reals, encode_integers, decode_integers, Coding};
+
+  #[test]
+  fn test_gray_code() {
+    for value in 0..1024u64 {
+      assert_eq!(gray_to_binary(binary_to_gray(value)), value);
+      assert_eq!((binary_to_gray(value) ^ binary_to_gray(value + 1)).count_ones(), 1);
+    }
+    assert_eq!(gray_to_binary(binary_to_gray(u64::MAX)), u64::MAX);
+  }
+
+  #[test]
+  fn test_real_precision() {
+    let num_bits = bits_for_precision(-5.0, 5.0, 0.001);
+    for &value in [-5.0, -1.234, 0.0, 2.5, 4.999, 5.0].iter() {
+      for &coding in [Coding::Binary, Coding::Gray].iter() {
+        let bits = encode_real(value, -5.0, 5.0, num_bits, coding);
+        assert_eq!(bits.len(), num_bits);
+        assert!((decode_real(&bits, -5.0, 5.0, coding) - value).abs() <= 0.001);
+      }
+    }
+    assert_eq!(decode_real(&encode_real(12.0, -5.0, 5.0, 8, Coding::Binary), -5.0, 5.0, Coding::Binary), 5.0);
+  }
+
+  #[test]
+  fn test_reals_round_trip() {
+    let bounds = vec![(-10.0, 10.0), (0.0, 1.0), (100.0, 200.0)];
+    let values = vec![-3.75, 0.5, 150.0];
+    let individual = encode_reals(&values, &bounds, 20, Coding::Gray).unwrap();
+    assert_eq!(individual.len(), 60);
+    let decoded = decode_reals(&individual, &bounds, 20, Coding::Gray).unwrap();
+    for (x, y) in decoded.iter().zip(values.iter()) {
+      assert!((x - y).abs() < 1e-3);
+    }
+    assert!(decode_reals(&individual, &bounds, 16, Coding::Gray).is_err());
+    assert!(encode_reals(&values, &bounds, 0, Coding::Gray).is_err());
+  }
+
+  #[test]
+  fn test_integers_round_trip() {
+    let bounds = vec![(0, 15), (-8, 7), (3, 9)];
+    let values = vec![11, -3, 6];
+    for &coding in [Coding::Binary, Coding::Gray].iter() {
+      let individual = encode_integers(&values, &bounds, 4, coding).unwrap();
+      assert_eq!(decode_integers(&individual, &bounds, 4, coding).unwrap(), values);
+    }
+    assert!(decode_integers(&vec![true; 7], &bounds, 4, Coding::Binary).is_err());
+  }
+}
@@ -0,0 +1,36 @@
+extern crate genx;
+
+#[cfg(test)]
+mod tests {
+  use genx::genome::BitGenome;
+
+  #[test]
+  fn test_bit_genome_round_trip() {
+    let individual = (0..150).map(|x| x % 3 == 0 || x % 7 == 0).collect::<Vec<bool>>();
+    let mut genome = BitGenome::from(&individual);
+    assert_eq!(genome.len(), 150);
+    assert_eq!(genome.to_bools(), individual);
+    assert_eq!(genome.count_ones(), individual.iter().filter(|&&x| x).count());
+
+    genome.set(149, true);
+    genome.flip(0);
+    assert!(genome.get(149));
+    assert!(!genome.get(0));
+    assert_eq!(genome.hamming_distance(&BitGenome::from(&individual)), 2);
+  }
+
+  #[test]
+  fn test_bit_genome_random() {
+    let genome = BitGenome::random(130, Some(42));
+    assert_eq!(genome, BitGenome::random(130, Some(42)));
+    assert_eq!(genome.words().len(), 3);
+    assert_eq!(genome.words()[2] >> 2, 0);
+    assert_eq!(genome.count_ones(), genome.to_bools().iter().filter(|&&x| x).count());
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_bit_genome_hamming_distance_fail() {
+    BitGenome::new(10).hamming_distance(&BitGenome::new(11));
+  }
+}
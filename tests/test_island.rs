@@ -0,0 +1,131 @@
+extern crate genx;
+
+#[cfg(test)]
+mod tests {
+  use genx::{
+    island::{insert_immigrants, select_emigrants, EmigrantPolicy, ImmigrantPolicy, Island, IslandModel, Migration, Topology},
+    mutation::flipping_mutation,
+  };
+
+  fn one_max(individual: &[bool]) -> f32 {
+    individual.iter().filter(|&&x| x).count() as f32
+  }
+
+  fn hill_climber(mutation_probability: f32) -> impl FnMut(&mut Vec<Vec<bool>>, &mut Vec<f32>, usize, Option<u64>) + Send {
+    move |population, fitness_values, _, seed| {
+      for (idx, (individual, fitness)) in population.iter_mut().zip(fitness_values.iter_mut()).enumerate() {
+        let mut offspring = individual.clone();
+        flipping_mutation(&mut offspring, mutation_probability, seed.map(|val| val.wrapping_add(idx as u64))).unwrap();
+        if one_max(&offspring) >= *fitness {
+          *fitness = one_max(&offspring);
+          *individual = offspring;
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn test_topology() {
+    assert_eq!(Topology::Ring.destinations(4, None), vec![vec![1], vec![2], vec![3], vec![0]]);
+    assert_eq!(Topology::Ring.destinations(1, None), vec![Vec::<usize>::new()]);
+    assert_eq!(Topology::FullyConnected.destinations(3, None), vec![vec![1, 2], vec![0, 2], vec![0, 1]]);
+    assert_eq!(Topology::Star.destinations(4, None), vec![vec![1, 2, 3], vec![0], vec![0], vec![0]]);
+    assert_eq!(Topology::Ring.sources(3, None), vec![vec![2], vec![0], vec![1]]);
+    assert_eq!(Topology::Star.sources(3, None), vec![vec![1, 2], vec![0], vec![0]]);
+
+    let destinations = Topology::Random(2).destinations(5, Some(42));
+    assert_eq!(destinations, Topology::Random(2).destinations(5, Some(42)));
+    for (source, destinations) in destinations.iter().enumerate() {
+      assert_eq!(destinations.len(), 2);
+      assert!(!destinations.contains(&source));
+      assert!(destinations[0] != destinations[1]);
+    }
+    assert_eq!(Topology::Random(5).destinations(3, Some(42)), Topology::FullyConnected.destinations(3, None));
+  }
+
+  #[test]
+  fn test_migration() {
+    let population = vec![vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+    let fitness_values = vec![1.0, 4.0, 0.5, 3.0];
+    assert!(select_emigrants(&population, &vec![1.0], 1, EmigrantPolicy::Best, None).is_err());
+    assert!(select_emigrants(&population, &fitness_values, 5, EmigrantPolicy::Best, None).is_err());
+    assert_eq!(
+      select_emigrants(&population, &fitness_values, 2, EmigrantPolicy::Best, None).unwrap(),
+      vec![(vec![3, 4], 4.0), (vec![7, 8], 3.0)]
+    );
+    let emigrants = select_emigrants(&population, &fitness_values, 4, EmigrantPolicy::Random, Some(42)).unwrap();
+    assert_eq!(emigrants.len(), 4);
+    for individual in population.iter() {
+      assert!(emigrants.iter().any(|(x, _)| x == individual));
+    }
+
+    let mut receiving = population.clone();
+    let mut receiving_fitness = fitness_values.clone();
+    let immigrants = vec![(vec![0, 0], 2.0), (vec![9, 9], 5.0)];
+    insert_immigrants(&mut receiving, &mut receiving_fitness, immigrants.clone(), ImmigrantPolicy::ReplaceWorst, None).unwrap();
+    assert_eq!(receiving, vec![vec![9, 9], vec![3, 4], vec![0, 0], vec![7, 8]]);
+    assert_eq!(receiving_fitness, vec![5.0, 4.0, 2.0, 3.0]);
+
+    let mut receiving = population.clone();
+    let mut receiving_fitness = fitness_values.clone();
+    insert_immigrants(&mut receiving, &mut receiving_fitness, immigrants, ImmigrantPolicy::ReplaceRandom, Some(42)).unwrap();
+    assert_eq!(receiving.iter().filter(|x| population.contains(x)).count(), 2);
+
+    // only the fittest immigrants are kept when there are too many
+    let mut receiving = vec![vec![1, 2]];
+    let mut receiving_fitness = vec![1.0];
+    let immigrants = vec![(vec![0, 0], 2.0), (vec![9, 9], 5.0)];
+    insert_immigrants(&mut receiving, &mut receiving_fitness, immigrants, ImmigrantPolicy::ReplaceWorst, None).unwrap();
+    assert_eq!(receiving, vec![vec![9, 9]]);
+  }
+
+  #[test]
+  fn test_island_model_migration() {
+    let migration = Migration::new(1, 1, EmigrantPolicy::Best, ImmigrantPolicy::ReplaceWorst);
+    assert!(IslandModel::<f32>::new(vec![], Topology::Ring, migration).is_err());
+    let island = Island::new(vec![1.0], vec![1.0], |_: &mut Vec<f32>, _: &mut Vec<f32>, _, _| {});
+    assert!(IslandModel::new(vec![island], Topology::Ring, Migration { interval: 0, ..migration }).is_err());
+    let island = Island::new(vec![1.0], vec![], |_: &mut Vec<f32>, _: &mut Vec<f32>, _, _| {});
+    assert!(IslandModel::new(vec![island], Topology::Ring, migration).is_err());
+
+    // islands which do not evolve, so that only migration spreads the best individual
+    let islands = (0..4)
+      .map(|idx| {
+        let value = idx as f32;
+        Island::new(vec![value; 3], vec![value; 3], |_: &mut Vec<f32>, _: &mut Vec<f32>, _, _| {})
+      })
+      .collect();
+    let mut model = IslandModel::new(islands, Topology::Ring, migration).unwrap();
+    model.step(Some(42)).unwrap();
+    assert_eq!(model.islands()[0].population, vec![3.0, 0.0, 0.0]);
+    assert_eq!(model.islands()[1].population, vec![0.0, 1.0, 1.0]);
+    model.run(2, Some(42)).unwrap();
+    assert_eq!(model.generation(), 3);
+    for island in model.islands() {
+      assert_eq!(island.best(), Some((&3.0, 3.0)));
+    }
+
+    // migrations larger than a population are rejected before the first generation
+    let migration = Migration::new(5, 1, EmigrantPolicy::Best, ImmigrantPolicy::ReplaceWorst);
+    let islands = vec![Island::new(vec![1.0], vec![1.0], |_: &mut Vec<f32>, _: &mut Vec<f32>, _, _| {})];
+    assert!(IslandModel::new(islands, Topology::Ring, migration).is_err());
+  }
+
+  #[test]
+  fn test_island_model_evolution() {
+    let run = |seed| {
+      let islands = vec![0.02, 0.05, 0.1, 0.2]
+        .into_iter()
+        .map(|mutation_probability| Island::new(vec![vec![false; 32]; 8], vec![0.0; 8], hill_climber(mutation_probability)))
+        .collect();
+      let migration = Migration::new(2, 5, EmigrantPolicy::Best, ImmigrantPolicy::ReplaceWorst);
+      let mut model = IslandModel::new(islands, Topology::Random(1), migration).unwrap();
+      model.run(100, seed).unwrap();
+      model.islands().iter().map(|island| island.population.clone()).collect::<Vec<Vec<Vec<bool>>>>()
+    };
+    let populations = run(Some(42));
+    assert_eq!(populations, run(Some(42)));
+    let best = populations.iter().flatten().map(|x| one_max(x)).fold(0.0, f32::max);
+    assert!(best > 28.0);
+  }
+}
@@ -2,7 +2,9 @@ extern crate genx;
 
 #[cfg(test)]
 mod tests {
-  use genx::mutation::{flipping::flipping_mutation, inversion::inversion_mutation, polynomial::polynomial_mutation, random::random_mutation, scramble::scramble_mutation, swap::swap_mutation};
+  use genx::genome::{BitGenome, CompositeGenome, Segment, SegmentCrossover, SegmentMutation, SegmentOperator};
+  use genx::crossover::order_crossover;
+  use genx::mutation::{insertion::insertion_mutation, deletion::deletion_mutation, duplication::duplication_mutation, composite::composite_mutation, packed_flipping::packed_flipping_mutation, creep::creep_mutation, random_resetting::random_resetting_mutation, flipping::flipping_mutation, inversion::inversion_mutation, polynomial::polynomial_mutation, random::random_mutation, scramble::scramble_mutation, swap::swap_mutation};
 
   #[test]
   fn test_inversion_mutation() {
@@ -82,4 +84,112 @@ mod tests {
     let result = polynomial_mutation(individual, 4.2, 4.0, Some(43));
     assert_ne!(result, individual);
   }
+
+  #[test]
+  fn test_creep_mutation() {
+    let mut individual = vec![0, 3, 9, 5, 1, 8, 2, 7, 4, 6];
+    let original_individual = individual.clone();
+    let bounds = vec![(0, 9); individual.len()];
+    match creep_mutation(&mut individual, &bounds, 2, 0.5, Some(43)) {
+      Ok(_) => (),
+      Err(error) => panic!("{:?}", error)
+    };
+    assert_ne!(original_individual, individual);
+    for (x, y) in individual.iter().zip(original_individual.iter()) {
+      assert!((0..=9).contains(x));
+      assert!((x - y).abs() <= 2);
+    }
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_creep_mutation_fail() {
+    let mut individual = vec![0, 3, 9];
+    match creep_mutation(&mut individual, &vec![(0, 9)], 2, 0.5, Some(43)) {
+      Ok(_) => (),
+      Err(error) => panic!("{}", error)
+    };
+  }
+
+  #[test]
+  fn test_random_resetting_mutation() {
+    let mut individual = vec![0, 3, 9, 5, 1, 8, 2, 7, 4, 6];
+    let original_individual = individual.clone();
+    let bounds = (0..10).map(|x| (x, x + 5)).collect::<Vec<(i32, i32)>>();
+    match random_resetting_mutation(&mut individual, &bounds, 1.0, Some(43)) {
+      Ok(_) => (),
+      Err(error) => panic!("{:?}", error)
+    };
+    assert_ne!(original_individual, individual);
+    for (x, &(lower, upper)) in individual.iter().zip(bounds.iter()) {
+      assert!(lower <= *x && *x <= upper);
+    }
+  }
+
+  #[test]
+  fn test_packed_flipping_mutation() {
+    let mut individual = BitGenome::new(100_000);
+    match packed_flipping_mutation(&mut individual, 0.01, Some(43)) {
+      Ok(_) => (),
+      Err(error) => panic!("{:?}", error)
+    };
+    let flipped = individual.count_ones();
+    assert!(flipped > 800 && flipped < 1200);
+
+    match packed_flipping_mutation(&mut individual, 1.0, Some(43)) {
+      Ok(_) => (),
+      Err(error) => panic!("{:?}", error)
+    };
+    assert_eq!(individual.count_ones(), 100_000 - flipped);
+  }
+
+  #[test]
+  fn test_composite_mutation() {
+    let operators = vec![
+      SegmentOperator::new(SegmentCrossover::permutation(order_crossover), 1.0, SegmentMutation::permutation(|individual, seed| swap_mutation(individual, seed).unwrap()), 1.0),
+      SegmentOperator::new(SegmentCrossover::integer(|a, b, _| (a.clone(), b.clone())), 1.0, SegmentMutation::integer(|individual, seed| creep_mutation(individual, &vec![(0, 9); 4], 1, 1.0, seed).unwrap()), 0.0),
+      SegmentOperator::new(SegmentCrossover::real(|a, b, _| (a, b)), 1.0, SegmentMutation::real(|gene, seed| random_mutation(gene, 1.0, seed)), 1.0),
+    ];
+    let mut individual = CompositeGenome::new(vec![Segment::Permutation(vec![0, 1, 2, 3]), Segment::Integer(vec![4, 4, 4, 4]), Segment::Real(vec![0.0, 0.0])]);
+    let original_individual = individual.clone();
+    composite_mutation(&mut individual, &operators, Some(43));
+    assert_ne!(individual.segments[0], original_individual.segments[0]);
+    assert_eq!(individual.segments[1], original_individual.segments[1]);
+    match &individual.segments[2] {
+      Segment::Real(genes) => assert!(genes.iter().all(|&x| x != 0.0 && x.abs() <= 1.0)),
+      _ => panic!("Segment kinds changed"),
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "Segment kinds must match their operators")]
+  fn test_composite_mutation_fail() {
+    let operators = vec![SegmentOperator::new(SegmentCrossover::real(|a, b, _| (a, b)), 1.0, SegmentMutation::real(|gene, _| gene), 0.0)];
+    let mut individual = CompositeGenome::new(vec![Segment::Integer(vec![4, 4])]);
+    // the kinds are checked even when no segment is mutated
+    composite_mutation(&mut individual, &operators, Some(43));
+  }
+
+  #[test]
+  fn test_variable_length_mutation() {
+    let mut individual = vec![1, 2, 3];
+    insertion_mutation(&mut individual, 7, 4, Some(43));
+    assert_eq!(individual.len(), 4);
+    insertion_mutation(&mut individual, 8, 4, Some(43));
+    assert_eq!(individual.len(), 4);
+    assert!(individual.contains(&7) && !individual.contains(&8));
+
+    let removed = deletion_mutation(&mut individual, 3, Some(43)).unwrap();
+    assert_eq!(individual.len(), 3);
+    assert!(!individual.contains(&removed) || removed == 7);
+    assert_eq!(deletion_mutation(&mut individual, 3, Some(43)), None);
+
+    for seed in 0..20 {
+      let mut individual = vec![1, 2, 3, 4, 5];
+      duplication_mutation(&mut individual, 7, Some(seed));
+      assert!(individual.len() > 5 && individual.len() <= 7);
+      assert_eq!(individual[..1], [1]);
+      assert_eq!(individual[individual.len() - 1], 5);
+    }
+  }
 }
@@ -0,0 +1,163 @@
+extern crate genx;
+
+#[cfg(test)]
+mod tests {
+  use genx::{mutation::flipping_mutation, quality_diversity::{novelty_scores, random_elite_selection, weighted_novelty_fitness, Archive, ArchivePolicy, CvtArchive, GridArchive, MutationEmitter, NoveltyArchive}, selection::tournament_selection};
+
+  #[test]
+  fn test_grid_archive() {
+    assert!(GridArchive::<f32>::new(vec![(0.0, 1.0)], vec![2, 2]).is_err());
+    assert!(GridArchive::<f32>::new(vec![(0.0, 1.0)], vec![0]).is_err());
+    assert!(GridArchive::<f32>::new(vec![(1.0, 1.0)], vec![2]).is_err());
+
+    let mut archive = GridArchive::new(vec![(0.0, 10.0), (0.0, 1.0)], vec![5, 2]).unwrap();
+    assert_eq!(archive.num_cells(), 10);
+    assert_eq!(archive.cell_of(&vec![3.0, 0.7]), Some(3));
+    assert_eq!(archive.coordinates(3), vec![1, 1]);
+    // descriptors out of bounds are clamped into the border cells
+    assert_eq!(archive.cell_of(&vec![-5.0, 2.0]), Some(1));
+    assert_eq!(archive.cell_of(&vec![10.0, 1.0]), Some(9));
+    assert_eq!(archive.cell_of(&vec![1.0]), None);
+
+    assert!(archive.insert('a', 1.0, vec![3.0, 0.7]));
+    assert!(!archive.insert('b', 0.5, vec![2.5, 0.9]));
+    assert!(archive.insert('c', 2.0, vec![2.5, 0.9]));
+    assert!(archive.insert('d', 4.0, vec![9.0, 0.1]));
+    assert!(!archive.insert('e', 4.0, vec![9.0]));
+    // non finite fitness values never evict an elite
+    assert!(!archive.insert('f', f32::NAN, vec![2.5, 0.9]));
+    assert!(!archive.insert('g', f32::INFINITY, vec![0.5, 0.5]));
+
+    assert_eq!(archive.get(3).unwrap().individual, 'c');
+    assert_eq!(archive.len(), 2);
+    assert_eq!(archive.coverage(), 0.2);
+    assert_eq!(archive.qd_score(0.0), 6.0);
+    assert_eq!(archive.qd_score(-1.0), 8.0);
+    assert_eq!(archive.best().unwrap().individual, 'd');
+  }
+
+  #[test]
+  fn test_cvt_archive() {
+    assert!(CvtArchive::<f32>::new(vec![(0.0, 1.0)], 10, 5, 1, None).is_err());
+    assert!(CvtArchive::<f32>::with_centroids(vec![]).is_err());
+    assert!(CvtArchive::<f32>::with_centroids(vec![vec![0.0], vec![0.0, 1.0]]).is_err());
+
+    let archive = CvtArchive::<f32>::new(vec![(0.0, 1.0), (0.0, 1.0)], 16, 2000, 20, Some(42)).unwrap();
+    assert_eq!(archive.num_cells(), 16);
+    assert!(archive.centroids().iter().all(|centroid| centroid.iter().all(|&x| (0.0..=1.0).contains(&x))));
+    // the centroids spread over the space, so every centroid is its own nearest one
+    for (idx, centroid) in archive.centroids().iter().enumerate() {
+      assert_eq!(archive.cell_of(centroid), Some(idx));
+    }
+    assert_eq!(archive.centroids(), CvtArchive::<f32>::new(vec![(0.0, 1.0), (0.0, 1.0)], 16, 2000, 20, Some(42)).unwrap().centroids());
+
+    let mut archive = CvtArchive::with_centroids(vec![vec![0.0, 0.0], vec![1.0, 1.0]]).unwrap();
+    assert!(archive.insert(1, 1.0, vec![0.2, 0.1]));
+    assert!(archive.insert(2, 1.0, vec![0.8, 0.7]));
+    assert!(!archive.insert(3, 0.5, vec![0.9, 0.9]));
+    assert!(!archive.insert(4, f32::NAN, vec![0.9, 0.9]));
+    assert_eq!(archive.coverage(), 1.0);
+    assert_eq!(archive.get(1).unwrap().individual, 2);
+  }
+
+  #[test]
+  fn test_random_elite_selection() {
+    let mut archive = GridArchive::new(vec![(0.0, 3.0)], vec![3]).unwrap();
+    assert!(random_elite_selection(&archive, 5, Some(42)).is_empty());
+
+    archive.insert(0, 1.0, vec![0.5]);
+    archive.insert(1, 1.0, vec![1.5]);
+    archive.insert(2, 1.0, vec![2.5]);
+    let parents = random_elite_selection(&archive, 300, Some(42));
+    assert_eq!(parents.len(), 300);
+    for idx in 0..3 {
+      assert!(parents.iter().filter(|elite| elite.individual == idx).count() > 70);
+    }
+  }
+
+  #[test]
+  fn test_mutation_emitter() {
+    let weights = [9, 13, 15, 50, 15, 68, 27, 39];
+    let evaluate = |individual: &Vec<bool>| {
+      let weight = individual.iter().zip(weights.iter()).filter(|(&x, _)| x).map(|(_, &w)| w).sum::<i32>();
+      (individual.iter().filter(|&&x| x).count() as f32, vec![weight as f32])
+    };
+    let mut archive = GridArchive::new(vec![(0.0, 240.0)], vec![24]).unwrap();
+    let emitter = MutationEmitter::new(|x: &mut Vec<bool>, seed| flipping_mutation(x, 0.3, seed).unwrap(), 10);
+    assert!(emitter.emit(&archive, Some(42)).is_empty());
+
+    let individual = vec![false; 8];
+    let (fitness, descriptor) = evaluate(&individual);
+    archive.insert(individual, fitness, descriptor);
+
+    let offsprings = emitter.emit(&archive, Some(42));
+    assert_eq!(offsprings.len(), 10);
+    assert_eq!(offsprings, emitter.emit(&archive, Some(42)));
+
+    let mut previous = archive.qd_score(0.0);
+    for seed in 0..100 {
+      emitter.step(&mut archive, evaluate, Some(seed));
+      // elites are only ever replaced by fitter individuals
+      assert!(archive.qd_score(0.0) >= previous);
+      previous = archive.qd_score(0.0);
+    }
+    assert!(archive.coverage() > 0.5);
+  }
+
+  #[test]
+  fn test_novelty_scores() {
+    let behaviours = vec![vec![0.0, 0.0], vec![3.0, 4.0], vec![0.0, 1.0]];
+    assert_eq!(novelty_scores(&behaviours, &vec![], 1), vec![1.0, 4.2426405, 1.0]);
+    assert_eq!(novelty_scores(&behaviours, &vec![vec![3.0, 3.0]], 1), vec![1.0, 1.0, 1.0]);
+    assert_eq!(novelty_scores(&behaviours, &vec![], 5), vec![3.0, 4.6213202, 2.6213202]);
+    assert_eq!(novelty_scores(&vec![vec![1.0]], &vec![], 3), vec![0.0]);
+    assert!(novelty_scores(&vec![], &behaviours, 3).is_empty());
+  }
+
+  #[test]
+  fn test_novelty_archive() {
+    let population = vec![0.0, 1.0, 2.0, 10.0];
+    let characterize = |&x: &f32| vec![x];
+
+    let mut archive = NoveltyArchive::new(1, ArchivePolicy::Threshold(1.5), None).unwrap();
+    let novelty = archive.evaluate(&population, characterize, None);
+    assert_eq!(novelty, vec![1.0, 1.0, 1.0, 8.0]);
+    assert_eq!(archive.behaviours(), &vec![vec![10.0]]);
+    // the archived behaviour is no longer novel
+    assert_eq!(archive.novelty(&vec![vec![10.0], vec![20.0]]), vec![0.0, 10.0]);
+
+    let mut archive = NoveltyArchive::new(2, ArchivePolicy::MostNovel(2), Some(3)).unwrap();
+    assert!(archive.is_empty());
+    archive.evaluate(&population, characterize, None);
+    assert_eq!(archive.behaviours(), &vec![vec![0.0], vec![10.0]]);
+    archive.evaluate(&vec![5.0, 30.0], characterize, None);
+    assert_eq!(archive.behaviours(), &vec![vec![10.0], vec![5.0], vec![30.0]]);
+
+    assert!(NoveltyArchive::new(2, ArchivePolicy::Random(1.5), None).is_err());
+    assert!(NoveltyArchive::new(2, ArchivePolicy::Random(-0.5), None).is_err());
+    assert!(NoveltyArchive::new(2, ArchivePolicy::Random(f32::NAN), None).is_err());
+    let mut archive = NoveltyArchive::new(2, ArchivePolicy::Random(0.5), None).unwrap();
+    assert!(archive.update(vec![vec![0.0]; 3], &vec![0.0; 2], None).is_err());
+    assert!(archive.is_empty());
+    let added = archive.update(vec![vec![0.0]; 100], &vec![0.0; 100], Some(42)).unwrap();
+    assert_eq!(archive.len(), added);
+    assert!(added > 25 && added < 75);
+    let mut archive = NoveltyArchive::new(2, ArchivePolicy::Random(0.0), None).unwrap();
+    assert_eq!(archive.update(vec![vec![0.0]; 100], &vec![0.0; 100], Some(42)), Ok(0));
+  }
+
+  #[test]
+  fn test_weighted_novelty_fitness() {
+    assert!(weighted_novelty_fitness(&vec![1.0], &vec![1.0, 2.0], 0.5).is_err());
+    assert!(weighted_novelty_fitness(&vec![1.0], &vec![1.0], 1.5).is_err());
+    let novelty = vec![0.0, 2.0, 4.0];
+    let fitness_values = vec![10.0, 5.0, 0.0];
+    assert_eq!(weighted_novelty_fitness(&novelty, &fitness_values, 1.0).unwrap(), vec![0.0, 0.5, 1.0]);
+    assert_eq!(weighted_novelty_fitness(&novelty, &fitness_values, 0.0).unwrap(), vec![1.0, 0.5, 0.0]);
+    assert_eq!(weighted_novelty_fitness(&vec![3.0; 3], &fitness_values, 0.25).unwrap(), vec![0.75, 0.375, 0.0]);
+
+    let scores = weighted_novelty_fitness(&novelty, &fitness_values, 0.9).unwrap();
+    let selected = tournament_selection(&scores, 3, 3, Some(42));
+    assert_eq!(selected, vec![2, 2, 2]);
+  }
+}
@@ -0,0 +1,191 @@
+extern crate genx;
+
+#[cfg(test)]
+mod tests {
+  use genx::replacement::{deterministic_crowding, generation_gap_replacement, mu_comma_lambda_replacement, mu_plus_lambda_replacement, probabilistic_crowding, replace_oldest, replace_random, replace_worst, restricted_tournament_replacement, GenerationGapReplacement, MuCommaLambdaReplacement, MuPlusLambdaReplacement, ReplaceOldest, ReplaceRandom, ReplaceWorst, Replacement, RestrictedTournamentReplacement};
+
+  fn distance(a: &f32, b: &f32) -> f32 {
+    (a - b).abs()
+  }
+
+  #[test]
+  fn test_deterministic_crowding() {
+    let mut population = vec![0.0, 10.0, 20.0];
+    let mut fitness_values = vec![1.0, 2.0, 3.0];
+
+    // the first offspring is nearer to the second parent, so they are paired crosswise
+    let offsprings = ((9.0, 5.0), (1.0, 0.5));
+    let replaced = deterministic_crowding(&mut population, &mut fitness_values, (0, 1), offsprings, distance).unwrap();
+    assert_eq!(replaced, (true, false));
+    assert_eq!(population, vec![0.0, 9.0, 20.0]);
+    assert_eq!(fitness_values, vec![1.0, 5.0, 3.0]);
+
+    // competing for the same parent only the fitter winner stays
+    let offsprings = ((19.0, 4.0), (21.0, 6.0));
+    let replaced = deterministic_crowding(&mut population, &mut fitness_values, (2, 2), offsprings, distance).unwrap();
+    assert_eq!(replaced, (false, true));
+    assert_eq!(population[2], 21.0);
+
+    assert!(deterministic_crowding(&mut population, &mut fitness_values, (0, 3), ((0.0, 1.0), (0.0, 1.0)), distance).is_err());
+    assert!(deterministic_crowding(&mut population, &mut vec![1.0], (0, 1), ((0.0, 1.0), (0.0, 1.0)), distance).is_err());
+  }
+
+  #[test]
+  fn test_probabilistic_crowding() {
+    let mut wins = 0;
+    for seed in 0..1000 {
+      let mut population = vec![0.0, 10.0];
+      let mut fitness_values = vec![1.0, 1.0];
+      let offsprings = ((1.0, 3.0), (9.0, 0.0));
+      let replaced = probabilistic_crowding(&mut population, &mut fitness_values, (0, 1), offsprings, distance, Some(seed)).unwrap();
+      // an offspring of zero fitness never beats a parent of positive fitness
+      assert!(!replaced.1);
+      if replaced.0 {
+        wins += 1;
+        assert_eq!(population[0], 1.0);
+        assert_eq!(fitness_values[0], 3.0);
+      }
+    }
+    assert!((700..800).contains(&wins));
+
+    let mut population = vec![0.0, 10.0];
+    let mut fitness_values = vec![1.0, 1.0];
+    let first = probabilistic_crowding(&mut population.clone(), &mut fitness_values.clone(), (0, 1), ((1.0, 1.0), (9.0, 1.0)), distance, Some(42));
+    let second = probabilistic_crowding(&mut population, &mut fitness_values, (0, 1), ((1.0, 1.0), (9.0, 1.0)), distance, Some(42));
+    assert_eq!(first, second);
+  }
+
+  #[test]
+  fn test_restricted_tournament_replacement() {
+    let mut population = vec![0.0, 1.0, 5.0, 6.0, 10.0];
+    let mut fitness_values = vec![1.0, 2.0, 1.0, 0.5, 3.0];
+
+    // with a window over the whole population the nearest individual competes
+    let replaced = restricted_tournament_replacement(&mut population, &mut fitness_values, (5.8, 3.0), 10, distance, Some(42)).unwrap();
+    assert_eq!(replaced, Some(3));
+    assert_eq!(population, vec![0.0, 1.0, 5.0, 5.8, 10.0]);
+
+    let replaced = restricted_tournament_replacement(&mut population, &mut fitness_values, (9.0, 1.0), 10, distance, Some(42)).unwrap();
+    assert_eq!(replaced, None);
+    assert_eq!(fitness_values, vec![1.0, 2.0, 1.0, 3.0, 3.0]);
+
+    // a window of one competes with a random individual
+    let replaced = restricted_tournament_replacement(&mut population, &mut fitness_values, (0.0, 100.0), 1, distance, Some(7)).unwrap();
+    assert!(replaced.is_some());
+
+    assert!(restricted_tournament_replacement(&mut vec![], &mut vec![], (0.0, 1.0), 3, distance, None).unwrap().is_none());
+    assert!(restricted_tournament_replacement(&mut population, &mut vec![], (0.0, 1.0), 3, distance, None).is_err());
+  }
+
+  #[test]
+  fn test_mu_plus_lambda_replacement() {
+    let mut population = vec![0.0, 1.0, 2.0];
+    let mut fitness_values = vec![3.0, 1.0, 2.0];
+
+    mu_plus_lambda_replacement(&mut population, &mut fitness_values, vec![(3.0, 2.0), (4.0, 5.0)]).unwrap();
+    assert_eq!(population, vec![4.0, 0.0, 2.0]);
+    assert_eq!(fitness_values, vec![5.0, 3.0, 2.0]);
+
+    assert!(mu_plus_lambda_replacement(&mut population, &mut vec![1.0], vec![]).is_err());
+  }
+
+  #[test]
+  fn test_mu_comma_lambda_replacement() {
+    let mut population = vec![0.0, 1.0];
+    let mut fitness_values = vec![3.0, 1.0];
+
+    assert!(mu_comma_lambda_replacement(&mut population, &mut fitness_values, vec![(3.0, 2.0)]).is_err());
+    mu_comma_lambda_replacement(&mut population, &mut fitness_values, vec![(3.0, 2.0), (4.0, 0.5), (5.0, 1.5)]).unwrap();
+    // parents are discarded even when fitter
+    assert_eq!(population, vec![3.0, 5.0]);
+    assert_eq!(fitness_values, vec![2.0, 1.5]);
+  }
+
+  #[test]
+  fn test_replace_worst() {
+    let mut population = vec![0.0, 1.0, 2.0, 3.0];
+    let mut fitness_values = vec![3.0, 1.0, 2.0, 0.0];
+
+    replace_worst(&mut population, &mut fitness_values, vec![(4.0, 0.5), (5.0, 5.0)]).unwrap();
+    assert_eq!(population, vec![0.0, 5.0, 2.0, 4.0]);
+    assert_eq!(fitness_values, vec![3.0, 5.0, 2.0, 0.5]);
+
+    assert!(replace_worst(&mut vec![0.0], &mut vec![1.0], vec![(1.0, 1.0), (2.0, 2.0)]).is_err());
+  }
+
+  #[test]
+  fn test_replace_random() {
+    let mut population = vec![0.0, 1.0, 2.0, 3.0];
+    let mut fitness_values = vec![3.0, 1.0, 2.0, 0.0];
+
+    replace_random(&mut population, &mut fitness_values, vec![(10.0, 0.5), (11.0, 5.0)], Some(42)).unwrap();
+    assert_eq!(population.iter().filter(|&&x| x >= 10.0).count(), 2);
+    for (individual, fitness) in population.iter().zip(fitness_values.iter()) {
+      match *individual as i32 {
+        10 => assert_eq!(*fitness, 0.5),
+        11 => assert_eq!(*fitness, 5.0),
+        _ => {}
+      }
+    }
+
+    let mut other = vec![0.0, 1.0, 2.0, 3.0];
+    replace_random(&mut other, &mut vec![3.0, 1.0, 2.0, 0.0], vec![(10.0, 0.5), (11.0, 5.0)], Some(42)).unwrap();
+    assert_eq!(population, other);
+  }
+
+  #[test]
+  fn test_replace_oldest() {
+    let mut population = vec![0.0, 1.0, 2.0];
+    let mut fitness_values = vec![3.0, 1.0, 2.0];
+
+    replace_oldest(&mut population, &mut fitness_values, vec![(3.0, 0.0)]).unwrap();
+    replace_oldest(&mut population, &mut fitness_values, vec![(4.0, 0.0), (5.0, 9.0)]).unwrap();
+    assert_eq!(population, vec![3.0, 4.0, 5.0]);
+    assert_eq!(fitness_values, vec![0.0, 0.0, 9.0]);
+  }
+
+  #[test]
+  fn test_generation_gap_replacement() {
+    let mut population = vec![0.0, 1.0, 2.0, 3.0];
+    let mut fitness_values = vec![3.0, 1.0, 2.0, 0.0];
+
+    assert!(generation_gap_replacement(&mut population, &mut fitness_values, vec![(4.0, 1.0)], 0.0).is_err());
+    assert!(generation_gap_replacement(&mut population, &mut fitness_values, vec![(4.0, 1.0)], 0.5).is_err());
+
+    // a quarter of the population is replaced by the best offspring
+    generation_gap_replacement(&mut population, &mut fitness_values, vec![(4.0, 1.0), (5.0, 4.0)], 0.25).unwrap();
+    assert_eq!(population, vec![0.0, 1.0, 2.0, 5.0]);
+  }
+
+  #[test]
+  fn test_replacement_trait() {
+    let offsprings = || vec![(10.0, 0.5), (11.0, 5.0)];
+
+    let schemes: Vec<Box<dyn Replacement<f32>>> = vec![
+      Box::new(MuPlusLambdaReplacement),
+      Box::new(MuCommaLambdaReplacement),
+      Box::new(ReplaceWorst),
+      Box::new(ReplaceRandom),
+      Box::new(ReplaceOldest),
+      Box::new(GenerationGapReplacement::new(1.0).unwrap()),
+      Box::new(RestrictedTournamentReplacement::new(2, distance)),
+      Box::new(|population: &mut Vec<f32>, fitness_values: &mut Vec<f32>, offsprings: Vec<(f32, f32)>, seed: Option<u64>| {
+        replace_random(population, fitness_values, offsprings, seed)
+      }),
+    ];
+    for scheme in schemes.iter() {
+      let mut population = vec![0.0, 1.0];
+      let mut fitness_values = vec![3.0, 1.0];
+      scheme.replace(&mut population, &mut fitness_values, offsprings(), Some(42)).unwrap();
+      assert_eq!(population.len(), 2);
+      assert_eq!(fitness_values.len(), 2);
+      assert!(population.contains(&11.0));
+    }
+    assert!(GenerationGapReplacement::new(1.5).is_err());
+
+    let mut population = vec![0.0, 10.0];
+    let mut fitness_values = vec![1.0, 1.0];
+    RestrictedTournamentReplacement::new(2, distance).replace(&mut population, &mut fitness_values, vec![(9.0, 2.0), (1.0, 0.5)], Some(42)).unwrap();
+    assert_eq!(population, vec![0.0, 9.0]);
+  }
+}
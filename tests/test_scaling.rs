@@ -0,0 +1,225 @@
+extern crate genx;
+
+#[cfg(test)]
+mod tests {
+  use genx::{scaling::{boltzmann_scaling, exponential_scaling, linear_scaling, power_law_scaling, rank_scaling, top_scaling, BoltzmannScaling, ExponentialScaling, LinearScaling, PowerLawScaling, RankScaling, Scaling, SigmaScaling, TopScaling, WindowingScaling, fitness_sharing, clearing, clustering_niching}, selection::{stochastic_universal_selection, CoolingSchedule}};
+
+  #[test]
+  fn test_power_law_scaling() {
+    let mut fitness_values = vec![0.0, 1.0, 2.0, 4.0];
+    power_law_scaling(&mut fitness_values, 0.5);
+    assert_eq!(fitness_values, vec![0.0, 1.0, 2f32.sqrt(), 2.0]);
+  }
+
+  #[test]
+  fn test_exponential_scaling() {
+    let mut fitness_values = vec![-2.0, 0.0, 1.0];
+    exponential_scaling(&mut fitness_values, 0.5);
+    assert!(fitness_values.iter().all(|&x| x > 0.0));
+    assert!((fitness_values[2] / fitness_values[1] - 0.5f32.exp()).abs() < 1e-6);
+  }
+
+  #[test]
+  fn test_boltzmann_scaling() {
+    let original = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+    let schedule = CoolingSchedule::Exponential { initial_temperature: 10.0, cooling_rate: 0.5 };
+
+    let mut hot = original.clone();
+    boltzmann_scaling(&mut hot, schedule.temperature(0)).unwrap();
+    let mut cold = original.clone();
+    boltzmann_scaling(&mut cold, schedule.temperature(4)).unwrap();
+
+    let average = hot.iter().sum::<f32>() / hot.len() as f32;
+    assert!((average - 1.0).abs() < 1e-5);
+    // cooling makes the best individual stand out more
+    assert!(cold[17] > hot[17]);
+    assert!(cold[9] < hot[9]);
+
+    let mut huge = vec![1000.0, 999.0];
+    boltzmann_scaling(&mut huge, 1.0).unwrap();
+    assert!(huge.iter().all(|x| x.is_finite()));
+
+    let mut fitness_values = original.clone();
+    assert!(boltzmann_scaling(&mut fitness_values, 0.0).is_err());
+    assert!(boltzmann_scaling(&mut fitness_values, -1.0).is_err());
+    assert!(boltzmann_scaling(&mut fitness_values, f32::NAN).is_err());
+    assert_eq!(fitness_values, original);
+  }
+
+  #[test]
+  fn test_rank_scaling() {
+    let mut fitness_values = vec![2.4, -5.6, 2.4, 100.0, 0.6];
+    rank_scaling(&mut fitness_values);
+    assert_eq!(fitness_values, vec![3.5, 1.0, 3.5, 5.0, 2.0]);
+  }
+
+  #[test]
+  fn test_top_scaling() {
+    let mut fitness_values = vec![2.4, 5.6, 2.3, 1.2, 0.6, 4.4];
+    top_scaling(&mut fitness_values, 3);
+    assert_eq!(fitness_values, vec![1.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+
+    let mut fitness_values = vec![1.0, 2.0];
+    top_scaling(&mut fitness_values, 5);
+    assert_eq!(fitness_values, vec![1.0, 1.0]);
+  }
+
+  #[test]
+  fn test_windowing_scaling() {
+    let mut scaling = WindowingScaling::new(2);
+
+    let mut fitness_values = vec![1.0, 3.0];
+    scaling.scale(&mut fitness_values, 0);
+    assert_eq!(fitness_values, vec![0.0, 2.0]);
+
+    // the worst value of the previous generation is still in the window
+    let mut fitness_values = vec![5.0, 4.0];
+    scaling.scale(&mut fitness_values, 1);
+    assert_eq!(fitness_values, vec![4.0, 3.0]);
+
+    // calls within the same generation keep its worst value
+    let mut fitness_values = vec![6.0, 7.0];
+    scaling.scale(&mut fitness_values, 2);
+    assert_eq!(fitness_values, vec![2.0, 3.0]);
+    let mut fitness_values = vec![5.5, 7.0];
+    scaling.scale(&mut fitness_values, 2);
+    assert_eq!(scaling.history().iter().cloned().collect::<Vec<(usize, f32)>>(), vec![(1, 4.0), (2, 5.5)]);
+
+    // skipped generations leave the window
+    let mut fitness_values = vec![6.0, 7.0];
+    scaling.scale(&mut fitness_values, 5);
+    assert_eq!(fitness_values, vec![0.0, 1.0]);
+
+    scaling.reset();
+    assert!(scaling.history().is_empty());
+    assert_eq!(WindowingScaling::new(0).window(), 1);
+
+    // `scale_next` treats every call as a new generation
+    let mut fitness_values = vec![1.0, 3.0];
+    scaling.scale_next(&mut fitness_values);
+    let mut fitness_values = vec![5.0, 4.0];
+    scaling.scale_next(&mut fitness_values);
+    assert_eq!(fitness_values, vec![4.0, 3.0]);
+    assert_eq!(scaling.history().iter().cloned().collect::<Vec<(usize, f32)>>(), vec![(0, 1.0), (1, 4.0)]);
+    let mut fitness_values = vec![6.0, 7.0];
+    scaling.scale_next(&mut fitness_values);
+    assert_eq!(fitness_values, vec![2.0, 3.0]);
+    scaling.reset();
+    assert!(scaling.history().is_empty());
+  }
+
+  #[test]
+  fn test_scaling_trait() {
+    let original = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+    let mut expected = original.clone();
+    linear_scaling(&mut expected, 1.5);
+    let mut fitness_values = original.clone();
+    LinearScaling::new(1.5).scale(&mut fitness_values, 3);
+    assert_eq!(fitness_values, expected);
+
+    // the annealing temperature follows the generation
+    let schedule = CoolingSchedule::Linear { initial_temperature: 5.0, cooling_rate: 1.0, minimum_temperature: 1.0 };
+    let mut scaling = BoltzmannScaling::new(schedule).unwrap();
+    for generation in 0..6 {
+      let mut expected = original.clone();
+      boltzmann_scaling(&mut expected, schedule.temperature(generation)).unwrap();
+      let mut fitness_values = original.clone();
+      scaling.scale(&mut fitness_values, generation);
+      assert_eq!(fitness_values, expected);
+    }
+    assert_eq!(scaling.schedule(), schedule);
+
+    // schedules reaching non positive temperatures are rejected
+    assert!(BoltzmannScaling::new(CoolingSchedule::Linear { initial_temperature: 5.0, cooling_rate: 1.0, minimum_temperature: 0.0 }).is_err());
+    assert!(BoltzmannScaling::new(CoolingSchedule::Exponential { initial_temperature: 5.0, cooling_rate: 0.0 }).is_err());
+    assert!(BoltzmannScaling::new(CoolingSchedule::Exponential { initial_temperature: -5.0, cooling_rate: 0.5 }).is_err());
+    assert!(BoltzmannScaling::new(CoolingSchedule::Logarithmic { initial_temperature: f32::NAN }).is_err());
+    // while exponential cooling only underflows
+    let mut scaling = BoltzmannScaling::new(CoolingSchedule::Exponential { initial_temperature: 5.0, cooling_rate: 0.5 }).unwrap();
+    let mut fitness_values = original.clone();
+    scaling.scale(&mut fitness_values, 1000);
+    assert!(fitness_values.iter().all(|x| x.is_finite()));
+
+    let mut calls = 0;
+    let mut schemes: Vec<Box<dyn Scaling>> = vec![
+      Box::new(SigmaScaling::new(2.0)),
+      Box::new(PowerLawScaling::new(2.0)),
+      Box::new(ExponentialScaling::new(0.1)),
+      Box::new(RankScaling),
+      Box::new(TopScaling::new(4)),
+      Box::new(WindowingScaling::new(3)),
+      Box::new(|fitness_values: &mut Vec<f32>, generation: usize| {
+        calls += 1;
+        fitness_values.iter_mut().for_each(|x| *x *= generation as f32)
+      }),
+    ];
+    for scaling in schemes.iter_mut() {
+      let mut fitness_values = original.clone();
+      scaling.scale(&mut fitness_values, 1);
+      scaling.reset();
+      assert!(fitness_values.iter().all(|&x| x.is_finite() && x >= 0.0));
+    }
+    drop(schemes);
+    assert_eq!(calls, 1);
+  }
+
+  #[test]
+  fn test_fitness_sharing() {
+    let population = vec![0.0, 0.5, 1.0, 10.0];
+    let distance = |a: &f32, b: &f32| (a - b).abs();
+
+    let mut fitness_values = vec![4.0, 4.0, 4.0, 4.0];
+    fitness_sharing(&mut fitness_values, &population, distance, 1.0, 1.0).unwrap();
+    // niche counts are 1.5, 2.0, 1.5 and 1.0
+    assert_eq!(fitness_values, vec![4.0 / 1.5, 2.0, 4.0 / 1.5, 4.0]);
+
+    let mut fitness_values = vec![4.0, 4.0, 4.0, 4.0];
+    fitness_sharing(&mut fitness_values, &population, distance, 1.0, 2.0).unwrap();
+    assert_eq!(fitness_values[1], 4.0 / 2.5);
+
+    assert!(fitness_sharing(&mut vec![1.0], &population, distance, 1.0, 1.0).is_err());
+    assert!(fitness_sharing(&mut vec![1.0; 4], &population, distance, 0.0, 1.0).is_err());
+    assert!(fitness_sharing(&mut vec![1.0; 4], &population, distance, 1.0, 0.0).is_err());
+  }
+
+  #[test]
+  fn test_clearing() {
+    let population = vec![0.0, 0.5, 0.9, 1.2, 10.0, 10.1];
+    let distance = |a: &f32, b: &f32| (a - b).abs();
+
+    let mut fitness_values = vec![5.0, 4.0, 3.0, 2.0, 1.0, 0.5];
+    clearing(&mut fitness_values, &population, distance, 1.0, 1).unwrap();
+    assert_eq!(fitness_values, vec![5.0, 0.0, 0.0, 2.0, 1.0, 0.0]);
+
+    let mut fitness_values = vec![5.0, 4.0, 3.0, 2.0, 1.0, 0.5];
+    clearing(&mut fitness_values, &population, distance, 1.0, 2).unwrap();
+    assert_eq!(fitness_values, vec![5.0, 4.0, 0.0, 2.0, 1.0, 0.5]);
+
+    assert!(clearing(&mut vec![1.0; 6], &population, distance, 1.0, 0).is_err());
+    assert!(clearing(&mut vec![1.0; 2], &population, distance, 1.0, 1).is_err());
+  }
+
+  #[test]
+  fn test_clustering_niching() {
+    let population = vec![0.0, 0.5, 0.9, 1.2, 10.0, 10.1];
+    let distance = |a: &f32, b: &f32| (a - b).abs();
+
+    let mut fitness_values = vec![5.0, 4.0, 3.0, 2.0, 1.0, 0.5];
+    let leaders = clustering_niching(&mut fitness_values, &population, distance, 1.0, 1.0).unwrap();
+    assert_eq!(leaders, vec![0, 0, 0, 3, 4, 4]);
+    assert_eq!(fitness_values[0], 5.0 / 3.0);
+    assert_eq!(fitness_values[1], 4.0 / (3.0 * 0.75));
+    assert_eq!(fitness_values[3], 2.0);
+
+    // niching lets a proportional selection keep the lone niche alive
+    let mut fitness_values = vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+    clustering_niching(&mut fitness_values, &population, distance, 1.0, 1.0).unwrap();
+    let result = stochastic_universal_selection(&fitness_values, 6, Some(42));
+    assert!(result.contains(&3));
+
+    assert!(clustering_niching(&mut vec![1.0; 6], &population, distance, 0.0, 1.0).is_err());
+    assert!(clustering_niching(&mut vec![1.0; 6], &population, distance, 1.0, 0.0).is_err());
+    assert!(clustering_niching(&mut vec![1.0; 6], &population, distance, 1.0, -1.0).is_err());
+  }
+}
@@ -2,7 +2,7 @@ extern crate genx;
 
 #[cfg(test)]
 mod tests {
-    use genx::selection::{random::random_selection, rank::rank_selection, roulette_wheel::roulette_wheel_selection, steady_state::steady_state_selection, stochastic_universal::stochastic_universal_selection, tournament::tournament_selection};
+    use genx::selection::{group::{tournament_group_selection, stochastic_universal_group_selection}, linear_ranking::linear_ranking_selection, exponential_ranking::exponential_ranking_selection, Sampling, Selection, BoltzmannSelection, ExponentialRankingSelection, FitnessUniformSelection, LinearRankingSelection, RandomSelection, RankSelection, RouletteWheelSelection, SteadyStateSelection, StochasticUniversalSelection, TournamentSelection, TruncationSelection, CoolingSchedule, boltzmann::boltzmann_selection, fitness_uniform::fitness_uniform_selection, truncation::truncation_selection, lexicase::{lexicase_selection, epsilon_lexicase_selection, downsampled_lexicase_selection}, ProportionalSampler, BinaryTournamentSelection, ProbabilisticTournamentSelection, TournamentWithoutReplacementSelection, binary_tournament::binary_tournament_selection, probabilistic_tournament::probabilistic_tournament_selection, tournament_without_replacement::tournament_without_replacement_selection, FitnessTransform, Objective, objective::{check_proportional_fitness, transform_fitness}, random::random_selection, rank::rank_selection, roulette_wheel::roulette_wheel_selection, roulette_wheel_selection_with, stochastic_universal_selection_with, steady_state::steady_state_selection, stochastic_universal::stochastic_universal_selection, tournament::tournament_selection};
 
     #[test]
     fn test_random_selection() {
@@ -57,4 +57,399 @@ mod tests {
       let result = tournament_selection(&fitness_values, num_parents, 5, Some(42)).iter().map(|&a| fitness_values[a]).collect::<Vec<f32>>();
       assert_eq!(result,[11.0, 10.0, 5.6, 9.0, 8.4, 10.0, 9.4, 11.0, 9.4, 10.0]);
     }
+
+    #[test]
+    fn test_group_selection() {
+      let fitness_values = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+      let groups = tournament_group_selection(&fitness_values, 5, 3, 4, Some(42));
+      assert_eq!(groups.len(), 5);
+      assert!(groups.iter().all(|group| group.len() == 3 && group.iter().all(|&x| x < fitness_values.len())));
+      assert_eq!(groups, tournament_group_selection(&fitness_values, 5, 3, 4, Some(42)));
+
+      let groups = stochastic_universal_group_selection(&fitness_values, 5, 3, Some(42));
+      assert_eq!(groups.len(), 5);
+      let mut selected = groups.concat();
+      selected.sort();
+      let mut expected = stochastic_universal_selection(&fitness_values, 15, Some(42));
+      expected.sort();
+      assert_eq!(selected, expected);
+    }
+
+    #[test]
+    fn test_linear_ranking_selection() {
+      let fitness_values = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+      assert!(linear_ranking_selection(&fitness_values, 10, 2.5, Sampling::RouletteWheel, None).is_err());
+      assert!(linear_ranking_selection(&fitness_values, 10, 0.5, Sampling::RouletteWheel, None).is_err());
+
+      let result = linear_ranking_selection(&fitness_values, 10, 1.8, Sampling::RouletteWheel, Some(42)).unwrap();
+      assert_eq!(result.len(), 10);
+      assert_eq!(result, linear_ranking_selection(&fitness_values, 10, 1.8, Sampling::RouletteWheel, Some(42)).unwrap());
+
+      // maximum pressure never selects the worst individual
+      let result = linear_ranking_selection(&fitness_values, 100, 2.0, Sampling::StochasticUniversal, Some(42)).unwrap();
+      assert_eq!(result.len(), 100);
+      assert!(result.iter().all(|&x| x != 9));
+
+      // no pressure selects every individual almost equally often
+      let result = linear_ranking_selection(&fitness_values, 190, 1.0, Sampling::StochasticUniversal, Some(42)).unwrap();
+      for idx in 0..fitness_values.len() {
+        let count = result.iter().filter(|&&x| x == idx).count();
+        assert!((9..=11).contains(&count));
+      }
+
+      for sampling in [Sampling::RouletteWheel, Sampling::StochasticUniversal].iter().cloned() {
+        assert_eq!(linear_ranking_selection(&fitness_values, 0, 1.8, sampling, Some(42)), Ok(vec![]));
+        assert_eq!(linear_ranking_selection(&vec![], 10, 1.8, sampling, Some(42)), Ok(vec![]));
+      }
+    }
+
+    #[test]
+    fn test_exponential_ranking_selection() {
+      let fitness_values = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+      assert!(exponential_ranking_selection(&fitness_values, 10, 0.0, Sampling::RouletteWheel, None).is_err());
+      assert!(exponential_ranking_selection(&fitness_values, 10, 1.5, Sampling::RouletteWheel, None).is_err());
+
+      let result = exponential_ranking_selection(&fitness_values, 10, 0.8, Sampling::RouletteWheel, Some(42)).unwrap();
+      assert_eq!(result.len(), 10);
+      assert_eq!(result, exponential_ranking_selection(&fitness_values, 10, 0.8, Sampling::RouletteWheel, Some(42)).unwrap());
+
+      // a small base concentrates the selection on the best individuals
+      let result = exponential_ranking_selection(&fitness_values, 20, 0.2, Sampling::StochasticUniversal, Some(42)).unwrap();
+      assert!(result.iter().filter(|&&x| x == 17).count() >= 15);
+
+      // tied individuals are selected equally often
+      let fitness_values = vec![1.0, 3.0, 3.0, 2.0];
+      let result = exponential_ranking_selection(&fitness_values, 100, 0.5, Sampling::StochasticUniversal, Some(42)).unwrap();
+      let count = |idx: usize| result.iter().filter(|&&x| x == idx).count() as i32;
+      assert!((count(1) - count(2)).abs() <= 1);
+
+      for sampling in [Sampling::RouletteWheel, Sampling::StochasticUniversal].iter().cloned() {
+        assert_eq!(exponential_ranking_selection(&fitness_values, 0, 0.5, sampling, Some(42)), Ok(vec![]));
+        assert_eq!(exponential_ranking_selection(&vec![], 10, 0.5, sampling, Some(42)), Ok(vec![]));
+      }
+    }
+
+    #[test]
+    fn test_truncation_selection() {
+      let fitness_values = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+      assert!(truncation_selection(&fitness_values, 10, 0.0, None).is_err());
+      assert!(truncation_selection(&fitness_values, 10, 1.2, None).is_err());
+
+      let result = truncation_selection(&fitness_values, 50, 0.2, Some(42)).unwrap().iter().map(|&a| fitness_values[a]).collect::<Vec<f32>>();
+      assert_eq!(result.len(), 50);
+      assert!(result.iter().all(|&x| x >= 9.0));
+      assert_eq!(truncation_selection(&fitness_values, 5, 0.01, Some(42)).unwrap(), [17, 17, 17, 17, 17]);
+    }
+
+    #[test]
+    fn test_boltzmann_selection() {
+      let fitness_values = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+      assert!(boltzmann_selection(&fitness_values, 10, 0.0, None).is_err());
+
+      let result = boltzmann_selection(&fitness_values, 10, 1.0, Some(42)).unwrap();
+      assert_eq!(result.len(), 10);
+      assert_eq!(result, boltzmann_selection(&fitness_values, 10, 1.0, Some(42)).unwrap());
+
+      // a very low temperature only selects the fittest individual
+      let result = boltzmann_selection(&fitness_values, 10, 0.01, Some(42)).unwrap();
+      assert!(result.iter().all(|&x| x == 17));
+
+      let schedule = CoolingSchedule::Exponential { initial_temperature: 10.0, cooling_rate: 0.5 };
+      assert_eq!(schedule.temperature(0), 10.0);
+      assert_eq!(schedule.temperature(2), 2.5);
+      let schedule = CoolingSchedule::Linear { initial_temperature: 10.0, cooling_rate: 3.0, minimum_temperature: 0.5 };
+      assert_eq!(schedule.temperature(1), 7.0);
+      assert_eq!(schedule.temperature(10), 0.5);
+      let schedule = CoolingSchedule::Logarithmic { initial_temperature: 10.0 };
+      assert!((schedule.temperature(0) - 10.0).abs() < 1e-5);
+      assert!(schedule.temperature(5) < schedule.temperature(4));
+    }
+
+    #[test]
+    fn test_fitness_uniform_selection() {
+      // a crowded fitness level and two isolated individuals
+      let fitness_values = vec![0.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 10.0];
+
+      let result = fitness_uniform_selection(&fitness_values, 1000, Some(42));
+      assert_eq!(result.len(), 1000);
+      assert_eq!(result, fitness_uniform_selection(&fitness_values, 1000, Some(42)));
+      let count = |idx: usize| result.iter().filter(|&&x| x == idx).count();
+      assert!(count(0) > 150 && count(7) > 150);
+      assert!((1..7).all(|idx| count(idx) < 150));
+
+      assert_eq!(fitness_uniform_selection(&vec![3.0; 4], 3, Some(7)).len(), 3);
+      assert!(fitness_uniform_selection(&vec![], 3, Some(7)).is_empty());
+    }
+
+    #[test]
+    fn test_selection_trait() {
+      let fitness_values = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+      assert_eq!(TournamentSelection::new(5).select(&fitness_values, 10, Some(42)), tournament_selection(&fitness_values, 10, 5, Some(42)));
+      assert_eq!(SteadyStateSelection.select(&fitness_values, 10, None), steady_state_selection(&fitness_values, 10));
+      assert_eq!(
+        LinearRankingSelection::new(1.5, Sampling::StochasticUniversal).unwrap().select(&fitness_values, 10, Some(42)),
+        linear_ranking_selection(&fitness_values, 10, 1.5, Sampling::StochasticUniversal, Some(42)).unwrap()
+      );
+      assert!(LinearRankingSelection::new(3.0, Sampling::RouletteWheel).is_err());
+      assert!(ExponentialRankingSelection::new(0.0, Sampling::RouletteWheel).is_err());
+      assert!(TruncationSelection::new(0.0).is_err());
+
+      let mut boltzmann = BoltzmannSelection::new(2.0).unwrap();
+      assert!(boltzmann.set_temperature(-1.0).is_err());
+      assert_eq!(boltzmann.temperature(), 2.0);
+      boltzmann.set_temperature(1.0).unwrap();
+      assert_eq!(boltzmann.select(&fitness_values, 10, Some(42)), boltzmann_selection(&fitness_values, 10, 1.0, Some(42)).unwrap());
+
+      let schemes: Vec<Box<dyn Selection>> = vec![
+        Box::new(RandomSelection),
+        Box::new(RankSelection),
+        Box::new(RouletteWheelSelection),
+        Box::new(StochasticUniversalSelection),
+        Box::new(ExponentialRankingSelection::new(0.9, Sampling::RouletteWheel).unwrap()),
+        Box::new(TruncationSelection::new(0.5).unwrap()),
+        Box::new(FitnessUniformSelection),
+      ];
+      for scheme in schemes.iter() {
+        let result = scheme.select(&fitness_values, 10, Some(42));
+        assert_eq!(result.len(), 10);
+        assert!(result.iter().all(|&x| x < fitness_values.len()));
+      }
+    }
+
+    #[test]
+    fn test_lexicase_selection() {
+      // the first two individuals are specialists, the third has the lowest total error but is never the best on a case
+      let errors = vec![vec![0.0, 5.0, 5.0], vec![5.0, 0.0, 5.0], vec![1.0, 1.0, 1.0], vec![6.0, 6.0, 0.0], vec![7.0, 7.0, 7.0]];
+
+      let result = lexicase_selection(&errors, 300, Some(42)).unwrap();
+      assert_eq!(result, lexicase_selection(&errors, 300, Some(42)).unwrap());
+      let count = |idx: usize| result.iter().filter(|&&x| x == idx).count();
+      assert!(count(0) > 60 && count(1) > 60 && count(3) > 60);
+      assert_eq!(count(2) + count(4), 0);
+
+      // ties on every case are broken at random
+      let result = lexicase_selection(&vec![vec![1.0, 2.0]; 3], 100, Some(42)).unwrap();
+      assert!((0..3).all(|idx| result.contains(&idx)));
+
+      assert!(lexicase_selection(&vec![vec![1.0, 2.0], vec![1.0]], 2, None).is_err());
+      assert!(lexicase_selection(&vec![], 2, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_epsilon_lexicase_selection() {
+      // the second individual is only slightly worse than the first on the first case
+      let errors = vec![vec![1.0, 9.0], vec![1.01, 0.0], vec![5.0, 5.0], vec![9.0, 9.0]];
+
+      let result = lexicase_selection(&errors, 200, Some(42)).unwrap();
+      assert!(result.iter().filter(|&&x| x == 0).count() > 50);
+
+      let result = epsilon_lexicase_selection(&errors, 200, Some(42)).unwrap();
+      assert_eq!(result, epsilon_lexicase_selection(&errors, 200, Some(42)).unwrap());
+      assert!(result.iter().all(|&x| x == 1));
+
+      assert!(epsilon_lexicase_selection(&vec![vec![1.0], vec![1.0, 2.0]], 2, None).is_err());
+    }
+
+    #[test]
+    fn test_downsampled_lexicase_selection() {
+      let errors = vec![vec![0.0, 5.0, 5.0, 5.0], vec![5.0, 0.0, 5.0, 5.0], vec![5.0, 5.0, 0.0, 5.0], vec![5.0, 5.0, 5.0, 0.0]];
+
+      assert!(downsampled_lexicase_selection(&errors, 10, 0.0, None).is_err());
+      assert!(downsampled_lexicase_selection(&errors, 10, 1.5, None).is_err());
+
+      // a single sampled case makes every selection pick its specialist
+      let result = downsampled_lexicase_selection(&errors, 10, 0.1, Some(42)).unwrap();
+      assert_eq!(result.len(), 10);
+      assert!(result.iter().all(|&x| x == result[0]));
+      assert_eq!(result, downsampled_lexicase_selection(&errors, 10, 0.1, Some(42)).unwrap());
+
+      let mut result = downsampled_lexicase_selection(&errors, 100, 1.0, Some(42)).unwrap();
+      result.sort();
+      result.dedup();
+      assert_eq!(result, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_proportional_sampler() {
+      let fitness_values = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+      assert!(ProportionalSampler::alias(&vec![]).is_err());
+      assert!(ProportionalSampler::cdf(&vec![1.0, -1.0]).is_err());
+      assert!(ProportionalSampler::alias(&vec![0.0, 0.0]).is_err());
+      assert!(ProportionalSampler::cdf(&vec![1.0, f32::NAN]).is_err());
+
+      // the cumulative distribution selects the same individuals as the roulette wheel
+      let sampler = ProportionalSampler::cdf(&fitness_values).unwrap();
+      assert_eq!(sampler.len(), fitness_values.len());
+      assert_eq!(sampler.select(10, Some(42)), roulette_wheel_selection(&fitness_values, 10, Some(42)));
+
+      let sampler = ProportionalSampler::alias(&fitness_values).unwrap();
+      assert_eq!(sampler.select(10, Some(42)), sampler.select(10, Some(42)));
+
+      // frequencies follow the weights, and zero weights are never drawn
+      let weights = vec![1.0, 0.0, 3.0, 6.0];
+      for sampler in [ProportionalSampler::alias(&weights).unwrap(), ProportionalSampler::cdf(&weights).unwrap()].iter() {
+        let result = sampler.select(20000, Some(42));
+        let frequency = |idx: usize| result.iter().filter(|&&x| x == idx).count() as f32 / 20000.0;
+        assert_eq!(frequency(1), 0.0);
+        assert!((frequency(0) - 0.1).abs() < 0.02);
+        assert!((frequency(2) - 0.3).abs() < 0.02);
+        assert!((frequency(3) - 0.6).abs() < 0.02);
+      }
+
+      // trailing zero weights are not drawn either when rounding leaves the total short
+      let mut weights = vec![0.1; 10];
+      weights.extend([0.0, 0.0]);
+      let result = ProportionalSampler::cdf(&weights).unwrap().select(20000, Some(42));
+      assert!(result.iter().all(|&idx| idx < 10));
+      assert!(roulette_wheel_selection(&weights, 20000, Some(42)).iter().all(|&idx| idx < 10));
+    }
+
+    #[test]
+    fn test_tournament_without_replacement_selection() {
+      let fitness_values = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+      let result = tournament_without_replacement_selection(&fitness_values, 19, 3, Some(42));
+      assert_eq!(result.len(), 19);
+      assert_eq!(result, tournament_without_replacement_selection(&fitness_values, 19, 3, Some(42)));
+      // in a full pass the best individual wins all its tournaments and the worst one none
+      assert_eq!(result.iter().filter(|&&x| x == 17).count(), 3);
+      assert!(!result.contains(&9));
+
+      assert_eq!(tournament_without_replacement_selection(&fitness_values, 45, 2, Some(42)).len(), 45);
+      assert_eq!(tournament_without_replacement_selection(&fitness_values, 5, 1, Some(42)).len(), 5);
+    }
+
+    #[test]
+    fn test_probabilistic_tournament_selection() {
+      let fitness_values = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+      assert!(probabilistic_tournament_selection(&fitness_values, 10, 3, 0.0, None).is_err());
+      assert!(probabilistic_tournament_selection(&fitness_values, 10, 3, 1.1, None).is_err());
+
+      // with certainty the fittest of the whole population always wins
+      let result = probabilistic_tournament_selection(&fitness_values, 10, 30, 1.0, Some(42)).unwrap();
+      assert!(result.iter().all(|&x| x == 17));
+
+      let result = probabilistic_tournament_selection(&fitness_values, 1000, 30, 0.5, Some(42)).unwrap();
+      assert_eq!(result, probabilistic_tournament_selection(&fitness_values, 1000, 30, 0.5, Some(42)).unwrap());
+      let frequency = |idx: usize| result.iter().filter(|&&x| x == idx).count() as f32 / 1000.0;
+      assert!((frequency(17) - 0.5).abs() < 0.05);
+      assert!((frequency(8) - 0.25).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_binary_tournament_selection() {
+      let fitness_values = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+      let result = binary_tournament_selection(&fitness_values, 100, Some(42));
+      assert_eq!(result.len(), 100);
+      assert_eq!(result, binary_tournament_selection(&fitness_values, 100, Some(42)));
+      // the worst individual can never win a tournament against another individual
+      assert!(!result.contains(&9));
+
+      assert_eq!(binary_tournament_selection(&vec![1.0, 2.0], 5, Some(42)), [1, 1, 1, 1, 1]);
+      assert_eq!(binary_tournament_selection(&vec![1.0], 2, Some(42)), [0, 0]);
+      assert!(binary_tournament_selection(&vec![], 2, Some(42)).is_empty());
+
+      let schemes: Vec<Box<dyn Selection>> = vec![
+        Box::new(BinaryTournamentSelection),
+        Box::new(TournamentWithoutReplacementSelection::new(2)),
+        Box::new(ProbabilisticTournamentSelection::new(3, 0.7).unwrap()),
+      ];
+      for scheme in schemes.iter() {
+        assert_eq!(scheme.select(&fitness_values, 10, Some(42)).len(), 10);
+      }
+      assert!(ProbabilisticTournamentSelection::new(3, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_transform_fitness() {
+      let values = vec![3.0, -1.0, 2.0, 0.0];
+
+      assert_eq!(transform_fitness(&values, Objective::Maximize, FitnessTransform::Identity).unwrap(), [3.0, -1.0, 2.0, 0.0]);
+      assert_eq!(transform_fitness(&values, Objective::Minimize, FitnessTransform::Identity).unwrap(), [-3.0, 1.0, -2.0, -0.0]);
+      assert_eq!(transform_fitness(&values, Objective::Maximize, FitnessTransform::Windowing).unwrap(), [4.0, 0.0, 3.0, 1.0]);
+      assert_eq!(transform_fitness(&values, Objective::Minimize, FitnessTransform::Windowing).unwrap(), [0.0, 4.0, 1.0, 3.0]);
+      assert_eq!(transform_fitness(&values, Objective::Maximize, FitnessTransform::Offset(1.0)).unwrap(), [4.0, 0.0, 3.0, 1.0]);
+      assert_eq!(transform_fitness(&values, Objective::Minimize, FitnessTransform::Offset(3.0)).unwrap(), [0.0, 4.0, 1.0, 3.0]);
+      assert_eq!(transform_fitness(&vec![0.0, 1.0, 3.0], Objective::Minimize, FitnessTransform::Inverse).unwrap(), [1.0, 0.5, 0.25]);
+
+      assert!(transform_fitness(&values, Objective::Minimize, FitnessTransform::Inverse).is_err());
+      assert!(transform_fitness(&vec![1.0], Objective::Maximize, FitnessTransform::Inverse).is_err());
+      assert!(transform_fitness(&values, Objective::Maximize, FitnessTransform::Offset(0.5)).is_err());
+      assert!(transform_fitness(&vec![1.0, f32::NAN], Objective::Maximize, FitnessTransform::Identity).is_err());
+
+      // a converged population keeps uniform weights instead of all zeros
+      assert_eq!(transform_fitness(&vec![2.0; 3], Objective::Maximize, FitnessTransform::Windowing).unwrap(), [1.0; 3]);
+      assert_eq!(transform_fitness(&vec![-2.0; 3], Objective::Minimize, FitnessTransform::Windowing).unwrap(), [1.0; 3]);
+
+      assert!(check_proportional_fitness(&vec![1.0, 0.0]).is_ok());
+      assert!(check_proportional_fitness(&vec![]).is_err());
+      assert!(check_proportional_fitness(&vec![1.0, -1.0]).is_err());
+      assert!(check_proportional_fitness(&vec![0.0, 0.0]).is_err());
+    }
+
+    #[test]
+    fn test_select_with() {
+      let costs = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+
+      // minimizing picks the lowest cost where maximizing would pick the highest
+      let result = TournamentSelection::new(30).select_with(&costs, 5, Objective::Minimize, FitnessTransform::Identity, Some(42)).unwrap();
+      assert_eq!(result, [9, 9, 9, 9, 9]);
+      let result = SteadyStateSelection.select_with(&costs, 2, Objective::Minimize, FitnessTransform::Identity, None).unwrap();
+      assert_eq!(result, [9, 4]);
+
+      // proportional schemes need a transform making the values non negative
+      assert!(RouletteWheelSelection.select_with(&costs, 5, Objective::Minimize, FitnessTransform::Identity, None).is_err());
+      assert!(StochasticUniversalSelection.select_with(&vec![1.0, -1.0], 5, Objective::Minimize, FitnessTransform::Identity, None).is_err());
+      let result = StochasticUniversalSelection.select_with(&vec![1.0; 4], 4, Objective::Minimize, FitnessTransform::Windowing, Some(42)).unwrap();
+      assert_eq!(result, [0, 1, 2, 3]);
+      let result = RouletteWheelSelection.select_with(&costs, 100, Objective::Minimize, FitnessTransform::Windowing, Some(42)).unwrap();
+      assert!(!result.contains(&17));
+
+      let fitness_values = transform_fitness(&costs, Objective::Minimize, FitnessTransform::Inverse).unwrap();
+      assert_eq!(
+        StochasticUniversalSelection.select_with(&costs, 10, Objective::Minimize, FitnessTransform::Inverse, Some(42)).unwrap(),
+        stochastic_universal_selection(&fitness_values, 10, Some(42))
+      );
+    }
+
+    #[test]
+    fn test_proportional_selection_with() {
+      let costs = vec![2.4,5.6,2.3,1.2,0.6,4.4,2.3,5.6,10.0,0.2,9.0,4.8,7.7,8.4,3.2,9.4,9.0,11.0,4.5];
+      let fitness_values = transform_fitness(&costs, Objective::Minimize, FitnessTransform::Inverse).unwrap();
+
+      assert_eq!(
+        roulette_wheel_selection_with(&costs, 10, Objective::Minimize, FitnessTransform::Inverse, Some(42)).unwrap(),
+        roulette_wheel_selection(&fitness_values, 10, Some(42))
+      );
+      assert_eq!(
+        stochastic_universal_selection_with(&costs, 10, Objective::Minimize, FitnessTransform::Inverse, Some(42)).unwrap(),
+        stochastic_universal_selection(&fitness_values, 10, Some(42))
+      );
+
+      for &(values, transform) in [(&vec![-1.0, 2.0], FitnessTransform::Identity), (&vec![0.0, 0.0], FitnessTransform::Identity), (&vec![], FitnessTransform::Windowing)].iter() {
+        assert!(roulette_wheel_selection_with(values, 4, Objective::Maximize, transform, None).is_err());
+        assert!(stochastic_universal_selection_with(values, 4, Objective::Maximize, transform, None).is_err());
+      }
+      assert!(roulette_wheel_selection_with(&costs, 4, Objective::Maximize, FitnessTransform::Inverse, None).is_err());
+
+      // no parents are selected without panicking
+      assert_eq!(roulette_wheel_selection_with(&costs, 0, Objective::Minimize, FitnessTransform::Inverse, Some(42)), Ok(vec![]));
+      assert_eq!(stochastic_universal_selection_with(&costs, 0, Objective::Minimize, FitnessTransform::Inverse, Some(42)), Ok(vec![]));
+      assert_eq!(StochasticUniversalSelection.select_with(&costs, 0, Objective::Minimize, FitnessTransform::Inverse, Some(42)), Ok(vec![]));
+      assert!(StochasticUniversalSelection.select(&fitness_values, 0, Some(42)).is_empty());
+
+      // converged populations are selected uniformly
+      let result = stochastic_universal_selection_with(&vec![3.0; 5], 5, Objective::Maximize, FitnessTransform::Windowing, Some(42)).unwrap();
+      assert_eq!(result, [0, 1, 2, 3, 4]);
+      assert_eq!(roulette_wheel_selection_with(&vec![3.0; 5], 5, Objective::Minimize, FitnessTransform::Windowing, None).unwrap().len(), 5);
+    }
 }
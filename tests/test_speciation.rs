@@ -0,0 +1,97 @@
+extern crate genx;
+
+#[cfg(test)]
+mod tests {
+  use genx::speciation::{Species, Speciator};
+
+  fn distance(a: &f32, b: &f32) -> f32 {
+    (a - b).abs()
+  }
+
+  #[test]
+  fn test_speciate() {
+    let mut speciator = Speciator::new(1.0);
+    let population = vec![0.0, 0.5, 5.0, 5.2, 9.0];
+    let fitness_values = vec![1.0, 2.0, 3.0, 1.0, 0.5];
+
+    speciator.speciate(&population, &fitness_values, distance, 0).unwrap();
+    let members = speciator.species().iter().map(|species| species.members.clone()).collect::<Vec<Vec<usize>>>();
+    assert_eq!(members, vec![vec![0, 1], vec![2, 3], vec![4]]);
+    // the fittest member represents the species
+    assert_eq!(speciator.species()[0].representative, 0.5);
+    assert_eq!(speciator.species()[1].best_fitness, 3.0);
+    assert_eq!(speciator.species_of(3), Some(1));
+
+    // species persist across generations, and empty ones disappear
+    let population = vec![1.2, 5.1, 5.3];
+    speciator.speciate(&population, &vec![0.5, 4.0, 1.0], distance, 1).unwrap();
+    let ids = speciator.species().iter().map(|species| species.id).collect::<Vec<usize>>();
+    assert_eq!(ids, vec![0, 1]);
+    assert_eq!(speciator.species()[0].last_improvement, 0);
+    assert_eq!(speciator.species()[1].last_improvement, 1);
+
+    speciator.speciate(&vec![20.0], &vec![1.0], distance, 2).unwrap();
+    assert_eq!(speciator.species()[0].id, 3);
+
+    assert!(speciator.speciate(&vec![1.0], &vec![], distance, 3).is_err());
+  }
+
+  #[test]
+  fn test_adjust_threshold() {
+    let mut speciator = Speciator::new(1.0);
+    let population = vec![0.0, 0.5, 5.0, 5.2, 9.0];
+    let fitness_values = vec![1.0; 5];
+
+    speciator.speciate(&population, &fitness_values, distance, 0).unwrap();
+    speciator.adjust_threshold(2, 2.0);
+    assert_eq!(speciator.compatibility_threshold(), 3.0);
+    speciator.speciate(&population, &fitness_values, distance, 1).unwrap();
+    assert_eq!(speciator.species().len(), 3);
+
+    speciator.adjust_threshold(5, 2.0);
+    assert_eq!(speciator.compatibility_threshold(), 2.0);
+    speciator.adjust_threshold(3, 2.0);
+    assert_eq!(speciator.compatibility_threshold(), 2.0);
+  }
+
+  #[test]
+  fn test_allot_offspring() {
+    let mut speciator = Speciator::new(1.0);
+    let population = vec![0.0, 0.5, 5.0, 5.2, 9.0];
+
+    // adjusted fitness sums are 2.0, 2.0 and 4.0
+    let fitness_values = vec![2.0, 2.0, 3.0, 1.0, 4.0];
+    speciator.speciate(&population, &fitness_values, distance, 0).unwrap();
+    assert_eq!(speciator.allot_offspring(&fitness_values, 8).unwrap(), vec![2, 2, 4]);
+    let allotted = speciator.allot_offspring(&fitness_values, 10).unwrap();
+    assert_eq!(allotted.iter().sum::<usize>(), 10);
+    assert_eq!(allotted[2], 5);
+
+    assert_eq!(speciator.allot_offspring(&vec![0.0; 5], 5).unwrap(), vec![2, 2, 1]);
+    assert!(speciator.allot_offspring(&vec![1.0, -1.0, 1.0, 1.0, 1.0], 5).is_err());
+    assert!(speciator.allot_offspring(&vec![1.0], 5).is_err());
+  }
+
+  #[test]
+  fn test_cull_stagnant() {
+    let mut speciator = Speciator::new(1.0);
+    let population = vec![0.0, 5.0, 9.0];
+
+    speciator.speciate(&population, &vec![1.0, 3.0, 2.0], distance, 0).unwrap();
+    for generation in 1..5 {
+      // only the first species keeps improving
+      speciator.speciate(&population, &vec![1.0 + generation as f32, 3.0, 2.0], distance, generation).unwrap();
+    }
+    assert_eq!(speciator.species()[1].stagnation(4), 4);
+
+    let removed = speciator.cull_stagnant(4, 3, 0);
+    assert_eq!(removed.iter().map(|species| species.id).collect::<Vec<usize>>(), vec![1, 2]);
+    assert_eq!(speciator.species().len(), 1);
+
+    let mut speciator = Speciator::new(1.0);
+    speciator.speciate(&population, &vec![1.0, 3.0, 2.0], distance, 0).unwrap();
+    let removed: Vec<Species<f32>> = speciator.cull_stagnant(10, 3, 2);
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].id, 0);
+  }
+}